@@ -216,6 +216,36 @@ fn arithmetic_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, A
 
     let (l, l_scalar) = lhs.get();
     let (r, r_scalar) = rhs.get();
+
+    // Arithmetic with an untyped NULL is performed on nulls of the type of the other
+    // argument, such that the result has the type of the operation on that type
+    match (l.data_type(), r.data_type()) {
+        (Null, Null) => {
+            if !l_scalar && !r_scalar && l.len() != r.len() {
+                return Err(ArrowError::ComputeError(
+                    "Cannot perform a binary operation on arrays of different length".to_string(),
+                ));
+            }
+            let len = if l_scalar { r.len() } else { l.len() };
+            return Ok(new_null_array(&Null, len));
+        }
+        (Null, r_t) => {
+            let nulls = new_null_array(r_t, l.len());
+            return match l_scalar {
+                true => arithmetic_op(op, &Scalar::new(nulls), rhs),
+                false => arithmetic_op(op, &nulls, rhs),
+            };
+        }
+        (l_t, Null) => {
+            let nulls = new_null_array(l_t, r.len());
+            return match r_scalar {
+                true => arithmetic_op(op, lhs, &Scalar::new(nulls)),
+                false => arithmetic_op(op, lhs, &nulls),
+            };
+        }
+        _ => {}
+    }
+
     downcast_integer! {
         l.data_type(), r.data_type() => (integer_helper, op, l, l_scalar, r, r_scalar),
         (Float16, Float16) => float_op::<Float16Type>(op, l, l_scalar, r, r_scalar),
@@ -346,6 +376,116 @@ fn float_op<T: ArrowPrimitiveType>(
     Ok(Arc::new(array))
 }
 
+/// Perform `lhs - rhs` of two timestamps, returning a [`DataType::Duration`] of `unit`
+/// and an error on overflow
+///
+/// Unlike [`sub`], which requires timestamps of the same unit and returns a duration
+/// of that unit, `lhs` and `rhs` may have different units. They are converted to the
+/// finer of their units before subtracting, and the difference is then converted to
+/// `unit`, truncating towards zero if `unit` is coarser. As timestamps are relative to
+/// the UNIX epoch, their time zones do not affect the result.
+///
+/// # Errors
+/// This function errors if `lhs` or `rhs` are not timestamps, or on overflow
+///
+/// # Example
+/// ```
+/// # use arrow_arith::numeric::timestamp_diff;
+/// # use arrow_array::{DurationMillisecondArray, TimestampMillisecondArray, TimestampSecondArray};
+/// # use arrow_schema::TimeUnit;
+/// let a = TimestampMillisecondArray::from(vec![1_500, 60_000]).with_timezone("+02:00");
+/// let b = TimestampSecondArray::new_scalar(1);
+/// let diff = timestamp_diff(&a, &b, TimeUnit::Millisecond).unwrap();
+/// assert_eq!(diff.as_ref(), &DurationMillisecondArray::from(vec![500, 59_000]));
+/// ```
+pub fn timestamp_diff(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    unit: TimeUnit,
+) -> Result<ArrayRef, ArrowError> {
+    let (l, l_s) = lhs.get();
+    let (r, r_s) = rhs.get();
+    let (DataType::Timestamp(l_unit, _), DataType::Timestamp(r_unit, _)) =
+        (l.data_type(), r.data_type())
+    else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "timestamp_diff requires timestamps, got {} and {}",
+            l.data_type(),
+            r.data_type()
+        )));
+    };
+
+    let common = match unit_nanos(*l_unit) < unit_nanos(*r_unit) {
+        true => *l_unit,
+        false => *r_unit,
+    };
+    let l = &timestamp_in_unit(l, common)?;
+    let r = &timestamp_in_unit(r, common)?;
+    let diff: PrimitiveArray<Int64Type> = try_op!(l, l_s, r, r_s, l.sub_checked(r));
+
+    let (from, to) = (unit_nanos(common), unit_nanos(unit));
+    let diff = match from.cmp(&to) {
+        Ordering::Equal => diff,
+        Ordering::Greater => diff.try_unary(|v| v.mul_checked(from / to))?,
+        Ordering::Less => diff.unary(|v| v / (to / from)),
+    };
+    Ok(match unit {
+        TimeUnit::Second => Arc::new(diff.reinterpret_cast::<DurationSecondType>()),
+        TimeUnit::Millisecond => Arc::new(diff.reinterpret_cast::<DurationMillisecondType>()),
+        TimeUnit::Microsecond => Arc::new(diff.reinterpret_cast::<DurationMicrosecondType>()),
+        TimeUnit::Nanosecond => Arc::new(diff.reinterpret_cast::<DurationNanosecondType>()),
+    })
+}
+
+/// Returns the number of nanoseconds in `unit`
+fn unit_nanos(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    }
+}
+
+/// Returns the values of the timestamp `array` in `unit`, which must not be coarser
+/// than the unit of `array`, returning an error on overflow
+fn timestamp_in_unit(
+    array: &dyn Array,
+    unit: TimeUnit,
+) -> Result<PrimitiveArray<Int64Type>, ArrowError> {
+    let (values, from) = match array.data_type() {
+        DataType::Timestamp(TimeUnit::Second, _) => (
+            array
+                .as_primitive::<TimestampSecondType>()
+                .reinterpret_cast(),
+            TimeUnit::Second,
+        ),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => (
+            array
+                .as_primitive::<TimestampMillisecondType>()
+                .reinterpret_cast(),
+            TimeUnit::Millisecond,
+        ),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => (
+            array
+                .as_primitive::<TimestampMicrosecondType>()
+                .reinterpret_cast(),
+            TimeUnit::Microsecond,
+        ),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => (
+            array
+                .as_primitive::<TimestampNanosecondType>()
+                .reinterpret_cast(),
+            TimeUnit::Nanosecond,
+        ),
+        d => unreachable!("{d}"),
+    };
+    match unit_nanos(from) / unit_nanos(unit) {
+        1 => Ok(values),
+        factor => values.try_unary(|v| v.mul_checked(factor)),
+    }
+}
+
 /// Arithmetic trait for timestamp arrays
 trait TimestampOp: ArrowTimestampType {
     type Duration: ArrowPrimitiveType<Native = i64>;
@@ -1279,6 +1419,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_timestamp_diff() {
+        let a = TimestampSecondArray::from(vec![Some(10), None, Some(-5), Some(100)])
+            .with_timezone("+01:00");
+        let b = TimestampMillisecondArray::from(vec![Some(2_500), Some(0), Some(0), None]);
+
+        // The difference is computed in the finer unit, then converted
+        let diff = timestamp_diff(&a, &b, TimeUnit::Millisecond).unwrap();
+        let expected = DurationMillisecondArray::from(vec![Some(7_500), None, Some(-5_000), None]);
+        assert_eq!(diff.as_primitive::<DurationMillisecondType>(), &expected);
+
+        let diff = timestamp_diff(&a, &b, TimeUnit::Nanosecond).unwrap();
+        let expected = DurationNanosecondArray::from(vec![
+            Some(7_500_000_000),
+            None,
+            Some(-5_000_000_000),
+            None,
+        ]);
+        assert_eq!(diff.as_primitive::<DurationNanosecondType>(), &expected);
+
+        // Coarser units truncate towards zero
+        let diff = timestamp_diff(&b, &a, TimeUnit::Second).unwrap();
+        let expected = DurationSecondArray::from(vec![Some(-7), None, Some(5), None]);
+        assert_eq!(diff.as_primitive::<DurationSecondType>(), &expected);
+
+        // Adding the duration back preserves the time zone
+        let sum = add(&a, &timestamp_diff(&b, &a, TimeUnit::Second).unwrap()).unwrap();
+        assert_eq!(
+            sum.as_primitive::<TimestampSecondType>().timezone(),
+            Some("+01:00")
+        );
+
+        // Same units match `sub`
+        let diff = timestamp_diff(&a, &a.slice(1, 1), TimeUnit::Second);
+        assert!(diff.is_err());
+        let diff = timestamp_diff(&a, &Scalar::new(a.slice(0, 1)), TimeUnit::Second).unwrap();
+        let expected = sub(&a, &Scalar::new(a.slice(0, 1))).unwrap();
+        assert_eq!(&diff, &expected);
+
+        // Scalars are broadcast
+        let epoch = TimestampNanosecondArray::new_scalar(0);
+        let diff = timestamp_diff(&epoch, &b, TimeUnit::Microsecond).unwrap();
+        let expected =
+            DurationMicrosecondArray::from(vec![Some(-2_500_000), Some(0), Some(0), None]);
+        assert_eq!(diff.as_primitive::<DurationMicrosecondType>(), &expected);
+        let diff = timestamp_diff(&epoch, &epoch, TimeUnit::Second).unwrap();
+        assert_eq!(diff.as_primitive::<DurationSecondType>().values(), &[0]);
+    }
+
+    #[test]
+    fn test_timestamp_diff_overflow() {
+        // Converting to the finer unit of the other side overflows
+        let a = TimestampSecondArray::from(vec![i64::MAX / 1_000]);
+        let b = TimestampNanosecondArray::from(vec![0]);
+        let err = timestamp_diff(&a, &b, TimeUnit::Second).unwrap_err();
+        assert!(err.to_string().contains("Overflow"), "{err}");
+
+        // The difference overflows
+        let a = TimestampSecondArray::from(vec![i64::MAX]);
+        let b = TimestampSecondArray::from(vec![-1]);
+        let err = timestamp_diff(&a, &b, TimeUnit::Second).unwrap_err();
+        assert!(err.to_string().contains("Overflow"), "{err}");
+
+        // Converting the difference to a finer unit overflows
+        let b = TimestampSecondArray::from(vec![0]);
+        let err = timestamp_diff(&a, &b, TimeUnit::Millisecond).unwrap_err();
+        assert!(err.to_string().contains("Overflow"), "{err}");
+
+        let err = timestamp_diff(&a, &Int64Array::from(vec![0]), TimeUnit::Second).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: timestamp_diff requires timestamps, got Timestamp(Second, None) and Int64"
+        );
+    }
+
     #[test]
     fn test_timestamp() {
         test_timestamp_impl::<TimestampSecondType>();
@@ -1533,4 +1748,59 @@ mod tests {
             "Arithmetic overflow: Overflow happened on: 9223372036854775807 - -1"
         );
     }
+
+    #[test]
+    fn test_null_array_arithmetic() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let n = NullArray::new(3);
+
+        for r in [add(&a, &n), sub(&n, &a), mul_wrapping(&a, &n), div(&n, &a)] {
+            let r = r.unwrap();
+            assert_eq!(r.data_type(), &DataType::Int32);
+            assert_eq!(r.len(), 3);
+            assert_eq!(r.null_count(), 3);
+        }
+
+        // Scalars
+        let r = rem(&a, &Scalar::new(NullArray::new(1))).unwrap();
+        assert_eq!(r.as_primitive::<Int32Type>(), &Int32Array::new_null(3));
+
+        let s = Scalar::new(Float64Array::from(vec![1.5]));
+        let r = add(&s, &NullArray::new(2)).unwrap();
+        assert_eq!(r.as_primitive::<Float64Type>(), &Float64Array::new_null(2));
+
+        // The result has the type of the operation on the type of the other argument
+        let d = Decimal128Array::from(vec![1, 2])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let r = sub(&d, &NullArray::new(2)).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(11, 2));
+        assert_eq!(r.null_count(), 2);
+        let r = mul(&NullArray::new(2), &d).unwrap();
+        assert_eq!(r.data_type(), mul(&d, &d).unwrap().data_type());
+        assert_eq!(r.null_count(), 2);
+
+        let t = TimestampSecondArray::from(vec![1, 2]);
+        let r = sub(&t, &NullArray::new(2)).unwrap();
+        assert_eq!(r.data_type(), &DataType::Duration(TimeUnit::Second));
+        assert_eq!(r.null_count(), 2);
+
+        // Types that do not support the operation are rejected
+        let strings = StringArray::from(vec!["a", "b"]);
+        let err = add(&strings, &NullArray::new(2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid arithmetic operation: Utf8 + Utf8"
+        );
+
+        let r = add(&n, &n).unwrap();
+        assert_eq!(r.data_type(), &DataType::Null);
+        assert_eq!(r.len(), 3);
+
+        let err = add(&a, &NullArray::new(2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot perform a binary operation on arrays of different length"
+        );
+    }
 }
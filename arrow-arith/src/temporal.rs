@@ -685,6 +685,86 @@ impl<T: Datelike> ChronoDateExt for T {
     }
 }
 
+/// Converts seconds since the UNIX epoch to a [`TimestampSecondArray`] with time zone `tz`
+///
+/// Timestamps are stored relative to UTC, so the values are unchanged and `tz` only
+/// determines how they are displayed and how date parts are extracted. Nulls are
+/// preserved.
+///
+/// # Errors
+/// This function errors if `tz` is not a valid time zone
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int64Array, TimestampSecondArray};
+/// # use arrow_arith::temporal::from_unixtime;
+/// let seconds = Int64Array::from(vec![Some(1_700_000_000), None]);
+/// let timestamps = from_unixtime(&seconds, Some("+01:00")).unwrap();
+/// let expected = TimestampSecondArray::from(vec![Some(1_700_000_000), None]);
+/// assert_eq!(timestamps, expected.with_timezone("+01:00"));
+/// assert_eq!(timestamps.value_as_datetime_with_tz(0, "+01:00".parse().unwrap())
+///     .unwrap()
+///     .to_string(), "2023-11-14 23:13:20 +01:00");
+/// ```
+pub fn from_unixtime(
+    seconds: &Int64Array,
+    tz: Option<&str>,
+) -> Result<TimestampSecondArray, ArrowError> {
+    let timestamps = seconds.reinterpret_cast::<TimestampSecondType>();
+    match tz {
+        Some(tz) => {
+            tz.parse::<Tz>()?;
+            Ok(timestamps.with_timezone(tz))
+        }
+        None => Ok(timestamps),
+    }
+}
+
+/// Converts a timestamp array of any [`TimeUnit`] to seconds since the UNIX epoch
+///
+/// Sub-second precision is truncated towards negative infinity, so that a timestamp
+/// before the epoch maps to the second in which it occurs. The time zone of the
+/// array does not affect the result. Nulls are preserved.
+///
+/// # Errors
+/// This function errors if `timestamps` is not a timestamp array
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int64Array, TimestampMillisecondArray};
+/// # use arrow_arith::temporal::to_unixtime;
+/// let timestamps = TimestampMillisecondArray::from(vec![Some(1_500), None, Some(-1_500)])
+///     .with_timezone("America/New_York");
+/// let seconds = to_unixtime(&timestamps).unwrap();
+/// assert_eq!(seconds, Int64Array::from(vec![Some(1), None, Some(-2)]));
+/// ```
+pub fn to_unixtime(timestamps: &dyn Array) -> Result<Int64Array, ArrowError> {
+    let (values, divisor) = match timestamps.data_type() {
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            let values = timestamps.as_primitive::<TimestampSecondType>();
+            return Ok(values.reinterpret_cast());
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            let values = timestamps.as_primitive::<TimestampMillisecondType>();
+            (values.reinterpret_cast::<Int64Type>(), MILLISECONDS)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            let values = timestamps.as_primitive::<TimestampMicrosecondType>();
+            (values.reinterpret_cast::<Int64Type>(), MICROSECONDS)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            let values = timestamps.as_primitive::<TimestampNanosecondType>();
+            (values.reinterpret_cast::<Int64Type>(), NANOSECONDS)
+        }
+        d => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "to_unixtime requires a timestamp array, got {d}"
+            )))
+        }
+    };
+    Ok(values.unary(|v| v.div_euclid(divisor)))
+}
+
 /// Extracts the hours of a given array as an array of integers within
 /// the range of [0, 23]. If the given array isn't temporal primitive or dictionary array,
 /// an `Err` will be returned.
@@ -2236,6 +2316,29 @@ mod tests {
         assert_eq!(1, actual.value(2));
     }
 
+    #[test]
+    fn test_temporal_array_timestamp_week_iso_quarter_with_timezone() {
+        // 2015-12-31T23:00:00 UTC is in the next quarter at +02:00, and
+        // 2016-01-03T23:00:00 UTC is in the next ISO week and ISO year
+        let values = vec![
+            TIMESTAMP_SECOND_2016_01_03 - 2 * SECONDS_IN_DAY - 3600,
+            TIMESTAMP_SECOND_2016_01_03 + SECONDS_IN_DAY - 3600,
+        ];
+        let utc = TimestampSecondArray::from(values.clone());
+        let local = TimestampSecondArray::from(values).with_timezone("+02:00");
+
+        let parts = |a: &TimestampSecondArray, part| {
+            let b = date_part(a, part).unwrap();
+            b.as_primitive::<Int32Type>().values().to_vec()
+        };
+        assert_eq!(parts(&utc, DatePart::WeekISO), vec![53, 53]);
+        assert_eq!(parts(&local, DatePart::WeekISO), vec![53, 1]);
+        assert_eq!(parts(&utc, DatePart::YearISO), vec![2015, 2015]);
+        assert_eq!(parts(&local, DatePart::YearISO), vec![2015, 2016]);
+        assert_eq!(parts(&utc, DatePart::Quarter), vec![4, 1]);
+        assert_eq!(parts(&local, DatePart::Quarter), vec![1, 1]);
+    }
+
     #[test]
     fn test_temporal_array_timestamp_year_iso_edge_cases() {
         let a = TimestampSecondArray::from(vec![
@@ -2249,4 +2352,64 @@ mod tests {
         assert_eq!(2015, actual.value(1));
         assert_eq!(2016, actual.value(2));
     }
+
+    #[test]
+    fn test_from_unixtime() {
+        let seconds = Int64Array::from(vec![Some(TIMESTAMP_SECOND_2016_01_03), None, Some(-1)]);
+        let timestamps = from_unixtime(&seconds, None).unwrap();
+        assert_eq!(
+            timestamps.data_type(),
+            &DataType::Timestamp(TimeUnit::Second, None)
+        );
+        assert_eq!(timestamps.values(), seconds.values());
+        assert_eq!(timestamps.nulls(), seconds.nulls());
+
+        let timestamps = from_unixtime(&seconds, Some("+09:00")).unwrap();
+        assert_eq!(timestamps.timezone(), Some("+09:00"));
+        let hours = date_part(&timestamps, DatePart::Hour).unwrap();
+        let expected = Int32Array::from(vec![Some(9), None, Some(8)]);
+        assert_eq!(hours.as_primitive::<Int32Type>(), &expected);
+
+        let err = from_unixtime(&seconds, Some("Mars/Olympus")).unwrap_err();
+        assert!(err.to_string().contains("Invalid timezone"), "{err}");
+    }
+
+    #[test]
+    fn test_to_unixtime() {
+        let expected = Int64Array::from(vec![Some(TIMESTAMP_SECOND_2016_01_03), None, Some(-1)]);
+        let s = TimestampSecondArray::from(vec![Some(TIMESTAMP_SECOND_2016_01_03), None, Some(-1)]);
+        assert_eq!(to_unixtime(&s).unwrap(), expected);
+
+        let ms = TimestampMillisecondArray::from(vec![
+            Some(TIMESTAMP_SECOND_2016_01_03 * MILLISECONDS + 999),
+            None,
+            Some(-1),
+        ]);
+        assert_eq!(to_unixtime(&ms).unwrap(), expected);
+
+        let us = TimestampMicrosecondArray::from(vec![
+            Some(TIMESTAMP_SECOND_2016_01_03 * MICROSECONDS),
+            None,
+            Some(-MICROSECONDS),
+        ])
+        .with_timezone("+05:00");
+        assert_eq!(to_unixtime(&us).unwrap(), expected);
+
+        let ns = TimestampNanosecondArray::from(vec![
+            Some(TIMESTAMP_SECOND_2016_01_03 * NANOSECONDS + 1),
+            None,
+            Some(-NANOSECONDS + 1),
+        ]);
+        assert_eq!(to_unixtime(&ns).unwrap(), expected);
+
+        // Round trips through from_unixtime
+        let timestamps = from_unixtime(&expected, Some("+05:00")).unwrap();
+        assert_eq!(to_unixtime(&timestamps).unwrap(), expected);
+
+        let err = to_unixtime(&Date32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: to_unixtime requires a timestamp array, got Date32"
+        );
+    }
 }
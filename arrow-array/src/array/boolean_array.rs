@@ -80,6 +80,17 @@ impl std::fmt::Debug for BooleanArray {
     }
 }
 
+/// The number of true, false and null values of a [`BooleanArray`], see [`BooleanArray::stats`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BooleanStats {
+    /// The number of non null, true values
+    pub true_count: usize,
+    /// The number of non null, false values
+    pub false_count: usize,
+    /// The number of null values
+    pub null_count: usize,
+}
+
 impl BooleanArray {
     /// Create a new [`BooleanArray`] from the provided values and nulls
     ///
@@ -176,6 +187,28 @@ impl BooleanArray {
         self.len() - self.null_count() - self.true_count()
     }
 
+    /// Returns the number of true, false and null values within this array
+    ///
+    /// This scans the values and null bitmaps once, as the null count is already
+    /// known, and is therefore cheaper than calling [`Self::true_count`] and
+    /// [`Self::false_count`] separately.
+    ///
+    /// ```
+    /// # use arrow_array::{BooleanArray, BooleanStats};
+    /// let array = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+    /// let stats = array.stats();
+    /// assert_eq!(stats, BooleanStats { true_count: 2, false_count: 1, null_count: 1 });
+    /// ```
+    pub fn stats(&self) -> BooleanStats {
+        let null_count = self.null_count();
+        let true_count = self.true_count();
+        BooleanStats {
+            true_count,
+            false_count: self.len() - null_count - true_count,
+            null_count,
+        }
+    }
+
     /// Returns the boolean value at index `i`.
     ///
     /// # Safety
@@ -689,6 +722,17 @@ mod tests {
 
             let expected_false = d.iter().filter(|x| matches!(x, Some(false))).count();
             assert_eq!(b.false_count(), expected_false);
+
+            let stats = b.stats();
+            assert_eq!(stats.true_count, expected_true);
+            assert_eq!(stats.false_count, expected_false);
+            assert_eq!(stats.null_count, d.iter().filter(|x| x.is_none()).count());
+
+            let sliced = b.slice(13, 1500);
+            let stats = sliced.stats();
+            assert_eq!(stats.true_count, sliced.true_count());
+            assert_eq!(stats.false_count, sliced.false_count());
+            assert_eq!(stats.null_count, sliced.null_count());
         }
     }
 
@@ -261,6 +261,45 @@ impl<T: ByteArrayType> GenericByteArray<T> {
         self.value_data.as_slice()
     }
 
+    /// Returns the value bytes referenced by this array, without copying
+    ///
+    /// Unlike [`Self::value_data`], which may include bytes outside a sliced
+    /// array, this returns only the bytes between the first and last offset.
+    /// The value at index `i` is therefore found at
+    /// `value_offsets()[i] - value_offsets()[0]..value_offsets()[i + 1] - value_offsets()[0]`
+    ///
+    /// ```
+    /// # use arrow_array::{Array, StringArray};
+    /// let array = StringArray::from(vec!["hello", "arrow", "world"]).slice(1, 2);
+    /// assert_eq!(array.values_bytes(), b"arrowworld");
+    /// assert_eq!(array.value_data(), b"helloarrowworld");
+    /// ```
+    pub fn values_bytes(&self) -> &[u8] {
+        let offsets = self.value_offsets();
+        let start = offsets.first().unwrap().as_usize();
+        let end = offsets.last().unwrap().as_usize();
+        &self.value_data.as_slice()[start..end]
+    }
+
+    /// Returns the bytes of the element at index `i`, without validating
+    /// or converting them to [`T::Native`](ByteArrayType::Native)
+    ///
+    /// # Panics
+    /// Panics if index `i` is out of bounds.
+    pub fn value_bytes(&self, i: usize) -> &[u8] {
+        assert!(
+            i < self.len(),
+            "Trying to access an element at index {} from a {}{}Array of length {}",
+            i,
+            T::Offset::PREFIX,
+            T::PREFIX,
+            self.len()
+        );
+        let start = self.value_offsets()[i].as_usize();
+        let end = self.value_offsets()[i + 1].as_usize();
+        &self.value_data.as_slice()[start..end]
+    }
+
     /// Returns true if all data within this array is ASCII
     pub fn is_ascii(&self) -> bool {
         let offsets = self.value_offsets();
@@ -630,4 +669,39 @@ mod tests {
 
         BinaryArray::new(offsets, non_ascii_data, None);
     }
+
+    #[test]
+    fn test_value_bytes() {
+        let array = StringArray::from(vec![Some("hello"), None, Some(""), Some("wörld")]);
+        assert_eq!(array.values_bytes(), "hellowörld".as_bytes());
+        assert_eq!(array.value_bytes(0), b"hello");
+        assert_eq!(array.value_bytes(1), b"");
+        assert_eq!(array.value_bytes(2), b"");
+        assert_eq!(array.value_bytes(3), "wörld".as_bytes());
+
+        let sliced = array.slice(2, 2);
+        assert_eq!(sliced.values_bytes(), "wörld".as_bytes());
+        assert_eq!(sliced.value_bytes(1), "wörld".as_bytes());
+        // The returned bytes borrow the underlying buffer
+        assert_eq!(
+            sliced.values_bytes().as_ptr(),
+            array.value_bytes(3).as_ptr()
+        );
+
+        let empty = array.slice(1, 0);
+        assert_eq!(empty.values_bytes(), b"");
+
+        let array = BinaryArray::from_iter_values([b"\xFF\x00".as_slice(), b"ab"]);
+        assert_eq!(array.values_bytes(), b"\xFF\x00ab");
+        assert_eq!(array.value_bytes(1), b"ab");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Trying to access an element at index 2 from a StringArray of length 2"
+    )]
+    fn test_value_bytes_out_of_bounds() {
+        let array = StringArray::from(vec!["a", "b"]);
+        array.value_bytes(2);
+    }
 }
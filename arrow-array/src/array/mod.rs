@@ -226,6 +226,29 @@ pub trait Array: std::fmt::Debug + Send + Sync {
         self.nulls().cloned()
     }
 
+    /// Returns the null buffer of this array as returned by [`Array::nulls`], or an
+    /// all-valid [`NullBuffer`] of the array's length if there isn't one.
+    ///
+    /// Note: this allocates a new buffer when the array has no null buffer, hot paths
+    /// should therefore still special case [`Array::nulls`] returning `None`
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use arrow_array::{Array, Int32Array};
+    ///
+    /// let array = Int32Array::from(vec![1, 2]);
+    /// assert!(array.nulls().is_none());
+    /// assert_eq!(array.nulls_materialized().null_count(), 0);
+    /// assert_eq!(array.nulls_materialized().len(), 2);
+    /// ```
+    fn nulls_materialized(&self) -> NullBuffer {
+        match self.nulls() {
+            Some(nulls) => nulls.clone(),
+            None => NullBuffer::new_valid(self.len()),
+        }
+    }
+
     /// Returns whether the element at `index` is null according to [`Array::nulls`]
     ///
     /// Note: For performance reasons, this method returns nullability solely as determined by the
@@ -336,6 +359,34 @@ pub trait Array: std::fmt::Debug + Send + Sync {
     /// This value will always be greater than returned by `get_buffer_memory_size()` and
     /// includes the overhead of the data structures that contain the pointers to the various buffers.
     fn get_array_memory_size(&self) -> usize;
+
+    /// Returns an estimate of the number of bytes of buffer memory addressed by the
+    /// current offset and length of this array.
+    ///
+    /// Unlike [`Array::get_buffer_memory_size`], which reports the full size of the
+    /// underlying buffers even for a small slice, this only accounts for the portion
+    /// attributable to the slice, and is approximately the size the array would occupy
+    /// if it were copied into newly allocated buffers.
+    ///
+    /// For variable-length types this is an estimate based on the range of values
+    /// referenced by the offsets or views, and shared buffers such as the values of a
+    /// [`DictionaryArray`] are counted in full. If the sliced size cannot be determined,
+    /// this falls back to [`Array::get_buffer_memory_size`].
+    ///
+    /// ```
+    /// # use arrow_array::{Array, Int64Array};
+    /// let array = Int64Array::from_iter_values(0..100);
+    /// assert_eq!(array.slice_memory_size(), 800);
+    ///
+    /// let sliced = array.slice(0, 20);
+    /// assert_eq!(sliced.get_buffer_memory_size(), array.get_buffer_memory_size());
+    /// assert_eq!(sliced.slice_memory_size(), 160);
+    /// ```
+    fn slice_memory_size(&self) -> usize {
+        self.to_data()
+            .get_slice_memory_size()
+            .unwrap_or_else(|_| self.get_buffer_memory_size())
+    }
 }
 
 /// A reference-counted reference to a generic `Array`
@@ -419,6 +470,10 @@ impl Array for ArrayRef {
     fn get_array_memory_size(&self) -> usize {
         self.as_ref().get_array_memory_size()
     }
+
+    fn slice_memory_size(&self) -> usize {
+        self.as_ref().slice_memory_size()
+    }
 }
 
 impl<T: Array> Array for &T {
@@ -489,6 +544,10 @@ impl<T: Array> Array for &T {
     fn get_array_memory_size(&self) -> usize {
         T::get_array_memory_size(self)
     }
+
+    fn slice_memory_size(&self) -> usize {
+        T::slice_memory_size(self)
+    }
 }
 
 /// A generic trait for accessing the values of an [`Array`]
@@ -735,6 +794,17 @@ impl<T: ByteViewType + ?Sized> PartialEq for GenericByteViewArray<T> {
 
 /// Constructs an array using the input `data`.
 /// Returns a reference-counted `Array` instance.
+///
+/// This is zero-copy, and does not validate the contents of `data`, beyond
+/// checking its data type and number of buffers and children, which takes constant
+/// time for all but nested types. Validation is instead performed when constructing
+/// the [`ArrayData`], e.g. by [`ArrayDataBuilder::build`], and so an [`ArrayData`]
+/// produced by [`ArrayDataBuilder::build_unchecked`] or
+/// [`MutableArrayData::freeze`] can be converted without revalidation.
+///
+/// [`ArrayDataBuilder::build`]: arrow_data::ArrayDataBuilder::build
+/// [`ArrayDataBuilder::build_unchecked`]: arrow_data::ArrayDataBuilder::build_unchecked
+/// [`MutableArrayData::freeze`]: arrow_data::transform::MutableArrayData::freeze
 pub fn make_array(data: ArrayData) -> ArrayRef {
     match data.data_type() {
         DataType::Boolean => Arc::new(BooleanArray::from(data)) as ArrayRef,
@@ -1243,6 +1313,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_nulls_materialized() {
+        let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let nulls = array.nulls_materialized();
+        assert_eq!(&nulls, array.nulls().unwrap());
+
+        let array = Int32Array::from(vec![1, 2, 3]).slice(1, 2);
+        let nulls = array.nulls_materialized();
+        assert_eq!(nulls.len(), 2);
+        assert_eq!(nulls.null_count(), 0);
+
+        let array: ArrayRef = Arc::new(NullArray::new(2));
+        let nulls = array.nulls_materialized();
+        assert_eq!(nulls.len(), 2);
+        assert_eq!(nulls.null_count(), 0);
+    }
+
+    #[test]
+    fn test_slice_memory_size() {
+        let arr = PrimitiveArray::<Int64Type>::from_iter_values(0..128);
+        assert_eq!(arr.slice_memory_size(), 128 * 8);
+        let sliced = arr.slice(64, 32);
+        assert_eq!(
+            sliced.get_buffer_memory_size(),
+            arr.get_buffer_memory_size()
+        );
+        assert_eq!(sliced.slice_memory_size(), 32 * 8);
+
+        let arr: Int32Array = (0..16).map(|i| (i % 2 == 0).then_some(i)).collect();
+        assert_eq!(arr.slice(3, 8).slice_memory_size(), 8 * 4 + 1);
+
+        // 2 offsets and 5 bytes of data
+        let arr = StringArray::from(vec!["a", "bb", "ccc", "dddd"]);
+        assert_eq!(arr.slice(1, 2).slice_memory_size(), 2 * 4 + 5);
+        assert_eq!(arr.slice(1, 0).slice_memory_size(), 0);
+
+        // 1 offset and 3 child values
+        let arr = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3)]),
+            Some(vec![Some(4), Some(5), Some(6)]),
+        ]);
+        assert_eq!(arr.slice(2, 1).slice_memory_size(), 4 + 3 * 4);
+
+        // 1 view and 27 bytes of out-of-line data
+        let arr = StringViewArray::from(vec!["short", "a string longer than twelve"]);
+        assert_eq!(arr.slice(0, 1).slice_memory_size(), 16);
+        assert_eq!(arr.slice(1, 1).slice_memory_size(), 16 + 27);
+
+        let arr: ArrayRef = Arc::new(arr);
+        assert_eq!(arr.slice(1, 1).slice_memory_size(), 16 + 27);
+    }
+
     /// Test function that takes an &dyn Array
     fn compute_my_thing(arr: &dyn Array) -> bool {
         !arr.is_empty()
@@ -784,11 +784,11 @@ impl<T: ArrowPrimitiveType> PrimitiveArray<T> {
     where
         K: ArrowPrimitiveType<Native = T::Native>,
     {
-        let d = self.to_data().into_builder().data_type(K::DATA_TYPE);
-
-        // SAFETY:
-        // Native type is the same
-        PrimitiveArray::from(unsafe { d.build_unchecked() })
+        PrimitiveArray {
+            data_type: K::DATA_TYPE,
+            values: self.values.clone(),
+            nulls: self.nulls.clone(),
+        }
     }
 
     /// Applies a unary infallible function to a primitive array, producing a
@@ -1548,6 +1548,9 @@ impl<T: DecimalType + ArrowPrimitiveType> PrimitiveArray<T> {
 
     /// Validates values in this array can be properly interpreted
     /// with the specified precision.
+    ///
+    /// Pass [`Self::precision`] to check the values fit the declared precision,
+    /// for example after arithmetic that might have overflowed it
     pub fn validate_decimal_precision(&self, precision: u8) -> Result<(), ArrowError> {
         (0..self.len()).try_for_each(|idx| {
             if self.is_valid(idx) {
@@ -1561,8 +1564,11 @@ impl<T: DecimalType + ArrowPrimitiveType> PrimitiveArray<T> {
 
     /// Validates the Decimal Array, if the value of slot is overflow for the specified precision, and
     /// will be casted to Null
+    ///
+    /// The returned array has the same precision and scale as `self`
     pub fn null_if_overflow_precision(&self, precision: u8) -> Self {
         self.unary_opt::<_, T>(|v| T::is_valid_decimal_precision(v, precision).then_some(v))
+            .with_data_type(self.data_type().clone())
     }
 
     /// Returns [`Self::value`] formatted as a string
@@ -1649,6 +1655,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_reinterpret_cast() {
+        let a = Int32Array::from(vec![Some(1), None, Some(19000)]).slice(1, 2);
+        let b: Date32Array = a.reinterpret_cast();
+        assert_eq!(b.data_type(), &DataType::Date32);
+        assert_eq!(b.len(), 2);
+        assert!(b.is_null(0));
+        assert_eq!(b.value(1), 19000);
+        // The values and nulls are shared without copying
+        assert_eq!(b.values().as_ptr(), a.values().as_ptr());
+        assert!(b
+            .nulls()
+            .unwrap()
+            .inner()
+            .ptr_eq(a.nulls().unwrap().inner()));
+
+        let b: TimestampMillisecondArray = Int64Array::from(vec![1, 2]).reinterpret_cast();
+        assert_eq!(
+            b.data_type(),
+            &DataType::Timestamp(TimeUnit::Millisecond, None)
+        );
+        let c: Int64Array = b.reinterpret_cast();
+        assert_eq!(c, Int64Array::from(vec![1, 2]));
+    }
+
     #[test]
     fn test_primitive_array_from_vec_option() {
         // Test building a primitive array with null values
@@ -2462,6 +2493,29 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_decimal_null_if_overflow_declared_precision() {
+        let array = Decimal128Array::from(vec![Some(999), Some(1000), None, Some(-1000)])
+            .with_precision_and_scale(3, 1)
+            .unwrap();
+
+        let err = array
+            .validate_decimal_precision(array.precision())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: 1000 is too large to store in a Decimal128 of precision 3. Max is 999"
+        );
+
+        let result = array.null_if_overflow_precision(array.precision());
+        assert_eq!(result.data_type(), &DataType::Decimal128(3, 1));
+        result.validate_decimal_precision(3).unwrap();
+        let expected = Decimal128Array::from(vec![Some(999), None, None, None])
+            .with_precision_and_scale(3, 1)
+            .unwrap();
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_decimal256_iter() {
         let mut builder = Decimal256Builder::with_capacity(30);
@@ -158,6 +158,12 @@ impl BooleanBuilder {
         }
     }
 
+    /// Shrinks the builder's buffers to fit, see [`crate::builder::PrimitiveBuilder::shrink_to_fit`]
+    pub fn shrink_to_fit(&mut self) {
+        self.values_builder.shrink_to_fit();
+        self.null_buffer_builder.shrink_to_fit();
+    }
+
     /// Builds the [BooleanArray] and reset this builder.
     pub fn finish(&mut self) -> BooleanArray {
         let len = self.len();
@@ -405,4 +411,17 @@ mod tests {
         assert_eq!(actual, array);
         assert_eq!(actual.values(), array.values())
     }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut builder = BooleanBuilder::with_capacity(100_000);
+        builder.append_value(true);
+        builder.append_null();
+        builder.shrink_to_fit();
+        assert_eq!(builder.capacity(), 512);
+
+        let array = builder.finish();
+        assert_eq!(array, BooleanArray::from(vec![Some(true), None]));
+        assert_eq!(array.values().inner().capacity(), 64);
+    }
 }
@@ -98,6 +98,12 @@ impl FixedSizeBinaryBuilder {
         self.values_builder.as_slice()
     }
 
+    /// Shrinks the builder's buffers to fit, see [`crate::builder::PrimitiveBuilder::shrink_to_fit`]
+    pub fn shrink_to_fit(&mut self) {
+        self.values_builder.shrink_to_fit();
+        self.null_buffer_builder.shrink_to_fit();
+    }
+
     /// Builds the [`FixedSizeBinaryArray`] and reset this builder.
     pub fn finish(&mut self) -> FixedSizeBinaryArray {
         let array_length = self.len();
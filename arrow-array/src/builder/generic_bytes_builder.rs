@@ -171,6 +171,13 @@ impl<T: ByteArrayType> GenericByteBuilder<T> {
         }
     }
 
+    /// Shrinks the builder's buffers to fit, see [`crate::builder::PrimitiveBuilder::shrink_to_fit`]
+    pub fn shrink_to_fit(&mut self) {
+        self.value_builder.shrink_to_fit();
+        self.offsets_builder.shrink_to_fit();
+        self.null_buffer_builder.shrink_to_fit();
+    }
+
     /// Builds the [`GenericByteArray`] and reset this builder.
     pub fn finish(&mut self) -> GenericByteArray<T> {
         let array_type = T::DATA_TYPE;
@@ -810,4 +817,19 @@ mod tests {
 
         assert_eq!(actual, full_array);
     }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut builder = GenericStringBuilder::<i32>::with_capacity(1000, 10_000);
+        builder.append_value("hello");
+        builder.append_null();
+        builder.shrink_to_fit();
+
+        let array = builder.finish();
+        let expected = GenericStringArray::<i32>::from(vec![Some("hello"), None]);
+        assert_eq!(array, expected);
+        assert_eq!(array.values().capacity(), 64);
+        assert_eq!(array.offsets().inner().inner().capacity(), 64);
+        assert_eq!(array.nulls().unwrap().buffer().capacity(), 64);
+    }
 }
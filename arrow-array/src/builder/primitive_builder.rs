@@ -294,6 +294,27 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
         self.values_builder.append_trusted_len_iter(iter);
     }
 
+    /// Shrinks the capacity of the builder's buffers as much as possible, such that
+    /// the array returned by the next call to [`Self::finish`] does not hold excess
+    /// memory, which is useful for arrays that are kept for a long time.
+    ///
+    /// This reallocates the buffers, potentially copying their contents, if they
+    /// have excess capacity.
+    ///
+    /// ```
+    /// # use arrow_array::builder::Int64Builder;
+    /// let mut builder = Int64Builder::with_capacity(1024);
+    /// builder.append_slice(&[1, 2, 3]);
+    /// builder.shrink_to_fit();
+    /// assert_eq!(builder.capacity(), 8);
+    /// let array = builder.finish();
+    /// assert_eq!(array.values().inner().capacity(), 64);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.values_builder.shrink_to_fit();
+        self.null_buffer_builder.shrink_to_fit();
+    }
+
     /// Builds the [`PrimitiveArray`] and reset this builder.
     pub fn finish(&mut self) -> PrimitiveArray<T> {
         let len = self.len();
@@ -697,4 +718,19 @@ mod tests {
         let mut builder = Decimal128Builder::new().with_data_type(DataType::Decimal128(2, 3));
         builder.append_array(&array)
     }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut builder = Int32Builder::with_capacity(10_000);
+        builder.append_value(1);
+        builder.append_null();
+        builder.append_value(3);
+        builder.shrink_to_fit();
+        assert_eq!(builder.capacity(), 16);
+
+        let array = builder.finish();
+        assert_eq!(array, Int32Array::from(vec![Some(1), None, Some(3)]));
+        assert_eq!(array.values().inner().capacity(), 64);
+        assert_eq!(array.nulls().unwrap().buffer().capacity(), 64);
+    }
 }
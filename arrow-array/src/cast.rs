@@ -18,8 +18,10 @@
 //! Defines helper functions for downcasting [`dyn Array`](Array) to concrete types
 
 use crate::array::*;
+use crate::iterator::PrimitiveIter;
 use crate::types::*;
 use arrow_data::ArrayData;
+use arrow_schema::{ArrowError, DataType};
 
 /// Re-export symbols needed for downcast macros
 ///
@@ -807,6 +809,10 @@ mod private {
     pub trait Sealed {}
 }
 
+fn downcast_error(expected: impl std::fmt::Display, actual: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("Expected {expected} array, got {actual}"))
+}
+
 /// An extension trait for `dyn Array` that provides ergonomic downcasting
 ///
 /// ```
@@ -817,7 +823,7 @@ mod private {
 /// let col = Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef;
 /// assert_eq!(col.as_primitive::<Int32Type>().values(), &[1, 2, 3]);
 /// ```
-pub trait AsArray: private::Sealed {
+pub trait AsArray: private::Sealed + Array {
     /// Downcast this to a [`BooleanArray`] returning `None` if not possible
     fn as_boolean_opt(&self) -> Option<&BooleanArray>;
 
@@ -834,6 +840,59 @@ pub trait AsArray: private::Sealed {
         self.as_primitive_opt().expect("primitive array")
     }
 
+    /// Downcast this to a [`PrimitiveArray`] returning an error naming the expected
+    /// and actual data types if not possible
+    ///
+    /// ```
+    /// # use arrow_array::{Array, Int32Array};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::{Int32Type, Int64Type};
+    /// let array = Int32Array::from(vec![1, 2, 3]);
+    /// let array: &dyn Array = &array;
+    /// assert_eq!(array.try_as_primitive::<Int32Type>().unwrap().values(), &[1, 2, 3]);
+    ///
+    /// let err = array.try_as_primitive::<Int64Type>().unwrap_err();
+    /// assert_eq!(err.to_string(), "Invalid argument error: Expected Int64 array, got Int32");
+    /// ```
+    fn try_as_primitive<T: ArrowPrimitiveType>(&self) -> Result<&PrimitiveArray<T>, ArrowError> {
+        self.as_primitive_opt()
+            .ok_or_else(|| downcast_error(T::DATA_TYPE, self.data_type()))
+    }
+
+    /// Downcast this to a [`PrimitiveArray`] and iterate its values, returning `None`
+    /// if not possible
+    fn iter_as_opt<T: ArrowPrimitiveType>(&self) -> Option<PrimitiveIter<'_, T>> {
+        self.as_primitive_opt().map(|a| a.iter())
+    }
+
+    /// Downcast this to a [`PrimitiveArray`] and iterate its values, panicking if
+    /// not possible
+    ///
+    /// This allows writing code generic over [`ArrowPrimitiveType`] against a
+    /// [`dyn Array`](Array)
+    ///
+    /// ```
+    /// # use arrow_array::{Array, Float64Array, Int32Array};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::{ArrowPrimitiveType, Float64Type, Int32Type};
+    /// fn count_positive<T: ArrowPrimitiveType>(array: &dyn Array) -> usize {
+    ///     array
+    ///         .iter_as::<T>()
+    ///         .flatten()
+    ///         .filter(|v| *v > T::default_value())
+    ///         .count()
+    /// }
+    ///
+    /// let array = Int32Array::from(vec![Some(1), None, Some(-2), Some(3)]);
+    /// assert_eq!(count_positive::<Int32Type>(&array), 2);
+    ///
+    /// let array = Float64Array::from(vec![0.5, -1.0]);
+    /// assert_eq!(count_positive::<Float64Type>(&array), 1);
+    /// ```
+    fn iter_as<T: ArrowPrimitiveType>(&self) -> PrimitiveIter<'_, T> {
+        self.as_primitive().iter()
+    }
+
     /// Downcast this to a [`GenericByteArray`] returning `None` if not possible
     fn as_bytes_opt<T: ByteArrayType>(&self) -> Option<&GenericByteArray<T>>;
 
@@ -852,6 +911,13 @@ pub trait AsArray: private::Sealed {
         self.as_bytes_opt().expect("string array")
     }
 
+    /// Downcast this to a [`GenericStringArray`] returning an error naming the
+    /// expected and actual data types if not possible
+    fn try_as_string<O: OffsetSizeTrait>(&self) -> Result<&GenericStringArray<O>, ArrowError> {
+        self.as_string_opt()
+            .ok_or_else(|| downcast_error(GenericStringType::<O>::DATA_TYPE, self.data_type()))
+    }
+
     /// Downcast this to a [`GenericBinaryArray`] returning `None` if not possible
     fn as_binary_opt<O: OffsetSizeTrait>(&self) -> Option<&GenericBinaryArray<O>> {
         self.as_bytes_opt()
@@ -914,6 +980,13 @@ pub trait AsArray: private::Sealed {
         self.as_list_opt().expect("list array")
     }
 
+    /// Downcast this to a [`GenericListArray`] returning an error naming the
+    /// expected and actual data types if not possible
+    fn try_as_list<O: OffsetSizeTrait>(&self) -> Result<&GenericListArray<O>, ArrowError> {
+        self.as_list_opt()
+            .ok_or_else(|| downcast_error(format_args!("{}List", O::PREFIX), self.data_type()))
+    }
+
     /// Downcast this to a [`GenericListViewArray`] returning `None` if not possible
     fn as_list_view_opt<O: OffsetSizeTrait>(&self) -> Option<&GenericListViewArray<O>>;
 
@@ -1106,6 +1179,24 @@ mod tests {
         assert!(!as_string_array(&array).is_empty())
     }
 
+    #[test]
+    fn test_iter_as() {
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let values: Vec<_> = array.iter_as::<Int32Type>().collect();
+        assert_eq!(values, vec![Some(1), None, Some(3)]);
+
+        let array = array.as_ref();
+        assert_eq!(array.iter_as::<Int32Type>().len(), 3);
+        assert!(array.iter_as_opt::<Int64Type>().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "primitive array")]
+    fn test_iter_as_wrong_type() {
+        let array: ArrayRef = Arc::new(StringArray::from(vec!["a"]));
+        array.iter_as::<Int32Type>();
+    }
+
     #[test]
     fn test_decimal128array() {
         let a = Decimal128Array::from_iter_values([1, 2, 4, 5]);
@@ -1159,4 +1250,44 @@ mod tests {
             _ => panic!("unexpected data type")
         );
     }
+
+    #[test]
+    fn test_try_as() {
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        assert_eq!(
+            array.try_as_primitive::<Int32Type>().unwrap().values(),
+            &[1, 2]
+        );
+        let err = array.try_as_primitive::<Float64Type>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected Float64 array, got Int32"
+        );
+
+        // The time zone is not part of the downcast
+        let array: ArrayRef = Arc::new(TimestampSecondArray::from(vec![1]).with_timezone("+01:00"));
+        assert!(array.try_as_primitive::<TimestampSecondType>().is_ok());
+
+        let array: ArrayRef = Arc::new(StringArray::from(vec!["a"]));
+        assert_eq!(array.try_as_string::<i32>().unwrap().value(0), "a");
+        let err = array.as_ref().try_as_string::<i64>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected LargeUtf8 array, got Utf8"
+        );
+        let err = array.try_as_list::<i32>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected List array, got Utf8"
+        );
+
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>([Some([Some(1)])]);
+        let array: ArrayRef = Arc::new(list);
+        assert_eq!(array.try_as_list::<i32>().unwrap().len(), 1);
+        let err = array.try_as_list::<i64>().unwrap_err().to_string();
+        assert!(
+            err.starts_with("Invalid argument error: Expected LargeList array, got List("),
+            "{err}"
+        );
+    }
 }
@@ -403,6 +403,10 @@ impl RecordBatch {
     }
 
     /// Projects the schema onto the specified columns
+    ///
+    /// The columns are returned in the order of `indices`, which may contain an index
+    /// more than once to repeat a column. This returns an error if an index is out of
+    /// bounds.
     pub fn project(&self, indices: &[usize]) -> Result<RecordBatch, ArrowError> {
         let projected_schema = self.schema.project(indices)?;
         let batch_fields = indices
@@ -1523,6 +1527,26 @@ mod tests {
         assert_eq!(expected, record_batch.project(&[0, 2]).unwrap());
     }
 
+    #[test]
+    fn project_reorder_and_repeat() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+
+        let record_batch = RecordBatch::try_from_iter(vec![("a", a.clone()), ("b", b.clone())])
+            .expect("valid conversion");
+
+        let projected = record_batch.project(&[1, 0, 1]).unwrap();
+        let expected = RecordBatch::try_from_iter(vec![("b", b.clone()), ("a", a), ("b", b)])
+            .expect("valid conversion");
+        assert_eq!(expected, projected);
+
+        let err = record_batch.project(&[0, 2]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: project index 2 out of bounds, max field 2"
+        );
+    }
+
     #[test]
     fn project_empty() {
         let c: ArrayRef = Arc::new(StringArray::from(vec!["d", "e", "f"]));
@@ -227,6 +227,50 @@ impl i256 {
         value_str.parse().ok()
     }
 
+    /// Parses an integer from a string in the given base
+    ///
+    /// The string is expected to be an optional `+` or `-` sign followed by digits,
+    /// where digits are `0-9`, `a-z` and `A-Z` depending on `radix`, matching the
+    /// behaviour of [`i128::from_str_radix`]. Leading zeros are permitted.
+    ///
+    /// Returns an error if the string is empty, contains invalid digits, or the
+    /// value does not fit in an `i256`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseI256Error> {
+        assert!(
+            (2..=36).contains(&radix),
+            "from_str_radix: radix must lie in the range `[2, 36]` - found {radix}"
+        );
+        if radix == 10 {
+            return src.parse();
+        }
+
+        let (negative, digits) = match src.as_bytes().first() {
+            Some(b'-') => (true, &src[1..]),
+            Some(b'+') => (false, &src[1..]),
+            _ => (false, src),
+        };
+        if digits.is_empty() {
+            return Err(ParseI256Error {});
+        }
+
+        let base = Self::from_i128(radix as i128);
+        digits.chars().try_fold(Self::ZERO, |acc, c| {
+            let digit = c.to_digit(radix).ok_or(ParseI256Error {})?;
+            let acc = acc.checked_mul(base).ok_or(ParseI256Error {})?;
+            // Accumulate negative values directly so that i256::MIN can be parsed
+            let digit = Self::from_i128(digit as i128);
+            match negative {
+                true => acc.checked_sub(digit),
+                false => acc.checked_add(digit),
+            }
+            .ok_or(ParseI256Error {})
+        })
+    }
+
     /// Create an optional i256 from the provided `f64`. Returning `None`
     /// if overflow occurred
     pub fn from_f64(v: f64) -> Option<Self> {
@@ -354,7 +398,7 @@ impl i256 {
         Self { low, high }
     }
 
-    /// Performs checked addition
+    /// Performs checked addition, returning `None` if overflow occurred
     #[inline]
     pub fn checked_add(self, other: Self) -> Option<Self> {
         let r = self.wrapping_add(other);
@@ -369,7 +413,7 @@ impl i256 {
         Self { low, high }
     }
 
-    /// Performs checked subtraction
+    /// Performs checked subtraction, returning `None` if overflow occurred
     #[inline]
     pub fn checked_sub(self, other: Self) -> Option<Self> {
         let r = self.wrapping_sub(other);
@@ -391,7 +435,7 @@ impl i256 {
         }
     }
 
-    /// Performs checked multiplication
+    /// Performs checked multiplication, returning `None` if overflow occurred
     #[inline]
     pub fn checked_mul(self, other: Self) -> Option<Self> {
         if self == i256::ZERO || other == i256::ZERO {
@@ -490,7 +534,7 @@ impl i256 {
         }
     }
 
-    /// Performs checked division
+    /// Performs checked division, returning `None` if `other == 0` or overflow occurred
     #[inline]
     pub fn checked_div(self, other: Self) -> Option<Self> {
         self.div_rem(other).map(|(v, _)| v).ok()
@@ -506,13 +550,13 @@ impl i256 {
         }
     }
 
-    /// Performs checked remainder
+    /// Performs checked remainder, returning `None` if `other == 0` or overflow occurred
     #[inline]
     pub fn checked_rem(self, other: Self) -> Option<Self> {
         self.div_rem(other).map(|(_, v)| v).ok()
     }
 
-    /// Performs checked exponentiation
+    /// Performs checked exponentiation, returning `None` if overflow occurred
     #[inline]
     pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
         if exp == 0 {
@@ -1067,6 +1111,105 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_checked_boundaries() {
+        // Addition and subtraction
+        assert_eq!(i256::MAX.checked_add(i256::ONE), None);
+        assert_eq!(
+            i256::MAX.checked_add(i256::MINUS_ONE),
+            Some(i256::MAX - i256::ONE)
+        );
+        assert_eq!(i256::MIN.checked_add(i256::MINUS_ONE), None);
+        assert_eq!(i256::MIN.checked_add(i256::MAX), Some(i256::MINUS_ONE));
+        assert_eq!(i256::MIN.checked_sub(i256::ONE), None);
+        assert_eq!(i256::MAX.checked_sub(i256::MINUS_ONE), None);
+        assert_eq!(
+            i256::ZERO.checked_sub(i256::MAX),
+            Some(i256::MIN + i256::ONE)
+        );
+        assert_eq!(i256::MINUS_ONE.checked_sub(i256::MAX), Some(i256::MIN));
+        assert_eq!(i256::ZERO.checked_sub(i256::MIN), None);
+
+        // Carry propagation between the low and high parts
+        let low_max = i256::from_parts(u128::MAX, 0);
+        assert_eq!(low_max.checked_add(i256::ONE), Some(i256::from_parts(0, 1)));
+        assert_eq!(i256::from_parts(0, 1).checked_sub(i256::ONE), Some(low_max));
+
+        // Multiplication
+        assert_eq!(i256::MAX.checked_mul(i256::ONE), Some(i256::MAX));
+        assert_eq!(
+            i256::MAX.checked_mul(i256::MINUS_ONE),
+            Some(i256::MIN + i256::ONE)
+        );
+        assert_eq!(i256::MIN.checked_mul(i256::ONE), Some(i256::MIN));
+        assert_eq!(i256::MIN.checked_mul(i256::MINUS_ONE), None);
+        assert_eq!(i256::MAX.checked_mul(i256::from_i128(2)), None);
+        assert_eq!(
+            (i256::MIN >> 1).checked_mul(i256::from_i128(2)),
+            Some(i256::MIN)
+        );
+        assert_eq!(
+            (i256::MAX >> 1).checked_mul(i256::from_i128(-2)),
+            Some(i256::MIN + i256::from_i128(2))
+        );
+
+        // Multiplication by powers of ten, as used when rescaling decimals
+        let ten = i256::from_i128(10);
+        let max_pow = ten.checked_pow(76).unwrap();
+        assert_eq!(max_pow.to_string(), format!("1{}", "0".repeat(76)));
+        assert_eq!(ten.checked_pow(77), None);
+        assert_eq!(ten.wrapping_neg().checked_pow(76), Some(max_pow));
+        assert_eq!(
+            ten.wrapping_neg().checked_pow(75),
+            Some(max_pow.wrapping_div(ten).wrapping_neg())
+        );
+        for exp in [1, 10, 38, 39, 50, 76] {
+            let mul = ten.checked_pow(exp).unwrap();
+            let max = i256::MAX.checked_div(mul).unwrap();
+            let min = i256::MIN.checked_div(mul).unwrap();
+
+            let scaled = max.checked_mul(mul).unwrap();
+            assert_eq!(
+                i256::MAX.checked_sub(scaled).unwrap(),
+                i256::MAX.checked_rem(mul).unwrap()
+            );
+            assert_eq!(max.checked_add(i256::ONE).unwrap().checked_mul(mul), None);
+
+            let scaled = min.checked_mul(mul).unwrap();
+            assert_eq!(
+                i256::MIN.checked_sub(scaled).unwrap(),
+                i256::MIN.checked_rem(mul).unwrap()
+            );
+            assert_eq!(min.checked_sub(i256::ONE).unwrap().checked_mul(mul), None);
+        }
+
+        // Division
+        assert_eq!(i256::MAX.checked_div(i256::ZERO), None);
+        assert_eq!(i256::MIN.checked_div(i256::MINUS_ONE), None);
+        assert_eq!(i256::MIN.checked_div(i256::ONE), Some(i256::MIN));
+        assert_eq!(
+            i256::MAX.checked_div(i256::MINUS_ONE),
+            Some(i256::MIN + i256::ONE)
+        );
+        assert_eq!(i256::MIN.checked_div(i256::MIN), Some(i256::ONE));
+        assert_eq!(i256::MAX.checked_div(i256::MIN), Some(i256::ZERO));
+        assert_eq!(
+            i256::from_i128(-7).checked_div(i256::from_i128(2)),
+            Some(i256::from_i128(-3))
+        );
+
+        // Exponentiation
+        let two = i256::from_i128(2);
+        assert_eq!(two.checked_pow(254), Some(i256::ONE << 254));
+        assert_eq!(two.checked_pow(255), None);
+        assert_eq!(two.wrapping_neg().checked_pow(255), Some(i256::MIN));
+        assert_eq!(two.wrapping_neg().checked_pow(256), None);
+        assert_eq!(i256::MINUS_ONE.checked_pow(u32::MAX), Some(i256::MINUS_ONE));
+        assert_eq!(i256::ZERO.checked_pow(0), Some(i256::ONE));
+        assert_eq!(i256::MAX.checked_pow(1), Some(i256::MAX));
+        assert_eq!(i256::MIN.checked_pow(2), None);
+    }
+
     #[test]
     fn test_signed_ops() {
         // signum
@@ -1212,6 +1355,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_str_radix() {
+        let cases = [
+            ("0", 2, Some(i256::ZERO)),
+            ("-0", 16, Some(i256::ZERO)),
+            ("+101", 2, Some(i256::from_i128(5))),
+            ("-101", 2, Some(i256::from_i128(-5))),
+            ("00000ff", 16, Some(i256::from_i128(255))),
+            ("FF", 16, Some(i256::from_i128(255))),
+            ("zz", 36, Some(i256::from_i128(1295))),
+            ("-123", 10, Some(i256::from_i128(-123))),
+            ("", 16, None),
+            ("-", 16, None),
+            ("+", 2, None),
+            ("--1", 16, None),
+            ("102", 2, None),
+            ("1g", 16, None),
+            ("1 ", 16, None),
+        ];
+        for (case, radix, expected) in cases {
+            assert_eq!(i256::from_str_radix(case, radix).ok(), expected, "{case}");
+        }
+
+        let max_hex = format!("7{}", "f".repeat(63));
+        assert_eq!(i256::from_str_radix(&max_hex, 16).unwrap(), i256::MAX);
+        let min_hex = format!("-8{}", "0".repeat(63));
+        assert_eq!(i256::from_str_radix(&min_hex, 16).unwrap(), i256::MIN);
+
+        // Overflow by one
+        let overflow = format!("8{}", "0".repeat(63));
+        i256::from_str_radix(&overflow, 16).unwrap_err();
+        let underflow = format!("-8{}1", "0".repeat(62));
+        i256::from_str_radix(&underflow, 16).unwrap_err();
+        let too_long = format!("1{}", "0".repeat(255));
+        i256::from_str_radix(&too_long, 2).unwrap_err();
+
+        let max_bin = format!("0{}", "1".repeat(255));
+        assert_eq!(i256::from_str_radix(&max_bin, 2).unwrap(), i256::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must lie in the range `[2, 36]` - found 37")]
+    fn test_from_str_radix_invalid_radix() {
+        let _ = i256::from_str_radix("1", 37);
+    }
+
     #[allow(clippy::op_ref)]
     fn test_reference_op(il: i256, ir: i256) {
         let r1 = il + ir;
@@ -15,12 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::alloc::Layout;
+use std::alloc::{handle_alloc_error, Layout};
 use std::fmt::Debug;
 use std::ptr::NonNull;
 use std::sync::Arc;
 
-use crate::alloc::{Allocation, Deallocation};
+use crate::alloc::{Allocation, Deallocation, ALIGNMENT};
 use crate::util::bit_chunk_iterator::{BitChunks, UnalignedBitChunk};
 use crate::BufferBuilder;
 use crate::{bit_util, bytes::Bytes, native::ArrowNativeType};
@@ -300,6 +300,30 @@ impl Buffer {
         }
     }
 
+    /// Returns a [`Buffer`] spanning both `self` and `other` if `other` starts
+    /// exactly where `self` ends within the same underlying allocation, or `None`
+    /// otherwise.
+    ///
+    /// This is the inverse of splitting a buffer with [`Self::slice_with_length`],
+    /// and is `O(1)` without copying any data.
+    ///
+    /// ```
+    /// # use arrow_buffer::Buffer;
+    /// let buffer = Buffer::from_slice_ref([1_u8, 2, 3, 4]);
+    /// let (a, b) = (buffer.slice_with_length(0, 1), buffer.slice(1));
+    /// assert!(a.try_join(&b).unwrap().ptr_eq(&buffer));
+    /// assert!(b.try_join(&a).is_none());
+    /// ```
+    pub fn try_join(&self, other: &Self) -> Option<Self> {
+        let contiguous =
+            Arc::ptr_eq(&self.data, &other.data) && self.ptr.wrapping_add(self.length) == other.ptr;
+        contiguous.then(|| Self {
+            data: self.data.clone(),
+            ptr: self.ptr,
+            length: self.length + other.length,
+        })
+    }
+
     /// Returns a pointer to the start of this buffer.
     ///
     /// Note that this should be used cautiously, and the returned pointer should not be
@@ -324,6 +348,58 @@ impl Buffer {
         offsets
     }
 
+    /// Returns true if the start of this buffer is aligned to a multiple of `align` bytes
+    ///
+    /// Buffers created from a [`MutableBuffer`] are aligned to [`ALIGNMENT`], however,
+    /// slicing or importing memory via FFI may yield buffers with weaker alignment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two
+    ///
+    /// [`ALIGNMENT`]: crate::alloc::ALIGNMENT
+    #[inline]
+    pub fn is_aligned_to(&self, align: usize) -> bool {
+        assert!(
+            align.is_power_of_two(),
+            "alignment must be a power of two, got {align}"
+        );
+        self.ptr as usize & (align - 1) == 0
+    }
+
+    /// Returns a [`Buffer`] containing the same bytes as this buffer, whose start
+    /// is aligned to a multiple of `align` bytes
+    ///
+    /// If this buffer is already aligned this is `O(1)` and returns a shallow clone,
+    /// otherwise the contents are copied into a new allocation, which is `O(n)` in
+    /// the length of the buffer. The returned buffer never shares memory with `self`
+    /// in this case, and so any subsequent slicing is relative to the new allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two
+    pub fn realign(&self, align: usize) -> Self {
+        if self.is_aligned_to(align) {
+            return self.clone();
+        }
+
+        if align <= ALIGNMENT {
+            return Self::from_slice_ref(self.as_slice());
+        }
+
+        // Allocate at least one byte so that the returned pointer has the requested alignment
+        let layout = Layout::from_size_align(self.length.max(1), align)
+            .expect("failed to create layout for Buffer");
+        // Safety: Verified size != 0
+        let raw_ptr = unsafe { std::alloc::alloc(layout) };
+        let ptr = NonNull::new(raw_ptr).unwrap_or_else(|| handle_alloc_error(layout));
+        // Safety: `ptr` is valid for `self.length` bytes and does not overlap `self`
+        unsafe {
+            std::ptr::copy_nonoverlapping(self.ptr, ptr.as_ptr(), self.length);
+            Buffer::build_with_arguments(ptr, self.length, Deallocation::Standard(layout))
+        }
+    }
+
     /// Returns a slice of this buffer starting at a certain bit offset.
     /// If the offset is byte-aligned the returned buffer is a shallow clone,
     /// otherwise a new buffer is allocated and filled with a copy of the bits in the range.
@@ -1009,4 +1085,61 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_try_join() {
+        let buffer = Buffer::from_vec(vec![1_u32, 2, 3, 4, 5]);
+        let a = buffer.slice_with_length(0, 8);
+        let b = buffer.slice_with_length(8, 4);
+        let c = buffer.slice(12);
+
+        let joined = a.try_join(&b).unwrap();
+        assert_eq!(joined.typed_data::<u32>(), &[1, 2, 3]);
+        let joined = joined.try_join(&c).unwrap();
+        assert!(joined.ptr_eq(&buffer));
+
+        // Out of order or overlapping slices
+        assert!(b.try_join(&a).is_none());
+        assert!(a.try_join(&c).is_none());
+        assert!(a.try_join(&a).is_none());
+
+        // Different allocations
+        let other = Buffer::from_vec(vec![1_u32, 2, 3, 4, 5]);
+        assert!(a.try_join(&other.slice(8)).is_none());
+
+        // Empty slices
+        let empty = buffer.slice_with_length(8, 0);
+        assert!(a.try_join(&empty).unwrap().ptr_eq(&a));
+    }
+
+    #[test]
+    fn test_realign() {
+        let buffer = Buffer::from_vec(vec![1_u64, 2, 3, 4]);
+        assert!(buffer.is_aligned_to(8));
+
+        let sliced = buffer.slice(1);
+        assert!(!sliced.is_aligned_to(2));
+
+        // Already aligned buffers are not copied
+        let aligned = sliced.realign(1);
+        assert!(aligned.ptr_eq(&sliced));
+
+        for align in [2, 8, ALIGNMENT, ALIGNMENT * 4] {
+            let aligned = sliced.realign(align);
+            assert!(aligned.is_aligned_to(align));
+            assert!(!aligned.ptr_eq(&sliced));
+            assert_eq!(aligned.as_slice(), sliced.as_slice());
+        }
+
+        let empty = buffer.slice_with_length(1, 0);
+        let aligned = empty.realign(ALIGNMENT * 4);
+        assert!(aligned.is_aligned_to(ALIGNMENT * 4));
+        assert!(aligned.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "alignment must be a power of two, got 3")]
+    fn test_is_aligned_to_non_power_of_two() {
+        Buffer::from_vec(vec![1_u8]).is_aligned_to(3);
+    }
 }
@@ -106,6 +106,12 @@ impl BooleanBufferBuilder {
         self.buffer.capacity() * 8
     }
 
+    /// Shrinks the capacity of the internal buffer as much as possible, see
+    /// [`MutableBuffer::shrink_to_fit`]
+    pub fn shrink_to_fit(&mut self) {
+        self.buffer.shrink_to_fit();
+    }
+
     /// Advances the buffer by `additional` bits
     #[inline]
     pub fn advance(&mut self, additional: usize) {
@@ -138,6 +138,22 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
         byte_capacity / std::mem::size_of::<T>()
     }
 
+    /// Shrinks the capacity of the internal buffer as much as possible, see
+    /// [`MutableBuffer::shrink_to_fit`]
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use arrow_buffer::builder::BufferBuilder;
+    /// let mut builder = BufferBuilder::<u32>::new(1024);
+    /// builder.append_slice(&[1, 2, 3]);
+    /// builder.shrink_to_fit();
+    /// assert_eq!(builder.capacity(), 16);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.buffer.shrink_to_fit();
+    }
+
     /// Increases the number of elements in the internal buffer by `n`
     /// and resizes the buffer as needed.
     ///
@@ -231,6 +231,14 @@ impl NullBufferBuilder {
         self.bitmap_builder.as_mut().map(|b| b.as_slice_mut())
     }
 
+    /// Shrinks the capacity of the internal buffer, if materialized, as much as
+    /// possible, see [`BooleanBufferBuilder::shrink_to_fit`]
+    pub fn shrink_to_fit(&mut self) {
+        if let Some(b) = self.bitmap_builder.as_mut() {
+            b.shrink_to_fit()
+        }
+    }
+
     /// Return the allocated size of this builder, in bytes, useful for memory accounting.
     pub fn allocated_size(&self) -> usize {
         self.bitmap_builder
@@ -39,6 +39,18 @@ pub(crate) fn dictionary_cast<K: ArrowDictionaryKeyType>(
                     )
                 })?;
 
+            if to_index_type.as_ref() == &K::DATA_TYPE {
+                // Only the values need to be cast, the keys can be reused as is
+                let cast_values =
+                    cast_with_options(dict_array.values(), to_value_type, cast_options)?;
+                // Safety
+                // Casting preserves the length of the values, so the keys are still valid
+                let cast_dict = unsafe {
+                    DictionaryArray::new_unchecked(dict_array.keys().clone(), cast_values)
+                };
+                return Ok(Arc::new(cast_dict));
+            }
+
             let keys_array: ArrayRef =
                 Arc::new(PrimitiveArray::<K>::from(dict_array.keys().to_data()));
             let values_array = dict_array.values();
@@ -60,7 +60,7 @@ use crate::parse::{
     string_to_datetime, Parser,
 };
 use arrow_array::{builder::*, cast::*, temporal_conversions::*, timezone::Tz, types::*, *};
-use arrow_buffer::{i256, ArrowNativeType, OffsetBuffer};
+use arrow_buffer::{i256, ArrowNativeType, NullBuffer, OffsetBuffer};
 use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayData;
 use arrow_schema::*;
@@ -307,6 +307,123 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<ArrayRef, ArrowErro
     cast_with_options(array, to_type, &CastOptions::default())
 }
 
+/// Take elements by index from `values` and cast the result to `to_type`
+///
+/// This is equivalent to calling [`take`] followed by [`cast`], except that lossless
+/// numeric widenings, such as `Int32` to `Int64`, are performed while gathering,
+/// avoiding materializing the intermediate array. Other casts use the default
+/// [`CastOptions`].
+///
+/// # Errors
+/// This function errors if an index is out of bounds or the cast is not supported
+///
+/// ```
+/// # use arrow_array::{Array, Int32Array, Int64Array, UInt32Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int64Type;
+/// # use arrow_cast::take_cast;
+/// # use arrow_schema::DataType;
+/// let values = Int32Array::from(vec![1, 2, 3]);
+/// let indices = UInt32Array::from(vec![Some(2), None, Some(0)]);
+/// let taken = take_cast(&values, &indices, &DataType::Int64).unwrap();
+/// assert_eq!(taken.as_primitive::<Int64Type>(), &Int64Array::from(vec![Some(3), None, Some(1)]));
+/// ```
+pub fn take_cast(
+    values: &dyn Array,
+    indices: &dyn Array,
+    to_type: &DataType,
+) -> Result<ArrayRef, ArrowError> {
+    use DataType::*;
+    let widened = match (values.data_type(), to_type) {
+        (Int8, Int16) => take_widen::<Int8Type, Int16Type>(values, indices),
+        (Int8, Int32) => take_widen::<Int8Type, Int32Type>(values, indices),
+        (Int8, Int64) => take_widen::<Int8Type, Int64Type>(values, indices),
+        (Int16, Int32) => take_widen::<Int16Type, Int32Type>(values, indices),
+        (Int16, Int64) => take_widen::<Int16Type, Int64Type>(values, indices),
+        (Int32, Int64) => take_widen::<Int32Type, Int64Type>(values, indices),
+        (UInt8, UInt16) => take_widen::<UInt8Type, UInt16Type>(values, indices),
+        (UInt8, UInt32) => take_widen::<UInt8Type, UInt32Type>(values, indices),
+        (UInt8, UInt64) => take_widen::<UInt8Type, UInt64Type>(values, indices),
+        (UInt8, Int16) => take_widen::<UInt8Type, Int16Type>(values, indices),
+        (UInt8, Int32) => take_widen::<UInt8Type, Int32Type>(values, indices),
+        (UInt8, Int64) => take_widen::<UInt8Type, Int64Type>(values, indices),
+        (UInt16, UInt32) => take_widen::<UInt16Type, UInt32Type>(values, indices),
+        (UInt16, UInt64) => take_widen::<UInt16Type, UInt64Type>(values, indices),
+        (UInt16, Int32) => take_widen::<UInt16Type, Int32Type>(values, indices),
+        (UInt16, Int64) => take_widen::<UInt16Type, Int64Type>(values, indices),
+        (UInt32, UInt64) => take_widen::<UInt32Type, UInt64Type>(values, indices),
+        (UInt32, Int64) => take_widen::<UInt32Type, Int64Type>(values, indices),
+        (Float32, Float64) => take_widen::<Float32Type, Float64Type>(values, indices),
+        _ => None,
+    };
+
+    match widened {
+        Some(array) => array,
+        None => cast(&take(values, indices, None)?, to_type),
+    }
+}
+
+/// Gathers and widens the values of a primitive array in a single pass, returning
+/// `None` if `indices` are not integers
+fn take_widen<T: ArrowPrimitiveType, O: ArrowPrimitiveType>(
+    values: &dyn Array,
+    indices: &dyn Array,
+) -> Option<Result<ArrayRef, ArrowError>>
+where
+    T::Native: AsPrimitive<O::Native>,
+{
+    macro_rules! take_widen_helper {
+        ($t:ty, $values:expr, $indices:expr) => {
+            Some(take_widen_impl::<T, O, $t>(
+                $values,
+                $indices.as_primitive::<$t>(),
+            ))
+        };
+    }
+
+    let values = values.as_primitive::<T>();
+    downcast_integer! {
+        indices.data_type() => (take_widen_helper, values, indices),
+        _ => None,
+    }
+}
+
+fn take_widen_impl<T: ArrowPrimitiveType, O: ArrowPrimitiveType, I: ArrowPrimitiveType>(
+    values: &PrimitiveArray<T>,
+    indices: &PrimitiveArray<I>,
+) -> Result<ArrayRef, ArrowError>
+where
+    T::Native: AsPrimitive<O::Native>,
+{
+    let len = values.len();
+    let out = indices
+        .values()
+        .iter()
+        .enumerate()
+        .map(|(i, index)| match values.values().get(index.as_usize()) {
+            Some(v) => Ok(v.as_()),
+            None if indices.is_null(i) => Ok(O::Native::default()),
+            None => Err(ArrowError::ComputeError(format!(
+                "Array index out of bounds, cannot get item at index {} from {len} entries",
+                index.as_usize()
+            ))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let nulls = match values.null_count() {
+        0 => indices.nulls().cloned(),
+        _ => {
+            let valid = indices.iter().map(|index| match index {
+                Some(index) => values.is_valid(index.as_usize()),
+                None => false,
+            });
+            Some(NullBuffer::new(valid.collect())).filter(|n| n.null_count() > 0)
+        }
+    };
+
+    Ok(Arc::new(PrimitiveArray::<O>::new(out.into(), nulls)))
+}
+
 fn cast_integer_to_decimal<
     T: ArrowPrimitiveType,
     D: DecimalType + ArrowPrimitiveType<Native = M>,
@@ -496,6 +613,38 @@ fn cast_reinterpret_arrays<I: ArrowPrimitiveType, O: ArrowPrimitiveType<Native =
     Ok(Arc::new(array.as_primitive::<I>().reinterpret_cast::<O>()))
 }
 
+/// Casts time values of type `I` to the coarser unit of `O` by dividing them by
+/// `divisor`, erroring if `cast_options.safe` is false and this truncates a value
+fn cast_time_to_coarser_unit<I, O, F>(
+    array: &dyn Array,
+    divisor: I::Native,
+    cast_options: &CastOptions,
+    convert: F,
+) -> Result<ArrayRef, ArrowError>
+where
+    I: ArrowPrimitiveType,
+    O: ArrowPrimitiveType,
+    F: Fn(I::Native) -> O::Native,
+{
+    let array = array.as_primitive::<I>();
+    if !cast_options.safe {
+        let truncated = array
+            .iter()
+            .flatten()
+            .find(|x| x.mod_wrapping(divisor) != I::Native::ZERO);
+        if let Some(x) = truncated {
+            return Err(ArrowError::CastError(format!(
+                "Cannot cast {} value {x:?} to {} without losing precision",
+                I::DATA_TYPE,
+                O::DATA_TYPE
+            )));
+        }
+    }
+    Ok(Arc::new(
+        array.unary::<_, O>(|x| convert(x.div_wrapping(divisor))),
+    ))
+}
+
 fn make_timestamp_array(
     array: &PrimitiveArray<Int64Type>,
     unit: TimeUnit,
@@ -598,7 +747,8 @@ fn timestamp_to_date32<T: ArrowTimestampType>(
 ///   has the wrong length it will be replaced with NULL, otherwise an error will be returned
 /// * Primitive to `List`: a list array with 1 value per slot is created
 /// * `Date32` and `Date64`: precision lost when going to higher interval
-/// * `Time32 and `Time64`: precision lost when going to higher interval
+/// * `Time32 and `Time64`: precision lost when going to higher interval. If safe is false
+///   and a value would be truncated, an error is returned instead
 /// * `Timestamp` and `Date{32|64}`: precision lost when going to higher interval
 /// * Temporal to/from backing Primitive: zero-copy with data type change
 /// * `Float32/Float64` to `Decimal(precision, scale)` rounds to the `scale` decimals
@@ -1447,11 +1597,14 @@ pub fn cast_with_options(
                 .unary::<_, Time64NanosecondType>(|x| x as i64 * NANOSECONDS),
         )),
 
-        (Time32(TimeUnit::Millisecond), Time32(TimeUnit::Second)) => Ok(Arc::new(
-            array
-                .as_primitive::<Time32MillisecondType>()
-                .unary::<_, Time32SecondType>(|x| x / MILLISECONDS as i32),
-        )),
+        (Time32(TimeUnit::Millisecond), Time32(TimeUnit::Second)) => {
+            cast_time_to_coarser_unit::<Time32MillisecondType, Time32SecondType, _>(
+                array,
+                MILLISECONDS as i32,
+                cast_options,
+                |x| x,
+            )
+        }
         (Time32(TimeUnit::Millisecond), Time64(TimeUnit::Microsecond)) => Ok(Arc::new(
             array
                 .as_primitive::<Time32MillisecondType>()
@@ -1460,40 +1613,55 @@ pub fn cast_with_options(
         (Time32(TimeUnit::Millisecond), Time64(TimeUnit::Nanosecond)) => Ok(Arc::new(
             array
                 .as_primitive::<Time32MillisecondType>()
-                .unary::<_, Time64NanosecondType>(|x| x as i64 * (MICROSECONDS / NANOSECONDS)),
+                .unary::<_, Time64NanosecondType>(|x| x as i64 * (NANOSECONDS / MILLISECONDS)),
         )),
 
-        (Time64(TimeUnit::Microsecond), Time32(TimeUnit::Second)) => Ok(Arc::new(
-            array
-                .as_primitive::<Time64MicrosecondType>()
-                .unary::<_, Time32SecondType>(|x| (x / MICROSECONDS) as i32),
-        )),
-        (Time64(TimeUnit::Microsecond), Time32(TimeUnit::Millisecond)) => Ok(Arc::new(
-            array
-                .as_primitive::<Time64MicrosecondType>()
-                .unary::<_, Time32MillisecondType>(|x| (x / (MICROSECONDS / MILLISECONDS)) as i32),
-        )),
+        (Time64(TimeUnit::Microsecond), Time32(TimeUnit::Second)) => {
+            cast_time_to_coarser_unit::<Time64MicrosecondType, Time32SecondType, _>(
+                array,
+                MICROSECONDS,
+                cast_options,
+                |x| x as i32,
+            )
+        }
+        (Time64(TimeUnit::Microsecond), Time32(TimeUnit::Millisecond)) => {
+            cast_time_to_coarser_unit::<Time64MicrosecondType, Time32MillisecondType, _>(
+                array,
+                MICROSECONDS / MILLISECONDS,
+                cast_options,
+                |x| x as i32,
+            )
+        }
         (Time64(TimeUnit::Microsecond), Time64(TimeUnit::Nanosecond)) => Ok(Arc::new(
             array
                 .as_primitive::<Time64MicrosecondType>()
                 .unary::<_, Time64NanosecondType>(|x| x * (NANOSECONDS / MICROSECONDS)),
         )),
 
-        (Time64(TimeUnit::Nanosecond), Time32(TimeUnit::Second)) => Ok(Arc::new(
-            array
-                .as_primitive::<Time64NanosecondType>()
-                .unary::<_, Time32SecondType>(|x| (x / NANOSECONDS) as i32),
-        )),
-        (Time64(TimeUnit::Nanosecond), Time32(TimeUnit::Millisecond)) => Ok(Arc::new(
-            array
-                .as_primitive::<Time64NanosecondType>()
-                .unary::<_, Time32MillisecondType>(|x| (x / (NANOSECONDS / MILLISECONDS)) as i32),
-        )),
-        (Time64(TimeUnit::Nanosecond), Time64(TimeUnit::Microsecond)) => Ok(Arc::new(
-            array
-                .as_primitive::<Time64NanosecondType>()
-                .unary::<_, Time64MicrosecondType>(|x| x / (NANOSECONDS / MICROSECONDS)),
-        )),
+        (Time64(TimeUnit::Nanosecond), Time32(TimeUnit::Second)) => {
+            cast_time_to_coarser_unit::<Time64NanosecondType, Time32SecondType, _>(
+                array,
+                NANOSECONDS,
+                cast_options,
+                |x| x as i32,
+            )
+        }
+        (Time64(TimeUnit::Nanosecond), Time32(TimeUnit::Millisecond)) => {
+            cast_time_to_coarser_unit::<Time64NanosecondType, Time32MillisecondType, _>(
+                array,
+                NANOSECONDS / MILLISECONDS,
+                cast_options,
+                |x| x as i32,
+            )
+        }
+        (Time64(TimeUnit::Nanosecond), Time64(TimeUnit::Microsecond)) => {
+            cast_time_to_coarser_unit::<Time64NanosecondType, Time64MicrosecondType, _>(
+                array,
+                NANOSECONDS / MICROSECONDS,
+                cast_options,
+                |x| x,
+            )
+        }
 
         // Timestamp to integer/floating/decimals
         (Timestamp(TimeUnit::Second, _), _) if to_type.is_numeric() => {
@@ -2264,6 +2432,62 @@ where
     unsafe { PrimitiveArray::<T>::from_trusted_len_iter(iter) }
 }
 
+/// Converts a [`BinaryArray`] or [`LargeBinaryArray`] to a [`FixedSizeBinaryArray`],
+/// inferring the width from the length of its non-null values
+///
+/// An array containing only nulls is converted to a width of `0`
+///
+/// # Errors
+/// This function errors if the non-null values do not all have the same length, or
+/// `array` is not a binary array
+///
+/// ```
+/// # use arrow_array::{Array, BinaryArray};
+/// # use arrow_cast::binary_to_fixed_size_binary;
+/// let array = BinaryArray::from(vec![Some(b"abc".as_slice()), None, Some(b"xyz")]);
+/// let fixed = binary_to_fixed_size_binary(&array).unwrap();
+/// assert_eq!(fixed.value_length(), 3);
+/// assert_eq!(fixed.value(2), b"xyz");
+/// assert!(fixed.is_null(1));
+/// ```
+pub fn binary_to_fixed_size_binary(array: &dyn Array) -> Result<FixedSizeBinaryArray, ArrowError> {
+    fn infer_width<O: OffsetSizeTrait>(array: &GenericBinaryArray<O>) -> Result<i32, ArrowError> {
+        let mut lengths = array.iter().flatten().map(|v| v.len());
+        let width = lengths.next().unwrap_or_default();
+        if let Some(other) = lengths.find(|l| *l != width) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot infer FixedSizeBinary width, found values of length {width} and {other}"
+            )));
+        }
+        width.try_into().map_err(|_| {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot infer FixedSizeBinary width, values of length {width} are too large"
+            ))
+        })
+    }
+
+    let options = CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+    let array = match array.data_type() {
+        DataType::Binary => {
+            let width = infer_width(array.as_binary::<i32>())?;
+            cast_binary_to_fixed_size_binary::<i32>(array, width, &options)?
+        }
+        DataType::LargeBinary => {
+            let width = infer_width(array.as_binary::<i64>())?;
+            cast_binary_to_fixed_size_binary::<i64>(array, width, &options)?
+        }
+        d => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "binary_to_fixed_size_binary requires a Binary or LargeBinary array, got {d}"
+            )))
+        }
+    };
+    Ok(array.as_fixed_size_binary().clone())
+}
+
 /// Helper function to cast from one `BinaryArray` or 'LargeBinaryArray' to 'FixedSizeBinaryArray'.
 fn cast_binary_to_fixed_size_binary<O: OffsetSizeTrait>(
     array: &dyn Array,
@@ -2314,17 +2538,13 @@ fn cast_fixed_size_binary_to_binary<O: OffsetSizeTrait>(
         ));
     }
 
-    let mut builder = GenericBinaryBuilder::<O>::with_capacity(array.len(), array.len());
-
-    for i in 0..array.len() {
-        if array.is_null(i) {
-            builder.append_null();
-        } else {
-            builder.append_value(array.value(i));
-        }
-    }
-
-    Ok(Arc::new(builder.finish()))
+    // Every slot, including null slots, occupies `byte_width` bytes of the values,
+    // so these can be reused without copying
+    let lengths = std::iter::repeat(byte_width as usize).take(array.len());
+    let offsets = OffsetBuffer::<O>::from_lengths(lengths);
+    let array =
+        GenericBinaryArray::<O>::try_new(offsets, array.values().clone(), array.nulls().cloned())?;
+    Ok(Arc::new(array))
 }
 
 /// Helper function to cast from one `ByteArrayType` to another and vice versa.
@@ -4849,6 +5069,84 @@ mod tests {
         assert!(down_cast.is_null(2));
     }
 
+    #[test]
+    fn test_fixed_size_binary_to_binary_sliced() {
+        let uuids = [[1_u8; 16], [2; 16], [3; 16], [4; 16]];
+        let data = uuids.iter().map(|u| Some(u.as_slice()));
+        let array = FixedSizeBinaryArray::try_from_sparse_iter_with_size(data, 16).unwrap();
+        let array = array.slice(1, 3);
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let (size, values, _) = array.into_parts();
+        let array = FixedSizeBinaryArray::new(size, values, Some(nulls));
+
+        let result = cast(&array, &DataType::Binary).unwrap();
+        let result = result.as_binary::<i32>();
+        assert_eq!(result.value_offsets(), &[0, 16, 32, 48]);
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![Some(uuids[1].as_slice()), None, Some(uuids[3].as_slice())]
+        );
+        // The values are reused without copying
+        assert!(result.values().ptr_eq(array.values()));
+
+        let roundtrip = cast(result, &DataType::FixedSizeBinary(16)).unwrap();
+        assert_eq!(roundtrip.as_fixed_size_binary(), &array);
+    }
+
+    #[test]
+    fn test_binary_to_fixed_size_binary_length_mismatch() {
+        let array = BinaryArray::from(vec![Some(b"abcd".as_slice()), Some(b"ab"), None]);
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &DataType::FixedSizeBinary(4), &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Byte slice does not have the same length as FixedSizeBinaryBuilder value lengths"
+        );
+
+        // Values of the wrong length are null in safe mode
+        let result = cast(&array, &DataType::FixedSizeBinary(4)).unwrap();
+        let result = result.as_fixed_size_binary();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![Some(b"abcd".as_slice()), None, None]
+        );
+    }
+
+    #[test]
+    fn test_binary_to_fixed_size_binary_infer_width() {
+        let values = vec![None, Some([1_u8; 16].as_slice()), None, Some(&[2; 16])];
+        let array = BinaryArray::from(values.clone());
+        let fixed = binary_to_fixed_size_binary(&array).unwrap();
+        assert_eq!(fixed.data_type(), &DataType::FixedSizeBinary(16));
+        assert_eq!(fixed.iter().collect::<Vec<_>>(), values);
+
+        let array = LargeBinaryArray::from(values.clone());
+        let fixed = binary_to_fixed_size_binary(&array).unwrap();
+        assert_eq!(fixed.iter().collect::<Vec<_>>(), values);
+
+        let array = BinaryArray::new_null(3);
+        let fixed = binary_to_fixed_size_binary(&array).unwrap();
+        assert_eq!(fixed.data_type(), &DataType::FixedSizeBinary(0));
+        assert_eq!(fixed.null_count(), 3);
+
+        let array = BinaryArray::from(vec![Some(b"ab".as_slice()), None, Some(b"abc")]);
+        let err = binary_to_fixed_size_binary(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot infer FixedSizeBinary width, found values of length 2 and 3"
+        );
+
+        let array = StringArray::from(vec!["ab"]);
+        let err = binary_to_fixed_size_binary(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: binary_to_fixed_size_binary requires a Binary or LargeBinary array, got Utf8"
+        );
+    }
+
     #[test]
     fn test_fixed_size_binary_to_dictionary() {
         let bytes_1 = "Hiiii".as_bytes();
@@ -5172,6 +5470,84 @@ mod tests {
         assert!(b.is_err());
     }
 
+    #[test]
+    fn test_cast_time_units() {
+        // 00:00:00, 00:00:01 and 12:34:56 in nanoseconds
+        let nanos = [Some(0), Some(1_000_000_000), None, Some(45_296_000_000_000)];
+        let make = |data_type: &DataType| -> ArrayRef {
+            let scaled = |factor: i64| nanos.iter().map(move |v| v.map(|v| v / factor));
+            match data_type {
+                DataType::Time32(TimeUnit::Second) => Arc::new(Time32SecondArray::from_iter(
+                    scaled(1_000_000_000).map(|v| v.map(|v| v as i32)),
+                )),
+                DataType::Time32(TimeUnit::Millisecond) => {
+                    Arc::new(Time32MillisecondArray::from_iter(
+                        scaled(1_000_000).map(|v| v.map(|v| v as i32)),
+                    ))
+                }
+                DataType::Time64(TimeUnit::Microsecond) => {
+                    Arc::new(Time64MicrosecondArray::from_iter(scaled(1_000)))
+                }
+                DataType::Time64(TimeUnit::Nanosecond) => {
+                    Arc::new(Time64NanosecondArray::from_iter(scaled(1)))
+                }
+                _ => unreachable!(),
+            }
+        };
+
+        let types = [
+            DataType::Time32(TimeUnit::Second),
+            DataType::Time32(TimeUnit::Millisecond),
+            DataType::Time64(TimeUnit::Microsecond),
+            DataType::Time64(TimeUnit::Nanosecond),
+        ];
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        for from_type in &types {
+            for to_type in &types {
+                assert!(can_cast_types(from_type, to_type));
+                let array = make(from_type);
+                let expected = make(to_type);
+                let result = cast(&array, to_type).unwrap();
+                assert_eq!(&result, &expected, "{from_type} to {to_type}");
+                let result = cast_with_options(&array, to_type, &options).unwrap();
+                assert_eq!(&result, &expected, "{from_type} to {to_type}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_cast_time_units_truncation() {
+        let array = Time64NanosecondArray::from(vec![Some(1_500), None, Some(2_000)]);
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+
+        let to_type = DataType::Time64(TimeUnit::Microsecond);
+        let result = cast(&array, &to_type).unwrap();
+        let expected = Time64MicrosecondArray::from(vec![Some(1), None, Some(2)]);
+        assert_eq!(result.as_primitive::<Time64MicrosecondType>(), &expected);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast Time64(Nanosecond) value 1500 to Time64(Microsecond) without losing precision"
+        );
+
+        let array = Time32MillisecondArray::from(vec![Some(3_000), Some(3_001)]);
+        let to_type = DataType::Time32(TimeUnit::Second);
+        let result = cast(&array, &to_type).unwrap();
+        let expected = Time32SecondArray::from(vec![3, 3]);
+        assert_eq!(result.as_primitive::<Time32SecondType>(), &expected);
+        assert!(cast_with_options(&array, &to_type, &options).is_err());
+
+        // Values that are not truncated cast without error
+        let result = cast_with_options(&array.slice(0, 1), &to_type, &options).unwrap();
+        assert_eq!(result.as_primitive::<Time32SecondType>().value(0), 3);
+    }
+
     // Cast Timestamp(_, None) -> Timestamp(_, Some(timezone))
     #[test]
     fn test_cast_timestamp_with_timezone_1() {
@@ -7274,6 +7650,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_dict_value_type() {
+        use DataType::*;
+
+        let keys = Int32Array::from(vec![Some(1), None, Some(0), Some(1)]);
+        let values = StringArray::from(vec![Some("a"), None]);
+        let array = DictionaryArray::new(keys.clone(), Arc::new(values));
+
+        let cast_type = Dictionary(Box::new(Int32), Box::new(LargeUtf8));
+        let cast_array = cast(&array, &cast_type).unwrap();
+        assert_eq!(cast_array.data_type(), &cast_type);
+
+        let cast_array = cast_array.as_dictionary::<Int32Type>();
+        let expected = LargeStringArray::from(vec![Some("a"), None]);
+        assert_eq!(cast_array.values().as_string::<i64>(), &expected);
+
+        // The keys are not touched
+        assert_eq!(cast_array.keys(), &keys);
+        let (keys_buffer, cast_buffer) =
+            (keys.values().inner(), cast_array.keys().values().inner());
+        assert_eq!(keys_buffer.as_ptr(), cast_buffer.as_ptr());
+        let (keys_nulls, cast_nulls) = (keys.nulls().unwrap(), cast_array.keys().nulls().unwrap());
+        assert_eq!(keys_nulls.buffer().as_ptr(), cast_nulls.buffer().as_ptr());
+
+        // Sliced dictionaries
+        let sliced = array.slice(1, 3);
+        let cast_array = cast(&sliced, &cast_type).unwrap();
+        let cast_array = cast_array.as_dictionary::<Int32Type>();
+        assert_eq!(cast_array.keys(), &keys.slice(1, 3));
+        assert_eq!(cast_array.values().as_string::<i64>(), &expected);
+
+        // Value casts that fail are reported
+        let values = StringArray::from(vec!["1", "x"]);
+        let array = DictionaryArray::new(keys.clone(), Arc::new(values));
+        let cast_type = Dictionary(Box::new(Int32), Box::new(Int64));
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &cast_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string 'x' to value of Int64 type"
+        );
+    }
+
     #[test]
     fn test_cast_primitive_dict() {
         // FROM a dictionary with of INT32 values
@@ -10656,4 +11078,57 @@ mod tests {
         )) as ArrayRef;
         assert_eq!(*fixed_array, *r);
     }
+
+    #[test]
+    fn test_take_cast() {
+        let values = Int32Array::from(vec![Some(1), None, Some(i32::MAX), Some(-4)]);
+        let indices = UInt32Array::from(vec![Some(3), None, Some(1), Some(2), Some(0)]);
+
+        let taken = take_cast(&values, &indices, &DataType::Int64).unwrap();
+        assert_eq!(taken.data_type(), &DataType::Int64);
+        let expected = Int64Array::from(vec![Some(-4), None, None, Some(i32::MAX as i64), Some(1)]);
+        assert_eq!(taken.as_primitive::<Int64Type>(), &expected);
+
+        // Equivalent to a take followed by a cast
+        let unfused = cast(&take(&values, &indices, None).unwrap(), &DataType::Int64).unwrap();
+        assert_eq!(&taken, &unfused);
+
+        // Casts that are not widenings fall back to take then cast
+        let taken = take_cast(&values, &indices, &DataType::Utf8).unwrap();
+        let expected =
+            StringArray::from(vec![Some("-4"), None, None, Some("2147483647"), Some("1")]);
+        assert_eq!(taken.as_string::<i32>(), &expected);
+
+        let indices = Int64Array::from(vec![0, 4]);
+        let err = take_cast(&values, &indices, &DataType::Int64).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 4 from 4 entries"
+        );
+    }
+
+    #[test]
+    fn test_take_cast_widenings() {
+        let values = UInt8Array::from(vec![Some(255), Some(0), None]);
+        let indices = Int8Array::from(vec![2, 0, 1]);
+        for to_type in [
+            DataType::UInt16,
+            DataType::UInt32,
+            DataType::UInt64,
+            DataType::Int16,
+            DataType::Int32,
+            DataType::Int64,
+        ] {
+            let taken = take_cast(&values, &indices, &to_type).unwrap();
+            let expected = cast(&take(&values, &indices, None).unwrap(), &to_type).unwrap();
+            assert_eq!(&taken, &expected);
+        }
+
+        let values = Float32Array::from(vec![1.5, f32::NAN, -0.0]);
+        let taken = take_cast(&values, &indices, &DataType::Float64).unwrap();
+        let taken = taken.as_primitive::<Float64Type>();
+        assert_eq!(taken.value(0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(taken.value(1), 1.5);
+        assert!(taken.value(2).is_nan());
+    }
 }
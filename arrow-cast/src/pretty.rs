@@ -27,7 +27,7 @@ use std::fmt::Display;
 use comfy_table::{Cell, Table};
 
 use arrow_array::{Array, ArrayRef, RecordBatch};
-use arrow_schema::ArrowError;
+use arrow_schema::{ArrowError, DataType};
 
 use crate::display::{ArrayFormatter, FormatOptions};
 
@@ -126,6 +126,53 @@ pub fn pretty_format_columns_with_options(
     create_column(col_name, results, options)
 }
 
+/// Create a visual representation of a single [`Array`] as a table with one column
+/// named `name`
+///
+/// Uses default values for display. See [`pretty_format_array_with_options`] for
+/// more control.
+///
+/// # Example
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_cast::pretty::pretty_format_array;
+/// let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+/// let table = pretty_format_array(&array, "a").unwrap();
+/// assert_eq!(table,
+/// r#"+---+
+/// | a |
+/// +---+
+/// | 1 |
+/// |   |
+/// | 3 |
+/// +---+"#);
+/// ```
+pub fn pretty_format_array(array: &dyn Array, name: &str) -> Result<String, ArrowError> {
+    let options = FormatOptions::default().with_display_error(true);
+    pretty_format_array_with_options(array, name, &options)
+}
+
+/// Create a visual representation of a single [`Array`] as a table with one column
+/// named `name`, with formatting options.
+///
+/// The header includes the data type of `array` if [`FormatOptions::with_types_info`]
+/// is enabled. See [`pretty_format_batches_with_options`] for an example
+pub fn pretty_format_array_with_options(
+    array: &dyn Array,
+    name: &str,
+    options: &FormatOptions,
+) -> Result<String, ArrowError> {
+    let mut table = Table::new();
+    table.load_preset("||--+-++|    ++++++");
+    table.set_header([header_cell(name, array.data_type(), options)]);
+
+    let formatter = ArrayFormatter::try_new(array, options)?;
+    for row in 0..array.len() {
+        table.add_row([Cell::new(formatter.value(row))]);
+    }
+    Ok(table.to_string())
+}
+
 /// Prints a visual representation of record batches to stdout
 pub fn print_batches(results: &[RecordBatch]) -> Result<(), ArrowError> {
     println!("{}", pretty_format_batches(results)?);
@@ -149,18 +196,10 @@ fn create_table(results: &[RecordBatch], options: &FormatOptions) -> Result<Tabl
 
     let schema = results[0].schema();
 
-    let mut header = Vec::new();
-    for field in schema.fields() {
-        if options.types_info() {
-            header.push(Cell::new(format!(
-                "{}\n{}",
-                field.name(),
-                field.data_type()
-            )))
-        } else {
-            header.push(Cell::new(field.name()));
-        }
-    }
+    let header = schema
+        .fields()
+        .iter()
+        .map(|field| header_cell(field.name(), field.data_type(), options));
     table.set_header(header);
 
     for batch in results {
@@ -182,6 +221,13 @@ fn create_table(results: &[RecordBatch], options: &FormatOptions) -> Result<Tabl
     Ok(table)
 }
 
+fn header_cell(name: &str, data_type: &DataType, options: &FormatOptions) -> Cell {
+    match options.types_info() {
+        true => Cell::new(format!("{name}\n{data_type}")),
+        false => Cell::new(name),
+    }
+}
+
 fn create_column(
     field: &str,
     columns: &[ArrayRef],
@@ -295,6 +341,38 @@ mod tests {
         assert_eq!(expected, actual, "Actual result:\n{table}");
     }
 
+    #[test]
+    fn test_pretty_format_array() {
+        let array = StringArray::from(vec![Some("a"), None, Some("ccc")]);
+        let table = pretty_format_array(&array.slice(1, 2), "strings").unwrap();
+        let expected = vec![
+            "+---------+",
+            "| strings |",
+            "+---------+",
+            "|         |",
+            "| ccc     |",
+            "+---------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+
+        let options = FormatOptions::default()
+            .with_null("null")
+            .with_types_info(true);
+        let table = pretty_format_array_with_options(&array, "s", &options).unwrap();
+        let expected = vec![
+            "+------+", "| s    |", "| Utf8 |", "+------+", "| a    |", "| null |", "| ccc  |",
+            "+------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+
+        // An empty array has a header
+        let array = Int32Array::from(Vec::<i32>::new());
+        let table = pretty_format_array(&array, "a").unwrap();
+        assert_eq!(table, "+---+\n| a |\n+---+\n+---+");
+    }
+
     #[test]
     fn test_pretty_format_null() {
         let schema = Arc::new(Schema::new(vec![
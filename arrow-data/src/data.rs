@@ -459,6 +459,10 @@ impl ArrayData {
     /// the [`ArrayData`] was then [`Self::slice`]ed to refer to its
     /// first `20` elements, then [`Self::get_slice_memory_size`] on the
     /// sliced [`ArrayData`] would return `20 * 8 = 160`.
+    ///
+    /// Only the child values referenced by a slice of a list or fixed size
+    /// list are counted, and the out-of-line data referenced by views is
+    /// counted for [`DataType::Utf8View`] and [`DataType::BinaryView`].
     pub fn get_slice_memory_size(&self) -> Result<usize, ArrowError> {
         let mut result: usize = 0;
         let layout = layout(&self.data_type);
@@ -477,12 +481,12 @@ impl ArrayData {
                     let buffer_len: usize;
                     match self.data_type {
                         DataType::Utf8 | DataType::Binary => {
-                            let offsets = self.typed_offsets::<i32>()?;
-                            buffer_len = (offsets[self.len] - offsets[0] ) as usize;
+                            let (start, end) = self.offsets_range::<i32>()?;
+                            buffer_len = end - start;
                         }
                         DataType::LargeUtf8 | DataType::LargeBinary => {
-                            let offsets = self.typed_offsets::<i64>()?;
-                            buffer_len = (offsets[self.len] - offsets[0]) as usize;
+                            let (start, end) = self.offsets_range::<i64>()?;
+                            buffer_len = end - start;
                         }
                         _ => {
                             return Err(ArrowError::NotYetImplemented(format!(
@@ -503,16 +507,58 @@ impl ArrayData {
             }
         }
 
+        if matches!(self.data_type, DataType::Utf8View | DataType::BinaryView) {
+            // Values longer than the inline length are stored in the variadic buffers
+            let views = &self.buffer::<u128>(0)[..self.len];
+            result += views
+                .iter()
+                .map(|v| *v as u32 as usize)
+                .filter(|len| *len > 12)
+                .sum::<usize>();
+        }
+
         if self.nulls().is_some() {
             result += bit_util::ceil(self.len, 8);
         }
 
-        for child in &self.child_data {
-            result += child.get_slice_memory_size()?;
+        match &self.data_type {
+            // Only account for the child values referenced by this slice
+            DataType::List(_) | DataType::Map(_, _) => {
+                let (start, end) = self.offsets_range::<i32>()?;
+                result += self.child_data[0]
+                    .slice(start, end - start)
+                    .get_slice_memory_size()?;
+            }
+            DataType::LargeList(_) => {
+                let (start, end) = self.offsets_range::<i64>()?;
+                result += self.child_data[0]
+                    .slice(start, end - start)
+                    .get_slice_memory_size()?;
+            }
+            DataType::FixedSizeList(_, size) => {
+                let size = *size as usize;
+                result += self.child_data[0]
+                    .slice(self.offset * size, self.len * size)
+                    .get_slice_memory_size()?;
+            }
+            _ => {
+                for child in &self.child_data {
+                    result += child.get_slice_memory_size()?;
+                }
+            }
         }
         Ok(result)
     }
 
+    /// Returns the range of values referenced by the offsets of this slice
+    fn offsets_range<T: ArrowNativeType + num::Num>(&self) -> Result<(usize, usize), ArrowError> {
+        let offsets = self.typed_offsets::<T>()?;
+        match (offsets.first(), offsets.last()) {
+            (Some(first), Some(last)) => Ok((first.as_usize(), last.as_usize())),
+            _ => Ok((0, 0)),
+        }
+    }
+
     /// Returns the total number of bytes of memory occupied
     /// physically by this [`ArrayData`] and all its [`Buffer`]s and
     /// children. (See also diagram on [`ArrayData`]).
@@ -2119,6 +2165,7 @@ impl From<ArrayData> for ArrayDataBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ByteView;
     use arrow_schema::{Field, Fields};
 
     // See arrow/tests/array_data_validation.rs for test of array validation
@@ -2345,6 +2392,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_slice_memory_size_referenced_values() {
+        // [[1, 2], [3], [4, 5, 6]]
+        let child = ArrayData::builder(DataType::Int32)
+            .len(6)
+            .add_buffer(make_i32_buffer(6))
+            .build()
+            .unwrap();
+        let list = ArrayData::builder(DataType::new_list(DataType::Int32, false))
+            .len(3)
+            .add_buffer(Buffer::from_slice_ref([0_i32, 2, 3, 6]))
+            .add_child_data(child)
+            .build()
+            .unwrap();
+        assert_eq!(list.get_slice_memory_size().unwrap(), 3 * 4 + 6 * 4);
+        // Previously all 6 child values were counted, now only the 3 referenced
+        assert_eq!(list.slice(2, 1).get_slice_memory_size().unwrap(), 4 + 3 * 4);
+
+        let fixed = ArrayData::builder(DataType::new_fixed_size_list(DataType::Int32, 2, false))
+            .len(3)
+            .add_child_data(list.child_data()[0].clone())
+            .build()
+            .unwrap();
+        // Previously 4 + 6 * 4
+        assert_eq!(fixed.slice(1, 1).get_slice_memory_size().unwrap(), 2 * 4);
+
+        let data = Buffer::from_slice_ref("a string longer than twelve");
+        let mut inline = [0; 16];
+        inline[..4].copy_from_slice(&5_u32.to_le_bytes());
+        inline[4..9].copy_from_slice(b"short");
+        let views = [
+            u128::from_le_bytes(inline),
+            ByteView::new(27, b"a st").as_u128(),
+        ];
+        let view_data = ArrayData::builder(DataType::Utf8View)
+            .len(2)
+            .add_buffer(Buffer::from_slice_ref(views))
+            .add_buffer(data)
+            .build()
+            .unwrap();
+        // Previously only the views were counted, now also the out-of-line data
+        assert_eq!(view_data.slice(0, 1).get_slice_memory_size().unwrap(), 16);
+        assert_eq!(
+            view_data.slice(1, 1).get_slice_memory_size().unwrap(),
+            16 + 27
+        );
+    }
+
     #[test]
     fn test_count_nulls() {
         let buffer = Buffer::from([0b00010110, 0b10011111]);
@@ -20,7 +20,7 @@
 //! Provides utilities for creating, manipulating, and converting Arrow arrays
 //! made of primitive types, strings, and nested types.
 
-use super::{data::new_buffers, ArrayData, ArrayDataBuilder, ByteView};
+use super::{data::new_buffers, layout, ArrayData, ArrayDataBuilder, BufferSpec, ByteView};
 use crate::bit_mask::set_bits;
 use arrow_buffer::buffer::{BooleanBuffer, NullBuffer};
 use arrow_buffer::{bit_util, i256, ArrowNativeType, Buffer, MutableBuffer};
@@ -72,7 +72,7 @@ impl _MutableArrayData<'_> {
     }
 }
 
-fn build_extend_null_bits(array: &ArrayData, use_nulls: bool) -> ExtendNullBits {
+fn build_extend_null_bits(array: &ArrayData) -> ExtendNullBits {
     if let Some(nulls) = array.nulls() {
         let bytes = nulls.validity();
         Box::new(move |mutable, start, len| {
@@ -87,21 +87,46 @@ fn build_extend_null_bits(array: &ArrayData, use_nulls: bool) -> ExtendNullBits
                 len,
             );
         })
-    } else if use_nulls {
+    } else {
         Box::new(|mutable, _, len| {
             let mutable_len = mutable.len;
-            let out = mutable.null_buffer();
-            utils::resize_for_bits(out, mutable_len + len);
-            let write_data = out.as_slice_mut();
-            (0..len).for_each(|i| {
-                bit_util::set_bit(write_data, mutable_len + i);
-            });
+            // the validity buffer may be allocated lazily by `extend_nulls`
+            if let Some(out) = mutable.null_buffer.as_mut() {
+                set_valid(out, mutable_len, len);
+            }
         })
-    } else {
-        Box::new(|_, _, _| {})
     }
 }
 
+/// Returns true if the first buffer of `data_type` contains offsets
+fn has_offsets(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Binary
+            | DataType::LargeBinary
+            | DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::Map(_, _)
+    )
+}
+
+/// Reserves capacity in the bitmap `buffer` for a total of `len` bits
+fn reserve_bits(buffer: &mut MutableBuffer, len: usize) {
+    let needed_bytes = bit_util::ceil(len, 8);
+    buffer.reserve(needed_bytes.saturating_sub(buffer.len()));
+}
+
+/// Marks the `len` bits of `nulls` starting at `offset` as valid
+fn set_valid(nulls: &mut MutableBuffer, offset: usize, len: usize) {
+    utils::resize_for_bits(nulls, offset + len);
+    let write_data = nulls.as_slice_mut();
+    (0..len).for_each(|i| {
+        bit_util::set_bit(write_data, offset + i);
+    });
+}
+
 /// Efficiently create an [ArrayData] from one or more existing [ArrayData]s by
 /// copying chunks.
 ///
@@ -392,8 +417,8 @@ impl<'a> MutableArrayData<'a> {
     ///   - `true` if the user plans to call [MutableArrayData::extend_nulls].
     /// * capacity - the preallocated capacity of the output array, in bytes
     ///
-    /// Thus, if `use_nulls` is `false`, calling
-    /// [MutableArrayData::extend_nulls] should not be used.
+    /// If `use_nulls` is `false`, [MutableArrayData::extend_nulls] may still be
+    /// called, but the validity buffer is then allocated on the first call.
     pub fn new(arrays: Vec<&'a ArrayData>, use_nulls: bool, capacity: usize) -> Self {
         Self::with_capacities(arrays, use_nulls, Capacities::Array(capacity))
     }
@@ -640,7 +665,7 @@ impl<'a> MutableArrayData<'a> {
 
         let extend_null_bits = arrays
             .iter()
-            .map(|array| build_extend_null_bits(array, use_nulls))
+            .map(|array| build_extend_null_bits(array))
             .collect();
 
         let null_buffer = use_nulls.then(|| {
@@ -723,12 +748,60 @@ impl<'a> MutableArrayData<'a> {
         self.data.len += len;
     }
 
-    /// Extends the in progress array with null elements, ignoring the input arrays.
+    /// Extends the in progress array with `len` copies of the element at `pos` of the
+    /// `index`th input array
     ///
-    /// # Panics
+    /// This is equivalent to calling [`Self::extend`] with `(index, pos, pos + 1)`
+    /// `len` times, but for variable-size types, such as [`DataType::LargeUtf8`],
+    /// the length of the element is computed once and its bytes copied in a single pass
     ///
-    /// Panics if [`MutableArrayData`] not created with `use_nulls` or nullable source arrays
+    /// # Panic
+    /// This function panics if there is an invalid index,
+    /// i.e. `index` >= the number of source arrays
+    /// or `pos` >= the length of the `index`th array
+    pub fn extend_constant(&mut self, index: usize, pos: usize, len: usize) {
+        let array = self.arrays[index];
+        assert!(pos < array.len(), "extend_constant position out of bounds");
+        if array.is_null(pos) {
+            self.extend_nulls(len);
+            return;
+        }
+
+        match array.data_type() {
+            DataType::Utf8 | DataType::Binary => {
+                variable_size::extend_constant::<i32>(&mut self.data, array, pos, len)
+            }
+            DataType::LargeUtf8 | DataType::LargeBinary => {
+                variable_size::extend_constant::<i64>(&mut self.data, array, pos, len)
+            }
+            _ => {
+                for _ in 0..len {
+                    (self.extend_values[index])(&mut self.data, index, pos, 1);
+                }
+            }
+        }
+
+        let mutable_len = self.data.len;
+        if let Some(out) = self.data.null_buffer.as_mut() {
+            utils::resize_for_bits(out, mutable_len + len);
+            let write_data = out.as_slice_mut();
+            (0..len).for_each(|i| bit_util::set_bit(write_data, mutable_len + i));
+        }
+        self.data.len += len;
+    }
+
+    /// Extends the in progress array with null elements, ignoring the input arrays.
+    ///
+    /// Offsets are advanced without copying any values, and the validity bits of the
+    /// appended slots are unset. This does not require a null source array, nor the
+    /// [`MutableArrayData`] to be created with `use_nulls`, though in that case the
+    /// validity buffer is allocated on the first call.
     pub fn extend_nulls(&mut self, len: usize) {
+        if self.data.null_buffer.is_none() {
+            let mut nulls = MutableBuffer::new(bit_util::ceil(self.data.len + len, 8));
+            set_valid(&mut nulls, 0, self.data.len);
+            self.data.null_buffer = Some(nulls);
+        }
         self.data.len += len;
         let bit_len = bit_util::ceil(self.data.len, 8);
         let nulls = self.data.null_buffer();
@@ -737,6 +810,80 @@ impl<'a> MutableArrayData<'a> {
         (self.extend_nulls)(&mut self.data, len);
     }
 
+    /// Returns the number of elements the in progress array can hold without
+    /// reallocating its buffers
+    ///
+    /// Only buffers with a fixed size per element are considered, and so this ignores
+    /// variable-width data, such as the bytes of strings, and the children of lists.
+    /// Types without such buffers of their own, such as [`DataType::Struct`], are
+    /// limited by the children that have one element per element of the array, if any,
+    /// and are otherwise unbounded.
+    pub fn capacity(&self) -> usize {
+        let data = &self.data;
+        let layout = layout(&data.data_type);
+        let buffers = layout.buffers.iter().zip([&data.buffer1, &data.buffer2]);
+        let own = buffers
+            .enumerate()
+            .filter_map(|(i, (spec, buffer))| match spec {
+                BufferSpec::FixedWidth { byte_width, .. } => {
+                    // offset buffers hold one more element than the array
+                    let offsets = i == 0 && has_offsets(&data.data_type);
+                    Some((buffer.capacity() / byte_width).saturating_sub(offsets as usize))
+                }
+                BufferSpec::BitMap => Some(buffer.capacity() * 8),
+                _ => None,
+            });
+        let nulls = data.null_buffer.as_ref().map(|n| n.capacity() * 8);
+        let children = data
+            .child_data
+            .iter()
+            .filter_map(|c| match &data.data_type {
+                DataType::Struct(_) | DataType::Union(_, UnionMode::Sparse) => Some(c.capacity()),
+                DataType::FixedSizeList(_, size) => c.capacity().checked_div(*size as usize),
+                _ => None,
+            });
+        own.chain(nulls).chain(children).min().unwrap_or(usize::MAX)
+    }
+
+    /// Reserves capacity for at least `additional` more elements, as per [`Self::capacity`]
+    ///
+    /// This can be used to avoid incremental reallocations when the length of the
+    /// output is known upfront.
+    pub fn reserve(&mut self, additional: usize) {
+        let data = &mut self.data;
+        let len = data.len;
+        let layout = layout(&data.data_type);
+        let buffers = layout
+            .buffers
+            .iter()
+            .zip([&mut data.buffer1, &mut data.buffer2]);
+        for (spec, buffer) in buffers {
+            match spec {
+                BufferSpec::FixedWidth { byte_width, .. } => {
+                    buffer.reserve(additional * byte_width)
+                }
+                BufferSpec::BitMap => reserve_bits(buffer, len + additional),
+                _ => {}
+            }
+        }
+        if let Some(nulls) = data.null_buffer.as_mut() {
+            reserve_bits(nulls, len + additional);
+        }
+        match &data.data_type {
+            DataType::Struct(_) | DataType::Union(_, UnionMode::Sparse) => data
+                .child_data
+                .iter_mut()
+                .for_each(|c| c.reserve(additional)),
+            DataType::FixedSizeList(_, size) => {
+                let additional = additional * *size as usize;
+                data.child_data
+                    .iter_mut()
+                    .for_each(|c| c.reserve(additional))
+            }
+            _ => {}
+        }
+    }
+
     /// Returns the current length
     #[inline]
     pub fn len(&self) -> usize {
@@ -59,6 +59,34 @@ pub(super) fn build_extend<T: ArrowNativeType + Integer + CheckedAdd + AsPrimiti
     )
 }
 
+/// Extends `mutable` with `len` copies of the element at `pos` of `array`
+pub(super) fn extend_constant<T: ArrowNativeType + Integer + CheckedAdd + AsPrimitive<usize>>(
+    mutable: &mut _MutableArrayData,
+    array: &ArrayData,
+    pos: usize,
+    len: usize,
+) {
+    let offsets = array.buffer::<T>(0);
+    let (start, end) = (offsets[pos], offsets[pos + 1]);
+    let value = &array.buffers()[1].as_slice()[start.as_()..end.as_()];
+    let length = end - start;
+
+    let offset_buffer = &mut mutable.buffer1;
+    // this is safe due to how offset is built. See details on `get_last_offset`
+    let mut last_offset: T = unsafe { get_last_offset(offset_buffer) };
+    offset_buffer.reserve(len * std::mem::size_of::<T>());
+    for _ in 0..len {
+        last_offset = last_offset.checked_add(&length).expect("offset overflow");
+        offset_buffer.push(last_offset);
+    }
+
+    let values_buffer = &mut mutable.buffer2;
+    values_buffer.reserve(len * value.len());
+    for _ in 0..len {
+        values_buffer.extend_from_slice(value);
+    }
+}
+
 pub(super) fn extend_nulls<T: ArrowNativeType>(mutable: &mut _MutableArrayData, len: usize) {
     let offset_buffer = &mut mutable.buffer1;
 
@@ -16,10 +16,15 @@
 // under the License.
 
 use arrow_array::builder::{Date32Builder, Decimal128Builder, Int32Builder};
-use arrow_array::{builder::StringBuilder, RecordBatch};
+use arrow_array::types::Int32Type;
+use arrow_array::{
+    builder::StringBuilder, Array, ArrayRef, DictionaryArray, Int32Array, RecordBatch,
+};
 use arrow_buffer::Buffer;
 use arrow_ipc::convert::fb_to_schema;
-use arrow_ipc::reader::{read_footer_length, FileDecoder, FileReader, StreamReader};
+use arrow_ipc::reader::{
+    read_footer_length, FileDecoder, FileReader, FileReaderBuilder, StreamReader,
+};
 use arrow_ipc::writer::{FileWriter, IpcWriteOptions, StreamWriter};
 use arrow_ipc::{root_as_footer, Block, CompressionType};
 use arrow_schema::{DataType, Field, Schema};
@@ -126,6 +131,33 @@ fn criterion_benchmark(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("FileReader/wide/read_10", |b| {
+        let buffer = ipc_file_wide();
+        b.iter(move || {
+            let cursor = Cursor::new(buffer.as_slice());
+            let mut reader = FileReaderBuilder::new().build(cursor).unwrap();
+            for _ in 0..10 {
+                reader.next().unwrap().unwrap();
+            }
+            assert!(reader.next().is_none());
+        })
+    });
+
+    group.bench_function("FileReader/wide/projection/read_10", |b| {
+        let buffer = ipc_file_wide();
+        b.iter(move || {
+            let cursor = Cursor::new(buffer.as_slice());
+            let mut reader = FileReaderBuilder::new()
+                .with_projection(vec![0, 1])
+                .build(cursor)
+                .unwrap();
+            for _ in 0..10 {
+                reader.next().unwrap().unwrap();
+            }
+            assert!(reader.next().is_none());
+        })
+    });
+
     // write to an actual file
     let dir = tempdir().unwrap();
     let path = dir.path().join("test.arrow");
@@ -196,6 +228,38 @@ fn ipc_file() -> Vec<u8> {
     buffer
 }
 
+/// Return an IPC file with 10 record batches of 100 columns, half of them dictionary encoded
+fn ipc_file_wide() -> Vec<u8> {
+    let num_rows = 1024;
+    let mut fields = vec![];
+    let mut columns: Vec<ArrayRef> = vec![];
+    for i in 0..50 {
+        let ints = Int32Array::from_iter_values(0..num_rows);
+        let values: Vec<_> = (0..num_rows)
+            .map(|j| format!("this is string number {j} of column {i}"))
+            .collect();
+        let dict: DictionaryArray<Int32Type> = values.iter().map(String::as_str).collect();
+        fields.push(Field::new(format!("c{}", 2 * i), DataType::Int32, false));
+        fields.push(Field::new(
+            format!("c{}", 2 * i + 1),
+            dict.data_type().clone(),
+            false,
+        ));
+        columns.push(Arc::new(ints));
+        columns.push(Arc::new(dict));
+    }
+    let schema = Arc::new(Schema::new(fields));
+    let batch = RecordBatch::try_new(schema.clone(), columns).unwrap();
+
+    let mut buffer = Vec::with_capacity(16 * 1024 * 1024);
+    let mut writer = FileWriter::try_new(&mut buffer, &schema).unwrap();
+    for _ in 0..10 {
+        writer.write(&batch).unwrap();
+    }
+    writer.finish().unwrap();
+    buffer
+}
+
 // copied from the zero_copy_ipc example.
 // should we move this to an actual API?
 /// Wrapper around the example in the `FileDecoder` which handles the
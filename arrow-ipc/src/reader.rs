@@ -29,7 +29,7 @@ mod stream;
 pub use stream::*;
 
 use flatbuffers::{VectorIter, VerifierOptions};
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
 use std::io::{BufReader, Read, Seek, SeekFrom};
 use std::sync::Arc;
@@ -815,6 +815,8 @@ pub struct FileDecoder {
     dictionaries: HashMap<i64, ArrayRef>,
     version: MetadataVersion,
     projection: Option<Vec<usize>>,
+    /// The ids of the dictionaries used by the projected columns, if valid
+    projected_dictionaries: Option<HashSet<i64>>,
     require_alignment: bool,
     skip_validation: UnsafeFlag,
 }
@@ -827,13 +829,26 @@ impl FileDecoder {
             version,
             dictionaries: Default::default(),
             projection: None,
+            projected_dictionaries: None,
             require_alignment: false,
             skip_validation: UnsafeFlag::new(),
         }
     }
 
     /// Specify a projection
+    ///
+    /// The buffers of columns that are not part of the projection are skipped, as are
+    /// dictionary batches only used by such columns
     pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
+        // An invalid projection is reported when reading record batches
+        self.projected_dictionaries = self.schema.project(&projection).ok().map(|projected| {
+            #[allow(deprecated)]
+            projected
+                .flattened_fields()
+                .into_iter()
+                .filter_map(|f| f.dict_id())
+                .collect()
+        });
         self.projection = Some(projection);
         self
     }
@@ -888,6 +903,9 @@ impl FileDecoder {
         match message.header_type() {
             crate::MessageHeader::DictionaryBatch => {
                 let batch = message.header_as_dictionary_batch().unwrap();
+                if !self.is_projected_dictionary(batch.id())? {
+                    return Ok(());
+                }
                 read_dictionary_impl(
                     &buf.slice(block.metaDataLength() as _),
                     batch,
@@ -904,6 +922,21 @@ impl FileDecoder {
         }
     }
 
+    /// Returns `false` if the dictionary with `id` is only used by columns that
+    /// are not part of the projection, and so does not need to be decoded
+    fn is_projected_dictionary(&self, id: i64) -> Result<bool, ArrowError> {
+        let Some(projected) = &self.projected_dictionaries else {
+            return Ok(true);
+        };
+        #[allow(deprecated)]
+        if self.schema.fields_with_dict_id(id).is_empty() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "dictionary id {id} not found in schema"
+            )));
+        }
+        Ok(projected.contains(&id))
+    }
+
     /// Read the RecordBatch with the given block and data buffer
     pub fn read_record_batch(
         &self,
@@ -972,6 +1005,9 @@ impl FileReaderBuilder {
     }
 
     /// Optional projection for which columns to load (zero-based column indices).
+    ///
+    /// Columns that are not part of the projection are not decoded, and neither are
+    /// the dictionaries only they use, see [`FileDecoder::with_projection`]
     pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
         self.projection = Some(projection);
         self
@@ -1818,6 +1854,42 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_projection_skips_dictionaries() {
+        let dict: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+        let values: DictionaryArray<Int8Type> = vec!["x", "y", "z", "x"].into_iter().collect();
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(values.data_type().clone(), true)),
+            OffsetBuffer::new(vec![0, 2, 2, 4].into()),
+            Arc::new(values),
+            None,
+        );
+        let ints = Int32Array::from(vec![1, 2, 3]);
+        let batch = RecordBatch::try_from_iter([
+            ("dict", Arc::new(dict) as ArrayRef),
+            ("list", Arc::new(list) as ArrayRef),
+            ("ints", Arc::new(ints) as ArrayRef),
+        ])
+        .unwrap();
+        let buf = write_ipc(&batch);
+
+        for (projection, num_dictionaries) in [
+            (vec![2], 0),
+            (vec![1], 1),
+            (vec![2, 0], 1),
+            (vec![0, 1, 2], 2),
+        ] {
+            let mut reader = FileReaderBuilder::new()
+                .with_projection(projection.clone())
+                .build(std::io::Cursor::new(buf.clone()))
+                .unwrap();
+            assert_eq!(reader.decoder.dictionaries.len(), num_dictionaries);
+
+            let read = reader.next().unwrap().unwrap();
+            assert_eq!(read, batch.project(&projection).unwrap());
+        }
+    }
+
     /// Write the record batch to an in-memory buffer in IPC File format
     fn write_ipc(rb: &RecordBatch) -> Vec<u8> {
         let mut buf = Vec::new();
@@ -501,6 +501,66 @@ impl IpcDataGenerator {
         Ok((encoded_dictionaries, encoded_message))
     }
 
+    /// Returns the number of bytes [`write_message`] writes for the record batch
+    /// message of `batch`, without encoding it
+    ///
+    /// The size includes the continuation marker, the header and the body, but not any
+    /// dictionary batches that precede the record batch. When
+    /// [`IpcWriteOptions::try_with_compression`] is used, the body is assumed to be
+    /// uncompressed, so the returned size is an upper bound on the encoded size.
+    pub fn estimated_encoded_size(
+        &self,
+        batch: &RecordBatch,
+        write_options: &IpcWriteOptions,
+    ) -> Result<usize, ArrowError> {
+        let mut num_nodes = 0;
+        let mut buffer_lengths = vec![];
+        let mut variadic_buffer_counts = vec![];
+        for array in batch.columns() {
+            let array_data = array.to_data();
+            array_data_encoded_size(
+                &array_data,
+                array.len(),
+                write_options,
+                &mut num_nodes,
+                &mut buffer_lengths,
+            )?;
+            append_variadic_buffer_counts(&mut variadic_buffer_counts, &array_data);
+        }
+
+        let body_len: usize = buffer_lengths
+            .iter()
+            .map(|len| {
+                let len = match write_options.batch_compression_type {
+                    // The uncompressed length is written before each non-empty buffer
+                    Some(_) if *len > 0 => len + 8,
+                    _ => *len,
+                };
+                len + pad_to_alignment(write_options.alignment, len)
+            })
+            .sum();
+
+        // The header only depends on the number of nodes and buffers, not their values
+        let nodes = vec![crate::FieldNode::new(0, 0); num_nodes];
+        let buffers = vec![crate::Buffer::new(0, 0); buffer_lengths.len()];
+        let header = record_batch_message(
+            batch.num_rows(),
+            &nodes,
+            &buffers,
+            &variadic_buffer_counts,
+            body_len,
+            write_options,
+        );
+
+        let prefix_size = if write_options.write_legacy_ipc_format {
+            4
+        } else {
+            8
+        };
+        let header_len = header.len() + prefix_size;
+        Ok(header_len + pad_to_alignment(write_options.alignment, header_len) + body_len)
+    }
+
     /// Write a `RecordBatch` into two sets of bytes, one for the header (crate::Message) and the
     /// other for the batch's data
     fn record_batch_to_bytes(
@@ -508,25 +568,15 @@ impl IpcDataGenerator {
         batch: &RecordBatch,
         write_options: &IpcWriteOptions,
     ) -> Result<EncodedData, ArrowError> {
-        let mut fbb = FlatBufferBuilder::new();
-
         let mut nodes: Vec<crate::FieldNode> = vec![];
         let mut buffers: Vec<crate::Buffer> = vec![];
         let mut arrow_data: Vec<u8> = vec![];
         let mut offset = 0;
 
-        // get the type of compression
-        let batch_compression_type = write_options.batch_compression_type;
-
-        let compression = batch_compression_type.map(|batch_compression_type| {
-            let mut c = crate::BodyCompressionBuilder::new(&mut fbb);
-            c.add_method(crate::BodyCompressionMethod::BUFFER);
-            c.add_codec(batch_compression_type);
-            c.finish()
-        });
-
-        let compression_codec: Option<CompressionCodec> =
-            batch_compression_type.map(TryInto::try_into).transpose()?;
+        let compression_codec: Option<CompressionCodec> = write_options
+            .batch_compression_type
+            .map(TryInto::try_into)
+            .transpose()?;
 
         let mut variadic_buffer_counts = vec![];
 
@@ -551,42 +601,17 @@ impl IpcDataGenerator {
         let pad_len = pad_to_alignment(write_options.alignment, len);
         arrow_data.extend_from_slice(&PADDING[..pad_len]);
 
-        // write data
-        let buffers = fbb.create_vector(&buffers);
-        let nodes = fbb.create_vector(&nodes);
-        let variadic_buffer = if variadic_buffer_counts.is_empty() {
-            None
-        } else {
-            Some(fbb.create_vector(&variadic_buffer_counts))
-        };
-
-        let root = {
-            let mut batch_builder = crate::RecordBatchBuilder::new(&mut fbb);
-            batch_builder.add_length(batch.num_rows() as i64);
-            batch_builder.add_nodes(nodes);
-            batch_builder.add_buffers(buffers);
-            if let Some(c) = compression {
-                batch_builder.add_compression(c);
-            }
-
-            if let Some(v) = variadic_buffer {
-                batch_builder.add_variadicBufferCounts(v);
-            }
-            let b = batch_builder.finish();
-            b.as_union_value()
-        };
-        // create an crate::Message
-        let mut message = crate::MessageBuilder::new(&mut fbb);
-        message.add_version(write_options.metadata_version);
-        message.add_header_type(crate::MessageHeader::RecordBatch);
-        message.add_bodyLength(arrow_data.len() as i64);
-        message.add_header(root);
-        let root = message.finish();
-        fbb.finish(root, None);
-        let finished_data = fbb.finished_data();
+        let ipc_message = record_batch_message(
+            batch.num_rows(),
+            &nodes,
+            &buffers,
+            &variadic_buffer_counts,
+            arrow_data.len(),
+            write_options,
+        );
 
         Ok(EncodedData {
-            ipc_message: finished_data.to_vec(),
+            ipc_message,
             arrow_data,
         })
     }
@@ -688,6 +713,62 @@ impl IpcDataGenerator {
     }
 }
 
+/// Encodes the [`crate::Message`] header of a record batch of `num_rows` rows, whose
+/// body of `body_len` bytes is described by `nodes` and `buffers`
+fn record_batch_message(
+    num_rows: usize,
+    nodes: &[crate::FieldNode],
+    buffers: &[crate::Buffer],
+    variadic_buffer_counts: &[i64],
+    body_len: usize,
+    write_options: &IpcWriteOptions,
+) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    // get the type of compression
+    let compression = write_options
+        .batch_compression_type
+        .map(|batch_compression_type| {
+            let mut c = crate::BodyCompressionBuilder::new(&mut fbb);
+            c.add_method(crate::BodyCompressionMethod::BUFFER);
+            c.add_codec(batch_compression_type);
+            c.finish()
+        });
+
+    let buffers = fbb.create_vector(buffers);
+    let nodes = fbb.create_vector(nodes);
+    let variadic_buffer = if variadic_buffer_counts.is_empty() {
+        None
+    } else {
+        Some(fbb.create_vector(variadic_buffer_counts))
+    };
+
+    let root = {
+        let mut batch_builder = crate::RecordBatchBuilder::new(&mut fbb);
+        batch_builder.add_length(num_rows as i64);
+        batch_builder.add_nodes(nodes);
+        batch_builder.add_buffers(buffers);
+        if let Some(c) = compression {
+            batch_builder.add_compression(c);
+        }
+
+        if let Some(v) = variadic_buffer {
+            batch_builder.add_variadicBufferCounts(v);
+        }
+        let b = batch_builder.finish();
+        b.as_union_value()
+    };
+    // create an crate::Message
+    let mut message = crate::MessageBuilder::new(&mut fbb);
+    message.add_version(write_options.metadata_version);
+    message.add_header_type(crate::MessageHeader::RecordBatch);
+    message.add_bodyLength(body_len as i64);
+    message.add_header(root);
+    let root = message.finish();
+    fbb.finish(root, None);
+    fbb.finished_data().to_vec()
+}
+
 fn append_variadic_buffer_counts(counts: &mut Vec<i64>, array: &ArrayData) {
     match array.data_type() {
         DataType::BinaryView | DataType::Utf8View => {
@@ -1272,6 +1353,15 @@ impl<W: Write> StreamWriter<W> {
         Ok(())
     }
 
+    /// Returns the number of bytes [`Self::write`] writes for the record batch message
+    /// of `batch`, excluding any dictionary batches, without encoding it
+    ///
+    /// See [`IpcDataGenerator::estimated_encoded_size`] for details
+    pub fn estimated_encoded_size(&self, batch: &RecordBatch) -> Result<usize, ArrowError> {
+        self.data_gen
+            .estimated_encoded_size(batch, &self.write_options)
+    }
+
     /// Write continuation bytes, and mark the stream as done
     pub fn finish(&mut self) -> Result<(), ArrowError> {
         if self.finished {
@@ -1802,6 +1892,106 @@ fn write_array_data(
     Ok(offset)
 }
 
+/// Adds the number of field nodes and the length of each buffer [`write_array_data`]
+/// writes for `array_data` to `num_nodes` and `buffer_lengths`, without copying any data
+fn array_data_encoded_size(
+    array_data: &ArrayData,
+    num_rows: usize,
+    write_options: &IpcWriteOptions,
+    num_nodes: &mut usize,
+    buffer_lengths: &mut Vec<usize>,
+) -> Result<(), ArrowError> {
+    *num_nodes += 1;
+    if has_validity_bitmap(array_data.data_type(), write_options) {
+        buffer_lengths.push(bit_util::ceil(num_rows, 8));
+    }
+
+    let data_type = array_data.data_type();
+    let children = match data_type {
+        DataType::Binary | DataType::Utf8 => {
+            let (offsets, _, len) = offsets_encoded_size::<i32>(array_data);
+            buffer_lengths.extend([offsets, len]);
+            vec![]
+        }
+        DataType::LargeBinary | DataType::LargeUtf8 => {
+            let (offsets, _, len) = offsets_encoded_size::<i64>(array_data);
+            buffer_lengths.extend([offsets, len]);
+            vec![]
+        }
+        DataType::BinaryView | DataType::Utf8View => {
+            buffer_lengths.extend(array_data.buffers().iter().map(|b| b.len()));
+            vec![]
+        }
+        d if d.is_numeric()
+            || d.is_temporal()
+            || matches!(d, DataType::FixedSizeBinary(_) | DataType::Dictionary(_, _)) =>
+        {
+            let buffer = &array_data.buffers()[0];
+            let layout = layout(data_type);
+            let spec = &layout.buffers[0];
+
+            let byte_width = get_buffer_element_width(spec);
+            let min_length = array_data.len() * byte_width;
+            let len = if buffer_need_truncate(array_data.offset(), buffer, spec, min_length) {
+                min(min_length, buffer.len() - array_data.offset() * byte_width)
+            } else {
+                buffer.len()
+            };
+            buffer_lengths.push(len);
+            vec![]
+        }
+        DataType::Boolean => {
+            buffer_lengths.push(bit_util::ceil(array_data.len(), 8));
+            vec![]
+        }
+        DataType::List(_) | DataType::Map(_, _) | DataType::LargeList(_) => {
+            let (offsets, start, len) = match data_type {
+                DataType::LargeList(_) => offsets_encoded_size::<i64>(array_data),
+                _ => offsets_encoded_size::<i32>(array_data),
+            };
+            buffer_lengths.push(offsets);
+            vec![array_data.child_data()[0].slice(start, len)]
+        }
+        DataType::FixedSizeList(_, fixed_size) => {
+            let fixed_size = *fixed_size as usize;
+            let child_offset = array_data.offset() * fixed_size;
+            let child_length = array_data.len() * fixed_size;
+            vec![array_data.child_data()[0].slice(child_offset, child_length)]
+        }
+        DataType::RunEndEncoded(_, _) => {
+            buffer_lengths.extend(array_data.buffers().iter().map(|b| b.len()));
+            unslice_run_array(array_data.clone())?.child_data().to_vec()
+        }
+        _ => {
+            buffer_lengths.extend(array_data.buffers().iter().map(|b| b.len()));
+            array_data.child_data().to_vec()
+        }
+    };
+
+    for child in children {
+        array_data_encoded_size(
+            &child,
+            child.len(),
+            write_options,
+            num_nodes,
+            buffer_lengths,
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns the length of the offsets [`reencode_offsets`] writes for `data`, along
+/// with the start offset and length of the values they reference
+fn offsets_encoded_size<O: OffsetSizeTrait>(data: &ArrayData) -> (usize, usize, usize) {
+    if data.is_empty() {
+        return (0, 0, 0);
+    }
+    let offsets = &data.buffers()[0].typed_data::<O>()[data.offset()..];
+    let start = offsets[0].as_usize();
+    let end = offsets[data.len()].as_usize();
+    ((data.len() + 1) * size_of::<O>(), start, end - start)
+}
+
 /// Write a buffer into `arrow_data`, a vector of bytes, and adds its
 /// [`crate::Buffer`] to `buffers`. Returns the new offset in `arrow_data`
 ///
@@ -2500,6 +2690,124 @@ mod tests {
         );
     }
 
+    fn estimated_size_batch() -> RecordBatch {
+        let ints = Int32Array::from_iter((0..100).map(|i| (i % 3 != 0).then_some(i)));
+        let strings = StringArray::from_iter_values((0..100).map(|i| "x".repeat(i % 7)));
+        let large_strings =
+            LargeStringArray::from_iter((0..100).map(|i| (i % 2 == 0).then_some("abc")));
+        let views =
+            StringViewArray::from_iter_values((0..100).map(|i| format!("a long string value {i}")));
+        let bools = BooleanArray::from_iter((0..100).map(|i| Some(i % 5 == 0)));
+
+        let mut lists = ListBuilder::new(Int64Builder::new());
+        for i in 0..100 {
+            lists.values().append_slice(&vec![i; i as usize % 4]);
+            lists.append(i % 9 != 0);
+        }
+        let mut fixed = FixedSizeListBuilder::new(Float32Builder::new(), 2);
+        for i in 0..100 {
+            fixed.values().append_slice(&[i as f32, -(i as f32)]);
+            fixed.append(true);
+        }
+        let dict: DictionaryArray<Int8Type> = (0..100).map(|i| ["a", "b", "c"][i % 3]).collect();
+        let mut runs = PrimitiveRunBuilder::<Int16Type, UInt32Type>::new();
+        runs.extend((0..100).map(|i| Some(i / 10)));
+        let structs = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int32, true)),
+                Arc::new(ints.clone()) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::Utf8, false)),
+                Arc::new(strings.clone()) as ArrayRef,
+            ),
+        ]);
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(ints),
+            Arc::new(strings),
+            Arc::new(large_strings),
+            Arc::new(views),
+            Arc::new(bools),
+            Arc::new(lists.finish()),
+            Arc::new(fixed.finish()),
+            Arc::new(dict),
+            Arc::new(runs.finish()),
+            Arc::new(structs),
+            Arc::new(NullArray::new(100)),
+        ];
+        RecordBatch::try_from_iter(
+            columns
+                .into_iter()
+                .enumerate()
+                .map(|(i, c)| (i.to_string(), c)),
+        )
+        .unwrap()
+    }
+
+    fn encoded_size(batch: &RecordBatch, write_options: &IpcWriteOptions) -> usize {
+        let mut tracker = DictionaryTracker::new(false);
+        let (_, encoded) = IpcDataGenerator::default()
+            .encoded_batch(batch, &mut tracker, write_options)
+            .unwrap();
+        let (header_len, body_len) = write_message(vec![], encoded, write_options).unwrap();
+        header_len + body_len
+    }
+
+    #[test]
+    fn test_estimated_encoded_size() {
+        let batch = estimated_size_batch();
+        let gen = IpcDataGenerator::default();
+        let options = [
+            IpcWriteOptions::default(),
+            IpcWriteOptions::try_new(64, false, MetadataVersion::V5).unwrap(),
+            IpcWriteOptions::try_new(8, true, MetadataVersion::V4).unwrap(),
+        ];
+        for write_options in &options {
+            for (offset, len) in [(0, 100), (13, 50), (99, 1), (40, 0)] {
+                let batch = batch.slice(offset, len);
+                assert_eq!(
+                    gen.estimated_encoded_size(&batch, write_options).unwrap(),
+                    encoded_size(&batch, write_options),
+                    "{write_options:?} {offset} {len}"
+                );
+            }
+        }
+
+        // The stream writer estimates the record batch message written by `write`
+        let mut writer = StreamWriter::try_new(vec![], batch.schema_ref()).unwrap();
+        let start = writer.get_ref().len();
+        let batch = batch.slice(20, 30);
+        let estimated = writer.estimated_encoded_size(&batch).unwrap();
+        writer.write(&batch).unwrap();
+        let mut dictionaries = DictionaryTracker::new(false);
+        let (encoded_dictionaries, _) = gen
+            .encoded_batch(&batch, &mut dictionaries, &IpcWriteOptions::default())
+            .unwrap();
+        let dictionary_len: usize = encoded_dictionaries
+            .into_iter()
+            .map(|d| {
+                let (header_len, body_len) =
+                    write_message(vec![], d, &IpcWriteOptions::default()).unwrap();
+                header_len + body_len
+            })
+            .sum();
+        assert_eq!(writer.get_ref().len() - start, estimated + dictionary_len);
+    }
+
+    #[test]
+    #[cfg(feature = "lz4")]
+    fn test_estimated_encoded_size_compression() {
+        let batch = estimated_size_batch();
+        let write_options = IpcWriteOptions::default()
+            .try_with_compression(Some(crate::CompressionType::LZ4_FRAME))
+            .unwrap();
+        let estimated = IpcDataGenerator::default()
+            .estimated_encoded_size(&batch, &write_options)
+            .unwrap();
+        assert!(estimated >= encoded_size(&batch, &write_options));
+    }
+
     #[test]
     fn test_large_slice_uint32() {
         ensure_roundtrip(Arc::new(UInt32Array::from_iter((0..8000).map(|i| {
@@ -433,6 +433,117 @@ where
     }
 }
 
+/// Converts the value at `index` of `array` to a [`serde_json::Value`], encoding it
+/// as per [`Writer`] with explicit nulls. A null value is converted to
+/// [`serde_json::Value::Null`]
+///
+/// This is intended for debugging and logging individual values. Converting many
+/// values is more efficiently done with a [`Writer`].
+///
+/// # Errors
+/// This function errors if `index` is out of bounds, or the data type of `array`
+/// cannot be encoded
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, ListArray, StringArray};
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_json::writer::array_value_to_json;
+/// # use serde_json::json;
+/// let array = StringArray::from(vec![Some("a"), None]);
+/// assert_eq!(array_value_to_json(&array, 0).unwrap(), json!("a"));
+/// assert_eq!(array_value_to_json(&array, 1).unwrap(), json!(null));
+///
+/// let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), None]),
+/// ]);
+/// assert_eq!(array_value_to_json(&array, 0).unwrap(), json!([1, null]));
+/// ```
+pub fn array_value_to_json(
+    array: &dyn Array,
+    index: usize,
+) -> Result<serde_json::Value, ArrowError> {
+    if index >= array.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "index {index} is out of bounds for array of length {}",
+            array.len()
+        )));
+    }
+    let array = array.slice(index, 1);
+    let field = Arc::new(Field::new("", array.data_type().clone(), true));
+    let options = EncoderOptions::default().with_explicit_nulls(true);
+    let mut encoder = make_encoder(&field, &array, &options)?;
+    if encoder.is_null(0) {
+        return Ok(serde_json::Value::Null);
+    }
+
+    let mut buffer = Vec::new();
+    encoder.encode(0, &mut buffer);
+    serde_json::from_slice(&buffer).map_err(|e| ArrowError::JsonError(e.to_string()))
+}
+
+/// Returns an iterator converting the rows of `batch` to JSON objects, one row at a
+/// time, with values encoded as per [`Writer`] with `options`
+///
+/// Null values are included in the objects as [`serde_json::Value::Null`] if
+/// [`EncoderOptions::explicit_nulls`] is set, and omitted otherwise. The encoders
+/// of the columns are created once, and each row is then encoded lazily.
+///
+/// # Errors
+/// If a data type of `batch` cannot be encoded, the iterator only returns this error
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+/// # use arrow_json::writer::{rows_as_json, EncoderOptions};
+/// # use serde_json::json;
+/// let batch = RecordBatch::try_from_iter(vec![
+///     ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+///     ("b", Arc::new(StringArray::from(vec![Some("x"), None]))),
+/// ])
+/// .unwrap();
+/// let options = EncoderOptions::default().with_explicit_nulls(true);
+/// let rows: Vec<_> = rows_as_json(&batch, &options).map(|r| r.unwrap()).collect();
+/// assert_eq!(rows[0], *json!({"a": 1, "b": "x"}).as_object().unwrap());
+/// assert_eq!(rows[1], *json!({"a": 2, "b": null}).as_object().unwrap());
+/// ```
+pub fn rows_as_json<'a>(
+    batch: &'a RecordBatch,
+    options: &'a EncoderOptions,
+) -> impl Iterator<Item = Result<serde_json::Map<String, serde_json::Value>, ArrowError>> + 'a {
+    let fields = batch.schema_ref().fields();
+    let encoders = fields
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, column)| make_encoder(field, column.as_ref(), options))
+        .collect::<Result<Vec<_>, _>>();
+    let (mut encoders, rows, error) = match encoders {
+        Ok(encoders) => (encoders, 0..batch.num_rows(), None),
+        Err(e) => (vec![], 0..0, Some(e)),
+    };
+
+    let mut buffer = Vec::new();
+    let rows = rows.map(move |row| {
+        let mut object = serde_json::Map::with_capacity(encoders.len());
+        for (field, encoder) in fields.iter().zip(&mut encoders) {
+            let value = match encoder.is_null(row) {
+                true if !options.explicit_nulls() => continue,
+                true => serde_json::Value::Null,
+                false => {
+                    buffer.clear();
+                    encoder.encode(row, &mut buffer);
+                    serde_json::from_slice(&buffer)
+                        .map_err(|e| ArrowError::JsonError(e.to_string()))?
+                }
+            };
+            object.insert(field.name().clone(), value);
+        }
+        Ok(object)
+    });
+    error.map(Err).into_iter().chain(rows)
+}
+
 #[cfg(test)]
 mod tests {
     use core::str;
@@ -2467,4 +2578,105 @@ mod tests {
 
         assert_eq!(json_value, expected);
     }
+
+    #[test]
+    fn test_array_value_to_json() {
+        let ints = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let strings = StringArray::from(vec![Some("a"), Some("b"), None]);
+        let structs = StructArray::from(vec![
+            (
+                Arc::new(Field::new("i", DataType::Int32, true)),
+                Arc::new(ints.clone()) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("s", DataType::Utf8, true)),
+                Arc::new(strings) as ArrayRef,
+            ),
+        ]);
+        let values: Vec<_> = (0..3)
+            .map(|i| array_value_to_json(&structs, i).unwrap())
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                json!({"i": 1, "s": "a"}),
+                json!({"i": null, "s": "b"}),
+                json!({"i": 3, "s": null}),
+            ]
+        );
+
+        // Sliced arrays
+        let sliced = ints.slice(1, 2);
+        assert_eq!(array_value_to_json(&sliced, 0).unwrap(), Value::Null);
+        assert_eq!(array_value_to_json(&sliced, 1).unwrap(), json!(3));
+
+        // Nested types
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("k");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        let maps = builder.finish();
+        assert_eq!(array_value_to_json(&maps, 0).unwrap(), json!({"k": null}));
+        assert_eq!(array_value_to_json(&maps, 1).unwrap(), Value::Null);
+
+        let dictionary: DictionaryArray<Int8Type> = vec!["x", "y", "x"].into_iter().collect();
+        assert_eq!(array_value_to_json(&dictionary, 2).unwrap(), json!("x"));
+
+        let floats = Float64Array::from(vec![1.5, f64::NAN]);
+        assert_eq!(array_value_to_json(&floats, 0).unwrap(), json!(1.5));
+        assert_eq!(array_value_to_json(&floats, 1).unwrap(), Value::Null);
+
+        let err = array_value_to_json(&sliced, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: index 2 is out of bounds for array of length 2"
+        );
+    }
+
+    #[test]
+    fn test_rows_as_json() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+        ]);
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef),
+            ("b", Arc::new(list) as ArrayRef),
+        ])
+        .unwrap();
+
+        let options = EncoderOptions::default().with_explicit_nulls(true);
+        let sliced = batch.slice(1, 2);
+        let rows = rows_as_json(&sliced, &options);
+        let rows: Vec<_> = rows.map(|r| Value::Object(r.unwrap())).collect();
+        assert_eq!(
+            rows,
+            vec![json!({"a": 2, "b": null}), json!({"a": 3, "b": []})]
+        );
+
+        // The rows are consistent with a writer with explicit nulls
+        let mut writer = WriterBuilder::new()
+            .with_explicit_nulls(true)
+            .build::<_, LineDelimited>(Vec::new());
+        writer.write(&batch).unwrap();
+        let written = writer.into_inner();
+        let expected: Vec<Value> = written
+            .split(|b| *b == b'\n')
+            .filter(|s| !s.is_empty())
+            .map(|s| serde_json::from_slice(s).unwrap())
+            .collect();
+        let rows: Vec<_> = rows_as_json(&batch, &options)
+            .map(|r| Value::Object(r.unwrap()))
+            .collect();
+        assert_eq!(rows, expected);
+
+        // Nulls are omitted without explicit nulls
+        let options = EncoderOptions::default();
+        let rows: Vec<_> = rows_as_json(&sliced, &options)
+            .map(|r| Value::Object(r.unwrap()))
+            .collect();
+        assert_eq!(rows, vec![json!({"a": 2}), json!({"a": 3, "b": []})]);
+    }
 }
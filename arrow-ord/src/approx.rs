@@ -0,0 +1,224 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`approx_eq`]: compare arrays of floating point values within a tolerance
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::NullBuffer;
+use arrow_schema::DataType;
+
+/// Returns true if `a` and `b` have the same data type, length and nulls, and their
+/// floating point values differ by at most `epsilon`, with `NaN` equal to `NaN`
+///
+/// This is intended for assertions over computed floating point values, see
+/// [`approx_eq_with_nan`] for details
+///
+/// # Example
+/// ```
+/// # use arrow_array::Float64Array;
+/// # use arrow_ord::approx::approx_eq;
+/// let a = Float64Array::from(vec![Some(0.1 + 0.2), None, Some(f64::NAN)]);
+/// let b = Float64Array::from(vec![Some(0.3), None, Some(f64::NAN)]);
+/// assert_ne!(a, b);
+/// assert!(approx_eq(&a, &b, 1e-9));
+/// ```
+pub fn approx_eq(a: &dyn Array, b: &dyn Array, epsilon: f64) -> bool {
+    approx_eq_with_nan(a, b, epsilon, true)
+}
+
+/// Returns true if `a` and `b` have the same data type, length and nulls, and their
+/// floating point values differ by at most `epsilon`
+///
+/// `NaN` is equal to `NaN` if `nan_equal` is true, and otherwise not equal to any
+/// value. Infinite values are only equal to infinite values of the same sign.
+///
+/// Floating point values of lists, fixed size lists and structs are compared within
+/// `epsilon`, and values under nulls are ignored. All other values, including those of
+/// dictionaries, are compared exactly, as per [`PartialEq`] for
+/// [`ArrayData`](arrow_data::ArrayData)
+pub fn approx_eq_with_nan(a: &dyn Array, b: &dyn Array, epsilon: f64, nan_equal: bool) -> bool {
+    a.data_type() == b.data_type() && a.len() == b.len() && equal(a, b, epsilon, nan_equal)
+}
+
+/// Compares `a` and `b` of the same data type and length
+fn equal(a: &dyn Array, b: &dyn Array, epsilon: f64, nan_equal: bool) -> bool {
+    if !nulls_equal(a.nulls(), b.nulls()) {
+        return false;
+    }
+    let eq = |x: f64, y: f64| {
+        x == y || (x.is_nan() && y.is_nan() && nan_equal) || (x - y).abs() <= epsilon
+    };
+    match a.data_type() {
+        DataType::Float16 => float_equal::<Float16Type>(a, b, |x, y| eq(x.to_f64(), y.to_f64())),
+        DataType::Float32 => float_equal::<Float32Type>(a, b, |x, y| eq(x as f64, y as f64)),
+        DataType::Float64 => float_equal::<Float64Type>(a, b, eq),
+        DataType::List(_) => list_equal(a.as_list::<i32>(), b.as_list(), epsilon, nan_equal),
+        DataType::LargeList(_) => list_equal(a.as_list::<i64>(), b.as_list(), epsilon, nan_equal),
+        DataType::FixedSizeList(_, _) => {
+            let (a, b) = (a.as_fixed_size_list(), b.as_fixed_size_list());
+            (0..a.len())
+                .filter(|&i| a.is_valid(i))
+                .all(|i| equal(&a.value(i), &b.value(i), epsilon, nan_equal))
+        }
+        DataType::Struct(_) => {
+            let (a, b) = (a.as_struct(), b.as_struct());
+            let mut columns = a.columns().iter().zip(b.columns());
+            match a.nulls().filter(|n| n.null_count() > 0) {
+                None => columns.all(|(a, b)| equal(a, b, epsilon, nan_equal)),
+                Some(nulls) => nulls.valid_slices().all(|(start, end)| {
+                    columns.clone().all(|(a, b)| {
+                        let (a, b) = (a.slice(start, end - start), b.slice(start, end - start));
+                        equal(&a, &b, epsilon, nan_equal)
+                    })
+                }),
+            }
+        }
+        _ => a.to_data() == b.to_data(),
+    }
+}
+
+/// Returns true if the valid values of `a` and `b` are equal as per `eq`
+fn float_equal<T: ArrowPrimitiveType>(
+    a: &dyn Array,
+    b: &dyn Array,
+    eq: impl Fn(T::Native, T::Native) -> bool,
+) -> bool {
+    let (a, b) = (a.as_primitive::<T>(), b.as_primitive::<T>());
+    let mut values = a.values().iter().zip(b.values().iter()).enumerate();
+    values.all(|(i, (x, y))| a.is_null(i) || eq(*x, *y))
+}
+
+fn list_equal<O: OffsetSizeTrait>(
+    a: &GenericListArray<O>,
+    b: &GenericListArray<O>,
+    epsilon: f64,
+    nan_equal: bool,
+) -> bool {
+    (0..a.len()).filter(|&i| a.is_valid(i)).all(|i| {
+        a.value_length(i) == b.value_length(i)
+            && equal(&a.value(i), &b.value(i), epsilon, nan_equal)
+    })
+}
+
+/// Returns true if `a` and `b` have the same null slots, treating a missing
+/// [`NullBuffer`] as all valid
+fn nulls_equal(a: Option<&NullBuffer>, b: Option<&NullBuffer>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(n), None) | (None, Some(n)) => n.null_count() == 0,
+        (Some(a), Some(b)) => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{Float32Builder, ListBuilder};
+    use arrow_schema::Field;
+    use half::f16;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_approx_eq_float() {
+        let a = Float64Array::from(vec![Some(1.0), None, Some(f64::NAN), Some(f64::INFINITY)]);
+        let b = Float64Array::from(vec![Some(1.05), None, Some(f64::NAN), Some(f64::INFINITY)]);
+        assert!(approx_eq(&a, &b, 0.1));
+        assert!(!approx_eq(&a, &b, 0.01));
+        assert!(!approx_eq_with_nan(&a, &b, 0.1, false));
+
+        // Values under nulls are ignored
+        let c = Float64Array::new(
+            vec![1.0, 100.0, f64::NAN, f64::INFINITY].into(),
+            Some(NullBuffer::from(vec![true, false, true, true])),
+        );
+        assert!(approx_eq(&a, &c, 0.0));
+
+        // Nulls, infinities and lengths must match
+        let d = Float64Array::from(vec![
+            Some(1.0),
+            Some(0.0),
+            Some(f64::NAN),
+            Some(f64::INFINITY),
+        ]);
+        assert!(!approx_eq(&a, &d, 1.0));
+        let d = Float64Array::from(vec![Some(1.0), None, Some(f64::NAN), Some(f64::MAX)]);
+        assert!(!approx_eq(&a, &d, 1.0));
+        let d = Float64Array::from(vec![Some(1.0), None, Some(f64::NAN), Some(-f64::INFINITY)]);
+        assert!(!approx_eq(&a, &d, f64::MAX));
+        assert!(!approx_eq(&a, &a.slice(0, 3), 1.0));
+
+        // Sliced arrays
+        assert!(approx_eq(&a.slice(1, 3), &b.slice(1, 3), 0.0));
+
+        let a = Float32Array::from(vec![0.1_f32 + 0.2_f32, 2.5]);
+        let b = Float32Array::from(vec![0.3_f32, 2.5]);
+        assert!(approx_eq(&a, &b, 1e-6));
+        assert!(!approx_eq(&a, &Float64Array::from(vec![0.3, 2.5]), 1.0));
+
+        let a = Float16Array::from(vec![f16::from_f32(1.0), f16::NAN]);
+        let b = Float16Array::from(vec![f16::from_f32(1.001), f16::NAN]);
+        assert!(approx_eq(&a, &b, 0.01));
+        assert!(!approx_eq_with_nan(&a, &b, 0.01, false));
+    }
+
+    #[test]
+    fn test_approx_eq_nested() {
+        let list = |values: Vec<Option<Vec<Option<f32>>>>| {
+            let mut builder = ListBuilder::new(Float32Builder::new());
+            for v in values {
+                builder.append_option(v);
+            }
+            builder.finish()
+        };
+        let a = list(vec![Some(vec![Some(1.0), None]), None, Some(vec![])]);
+        let b = list(vec![Some(vec![Some(1.01), None]), None, Some(vec![])]);
+        assert!(approx_eq(&a, &b, 0.1));
+        assert!(!approx_eq(&a, &b, 0.001));
+        let c = list(vec![Some(vec![Some(1.0)]), None, Some(vec![None])]);
+        assert!(!approx_eq(&a, &c, 0.1));
+
+        let fields = vec![
+            Field::new("f", DataType::Float64, true),
+            Field::new("s", DataType::Utf8, true),
+        ];
+        let strukt = |f: Vec<f64>, s: Vec<&str>| {
+            StructArray::new(
+                fields.clone().into(),
+                vec![
+                    Arc::new(Float64Array::from(f)),
+                    Arc::new(StringArray::from(s)),
+                ],
+                Some(NullBuffer::from(vec![true, false, true])),
+            )
+        };
+        let a = strukt(vec![1.0, 2.0, 3.0], vec!["a", "b", "c"]);
+        // Values under null rows are ignored
+        let b = strukt(vec![1.001, 20.0, 3.0], vec!["a", "z", "c"]);
+        assert!(approx_eq(&a, &b, 0.01));
+        assert!(!approx_eq(&a, &b, 0.0001));
+        // Other types are compared exactly
+        let c = strukt(vec![1.0, 2.0, 3.0], vec!["a", "b", "d"]);
+        assert!(!approx_eq(&a, &c, 1.0));
+        assert!(approx_eq(&a.slice(1, 2), &b.slice(1, 2), 0.0));
+
+        let a = StringArray::from(vec!["a", "b"]);
+        assert!(approx_eq(&a, &a, 0.0));
+        assert!(!approx_eq(&a, &StringArray::from(vec!["a", "c"]), 1.0));
+    }
+}
@@ -232,6 +232,29 @@ fn compare_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray,
     let r = r_v.map(|x| x.values().as_ref()).unwrap_or(r);
     let r_t = r.data_type();
 
+    if (l_t == &Null) != (r_t == &Null) {
+        // Comparing with an untyped NULL, such as a SQL NULL literal
+        let (nulls, is_scalar) = match l_t {
+            Null => (r_nulls, r_s),
+            _ => (l_nulls, l_s),
+        };
+        return Ok(match op {
+            Op::Distinct | Op::NotDistinct => {
+                // NULL is only not distinct from other nulls
+                let valid = match (nulls, is_scalar) {
+                    (Some(n), false) => n.into_inner(),
+                    (Some(n), true) if n.is_null(0) => BooleanBuffer::new_unset(len),
+                    _ => BooleanBuffer::new_set(len),
+                };
+                match op {
+                    Op::Distinct => valid.into(),
+                    _ => valid.not().into(),
+                }
+            }
+            _ => BooleanArray::new_null(len),
+        });
+    }
+
     if r_t.is_nested() || l_t.is_nested() {
         return Err(ArrowError::InvalidArgumentError(format!(
             "Nested comparison: {l_t} {op} {r_t} (hint: use make_comparator instead)"
@@ -697,7 +720,7 @@ pub unsafe fn compare_byte_view_unchecked<T: ByteViewType>(
 mod tests {
     use std::sync::Arc;
 
-    use arrow_array::{DictionaryArray, Int32Array, Scalar, StringArray};
+    use arrow_array::{DictionaryArray, Int32Array, NullArray, Scalar, StringArray};
 
     use super::*;
 
@@ -855,4 +878,48 @@ mod tests {
 
         neq(&col.slice(0, col.len() - 1), &col.slice(1, col.len() - 1)).unwrap();
     }
+
+    #[test]
+    fn test_compare_null_array() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let n = NullArray::new(3);
+
+        for r in [eq(&a, &n), lt(&n, &a), gt_eq(&a, &n)] {
+            assert_eq!(r.unwrap(), BooleanArray::new_null(3));
+        }
+
+        let r = distinct(&a, &n).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false, true]));
+        let r = not_distinct(&n, &a).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true, false]));
+
+        // Scalars
+        let s = Scalar::new(NullArray::new(1));
+        assert_eq!(eq(&a, &s).unwrap(), BooleanArray::new_null(3));
+        let r = distinct(&s, &a).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false, true]));
+
+        let s = Scalar::new(Int32Array::from(vec![1]));
+        assert_eq!(neq(&n, &s).unwrap(), BooleanArray::new_null(3));
+        let r = not_distinct(&s, &n).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, false, false]));
+
+        let s = Scalar::new(Int32Array::new_null(1));
+        let r = not_distinct(&n, &s).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, true, true]));
+
+        // Dictionaries
+        let keys = Int32Array::from(vec![Some(0), None, Some(1)]);
+        let values = StringArray::from(vec!["a", "b"]);
+        let d = DictionaryArray::new(keys, Arc::new(values));
+        assert_eq!(lt_eq(&d, &n).unwrap(), BooleanArray::new_null(3));
+        let r = distinct(&n, &d).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false, true]));
+
+        let err = eq(&a, &NullArray::new(2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot compare arrays of different lengths, got 3 vs 2"
+        );
+    }
 }
@@ -49,6 +49,7 @@
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![warn(missing_docs)]
+pub mod approx;
 pub mod cmp;
 #[doc(hidden)]
 pub mod comparison;
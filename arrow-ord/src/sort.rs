@@ -165,6 +165,114 @@ pub fn sort_limit(
     take(values, &indices, None)
 }
 
+/// Returns the index of the element of `array` that would be last if it were
+/// sorted with `options`, or `None` if `array` is empty.
+///
+/// With the default [`SortOptions`] this is the index of the maximum, with nulls
+/// considered smaller than all other values, such that a null is only returned
+/// if all elements are null. Setting `nulls_first: false` orders nulls after all
+/// other values instead, and `descending: true` returns the index of the minimum.
+/// Floats are compared using IEEE 754 totalOrder. Where several elements compare
+/// equal, the index of the first is returned.
+///
+/// Returns an `ArrowError::InvalidArgumentError` if the array type cannot be compared,
+/// see [`make_comparator`]
+///
+/// # Example
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::sort::{argmax, SortOptions};
+/// let array = Int32Array::from(vec![Some(3), None, Some(7), Some(7)]);
+/// assert_eq!(argmax(&array, SortOptions::default()).unwrap(), Some(2));
+///
+/// let options = SortOptions {
+///     nulls_first: false,
+///     ..Default::default()
+/// };
+/// assert_eq!(argmax(&array, options).unwrap(), Some(1));
+/// ```
+pub fn argmax(array: &dyn Array, options: SortOptions) -> Result<Option<usize>, ArrowError> {
+    arg_extreme(array, options, Ordering::Greater)
+}
+
+/// Returns the index of the element of `array` that would be first if it were
+/// sorted with `options`, or `None` if `array` is empty.
+///
+/// With the default [`SortOptions`] this is the index of the minimum, with nulls
+/// considered smaller than all other values, such that a null is returned if
+/// there is any. Setting `nulls_first: false` orders nulls after all other values
+/// instead, and `descending: true` returns the index of the maximum.
+/// Floats are compared using IEEE 754 totalOrder. Where several elements compare
+/// equal, the index of the first is returned.
+///
+/// Returns an `ArrowError::InvalidArgumentError` if the array type cannot be compared,
+/// see [`make_comparator`]
+///
+/// # Example
+/// ```
+/// # use arrow_array::Float64Array;
+/// # use arrow_ord::sort::{argmin, SortOptions};
+/// let array = Float64Array::from(vec![Some(3.0), None, Some(-1.0), Some(f64::NAN)]);
+/// assert_eq!(argmin(&array, SortOptions::default()).unwrap(), Some(1));
+///
+/// let options = SortOptions {
+///     nulls_first: false,
+///     ..Default::default()
+/// };
+/// assert_eq!(argmin(&array, options).unwrap(), Some(2));
+/// ```
+pub fn argmin(array: &dyn Array, options: SortOptions) -> Result<Option<usize>, ArrowError> {
+    arg_extreme(array, options, Ordering::Less)
+}
+
+/// Returns the index of the first element of `array` to which no other element
+/// compares as `keep`
+fn arg_extreme(
+    array: &dyn Array,
+    options: SortOptions,
+    keep: Ordering,
+) -> Result<Option<usize>, ArrowError> {
+    if array.is_empty() {
+        return Ok(None);
+    }
+    let cmp = make_comparator(array, array, options)?;
+    let best = (1..array.len()).fold(0, |best, i| match cmp(i, best) == keep {
+        true => i,
+        false => best,
+    });
+    Ok(Some(best))
+}
+
+/// Returns true if `array` is sorted with `options`, that is if sorting it with
+/// `options` would not change its order, including the placement of nulls
+///
+/// This compares each pair of adjacent elements, returning on the first pair that is
+/// out of order. Floats are compared using IEEE 754 totalOrder, as per [`sort`].
+///
+/// Returns an `ArrowError::InvalidArgumentError` if the array type cannot be compared,
+/// see [`make_comparator`]
+///
+/// # Example
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::sort::{is_sorted, SortOptions};
+/// let array = Int32Array::from(vec![None, Some(1), Some(1), Some(3)]);
+/// assert!(is_sorted(&array, SortOptions::default()).unwrap());
+///
+/// let options = SortOptions {
+///     nulls_first: false,
+///     ..Default::default()
+/// };
+/// assert!(!is_sorted(&array, options).unwrap());
+/// ```
+pub fn is_sorted(array: &dyn Array, options: SortOptions) -> Result<bool, ArrowError> {
+    if array.len() < 2 {
+        return Ok(true);
+    }
+    let cmp = make_comparator(array, array, options)?;
+    Ok((1..array.len()).all(|i| cmp(i - 1, i).is_le()))
+}
+
 /// we can only do this if the T is primitive
 #[inline]
 fn sort_unstable_by<T, F>(array: &mut [T], limit: usize, cmp: F)
@@ -735,6 +843,77 @@ pub fn lexsort_to_indices(
     ))
 }
 
+/// Returns true if the rows of `columns` are sorted lexicographically, as per
+/// [`lexsort`], see [`find_unsorted_lexicographic`]
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, StringArray};
+/// # use arrow_ord::sort::{is_sorted_lexicographic, SortColumn};
+/// let columns = [
+///     SortColumn {
+///         values: Arc::new(Int32Array::from(vec![1, 1, 2])) as ArrayRef,
+///         options: None,
+///     },
+///     SortColumn {
+///         values: Arc::new(StringArray::from(vec!["b", "c", "a"])) as ArrayRef,
+///         options: None,
+///     },
+/// ];
+/// assert!(is_sorted_lexicographic(&columns).unwrap());
+/// ```
+pub fn is_sorted_lexicographic(columns: &[SortColumn]) -> Result<bool, ArrowError> {
+    Ok(find_unsorted_lexicographic(columns)?.is_none())
+}
+
+/// Returns the index of the first row of `columns` that is lexicographically less
+/// than the row before it, or `None` if the rows are sorted, as per [`lexsort`]
+///
+/// This compares each pair of adjacent rows with a [`LexicographicalComparator`],
+/// returning on the first pair that is out of order.
+///
+/// Returns an `ArrowError::InvalidArgumentError` if `columns` is empty, or a column
+/// cannot be compared, and an `ArrowError::ComputeError` if the columns have
+/// different row counts
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array};
+/// # use arrow_ord::sort::{find_unsorted_lexicographic, SortColumn, SortOptions};
+/// let columns = [
+///     SortColumn {
+///         values: Arc::new(Int32Array::from(vec![1, 1, 2, 2])) as ArrayRef,
+///         options: None,
+///     },
+///     SortColumn {
+///         values: Arc::new(Int32Array::from(vec![3, 2, 5, 6])) as ArrayRef,
+///         options: Some(SortOptions {
+///             descending: true,
+///             nulls_first: true,
+///         }),
+///     },
+/// ];
+/// assert_eq!(find_unsorted_lexicographic(&columns).unwrap(), Some(3));
+/// ```
+pub fn find_unsorted_lexicographic(columns: &[SortColumn]) -> Result<Option<usize>, ArrowError> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Sort requires at least one column".to_string(),
+        ));
+    }
+    let row_count = columns[0].values.len();
+    if columns.iter().any(|item| item.values.len() != row_count) {
+        return Err(ArrowError::ComputeError(
+            "lexical sort columns have different row counts".to_string(),
+        ));
+    };
+
+    let comparator = LexicographicalComparator::try_new(columns)?;
+    Ok((1..row_count).find(|&i| comparator.compare(i - 1, i).is_gt()))
+}
+
 /// It's unstable_sort, may not preserve the order of equal elements
 pub fn partial_sort<T, F>(v: &mut [T], limit: usize, mut is_less: F)
 where
@@ -2083,6 +2262,72 @@ mod tests {
         );
     }
 
+    /// Sorts the same negative and positive decimal values declared at several scales
+    fn test_sort_indices_decimal_scales<T: DecimalType>(
+        precision: u8,
+        native: impl Fn(i128) -> T::Native,
+    ) {
+        // The values -1.5, 2.25, None, -0.05, 0, 10, -1.5 and 0.5 at scale 2
+        let raw = [
+            Some(-150),
+            Some(225),
+            None,
+            Some(-5),
+            Some(0),
+            Some(1000),
+            Some(-150),
+            Some(50),
+        ];
+        let expected = UInt32Array::from(vec![2, 0, 6, 3, 4, 7, 1, 5]);
+        let descending = UInt32Array::from(vec![2, 5, 1, 7, 4, 3, 0, 6]);
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+
+        // Declaring a value at a larger scale multiplies its raw value by the same
+        // power of ten for all values, so the raw values preserve the order
+        for (scale, multiplier) in [(2, 1), (5, 1000), (20, 10_i128.pow(18))] {
+            let array = raw
+                .iter()
+                .map(|v| v.map(|v| native(v * multiplier)))
+                .collect::<PrimitiveArray<T>>()
+                .with_precision_and_scale(precision, scale)
+                .unwrap();
+            assert_eq!(sort_to_indices(&array, None, None).unwrap(), expected);
+            assert_eq!(
+                sort_to_indices(&array, Some(options), None).unwrap(),
+                descending
+            );
+        }
+
+        // Columns of different scales are each ordered by their own values
+        let a = PrimitiveArray::<T>::from_iter_values([1, 1, 0].map(&native))
+            .with_precision_and_scale(precision, 0)
+            .unwrap();
+        let b = PrimitiveArray::<T>::from_iter_values([5, -500, 0].map(&native))
+            .with_precision_and_scale(precision, 3)
+            .unwrap();
+        let columns = [
+            SortColumn {
+                values: Arc::new(a),
+                options: None,
+            },
+            SortColumn {
+                values: Arc::new(b),
+                options: None,
+            },
+        ];
+        let indices = lexsort_to_indices(&columns, None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![2, 1, 0]));
+    }
+
+    #[test]
+    fn test_sort_indices_decimal_mixed_sign_and_scale() {
+        test_sort_indices_decimal_scales::<Decimal128Type>(38, |v| v);
+        test_sort_indices_decimal_scales::<Decimal256Type>(76, i256::from_i128);
+    }
+
     fn test_sort_decimal<T: DecimalType>(precision: u8, scale: i8) {
         // decimal default
         test_sort_decimal_array::<T>(
@@ -4523,4 +4768,192 @@ mod tests {
 
         assert_eq!(&sorted[0], &expected_struct_array);
     }
+
+    #[test]
+    fn test_argmax_argmin() {
+        let array = Int32Array::from(vec![
+            Some(2),
+            None,
+            Some(5),
+            Some(-1),
+            Some(5),
+            None,
+            Some(-1),
+        ]);
+        let default = SortOptions::default();
+        let nulls_last = SortOptions {
+            nulls_first: false,
+            ..Default::default()
+        };
+        let descending = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+
+        assert_eq!(argmax(&array, default).unwrap(), Some(2));
+        assert_eq!(argmin(&array, default).unwrap(), Some(1));
+        assert_eq!(argmax(&array, nulls_last).unwrap(), Some(1));
+        assert_eq!(argmin(&array, nulls_last).unwrap(), Some(3));
+        assert_eq!(argmax(&array, descending).unwrap(), Some(1));
+        assert_eq!(argmin(&array, descending).unwrap(), Some(2));
+
+        // The result is consistent with sort_to_indices
+        let indices = sort_to_indices(&array, Some(nulls_last), None).unwrap();
+        let values: Vec<_> = indices
+            .values()
+            .iter()
+            .map(|i| array.value(*i as usize))
+            .collect();
+        let min = argmin(&array, nulls_last).unwrap().unwrap();
+        assert_eq!(array.value(min), values[0]);
+
+        let empty = Int32Array::from(Vec::<i32>::new());
+        assert_eq!(argmax(&empty, default).unwrap(), None);
+        assert_eq!(argmin(&empty, default).unwrap(), None);
+
+        let nulls = Int32Array::from(vec![None, None]);
+        assert_eq!(argmax(&nulls, default).unwrap(), Some(0));
+        assert_eq!(argmin(&nulls, nulls_last).unwrap(), Some(0));
+    }
+
+    #[test]
+    fn test_argmax_argmin_non_primitive() {
+        let array = StringArray::from(vec![Some("b"), Some("d"), None, Some("a"), Some("d")]);
+        assert_eq!(argmax(&array, SortOptions::default()).unwrap(), Some(1));
+        assert_eq!(argmin(&array, SortOptions::default()).unwrap(), Some(2));
+
+        let array = Float32Array::from(vec![1.0, f32::NAN, f32::INFINITY, -0.0, 0.0]);
+        assert_eq!(argmax(&array, SortOptions::default()).unwrap(), Some(1));
+        assert_eq!(argmin(&array, SortOptions::default()).unwrap(), Some(3));
+    }
+
+    #[test]
+    fn test_is_sorted() {
+        let asc_nulls_first = SortOptions::default();
+        let asc_nulls_last = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let desc_nulls_first = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let desc_nulls_last = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+
+        let array = Int32Array::from(vec![None, None, Some(-1), Some(2), Some(2), Some(5)]);
+        assert!(is_sorted(&array, asc_nulls_first).unwrap());
+        assert!(!is_sorted(&array, asc_nulls_last).unwrap());
+        assert!(!is_sorted(&array, desc_nulls_first).unwrap());
+        assert!(is_sorted(&array.slice(2, 4), asc_nulls_last).unwrap());
+
+        let array = Int32Array::from(vec![Some(5), Some(2), Some(-1), None]);
+        assert!(is_sorted(&array, desc_nulls_last).unwrap());
+        assert!(!is_sorted(&array, desc_nulls_first).unwrap());
+
+        // A single out of order pair
+        let array = Int32Array::from(vec![1, 2, 4, 3, 5]);
+        assert!(!is_sorted(&array, asc_nulls_first).unwrap());
+        assert!(is_sorted(&array.slice(0, 3), asc_nulls_first).unwrap());
+
+        let array = Float64Array::from(vec![f64::NEG_INFINITY, -0.0, 0.0, 1.0, f64::NAN]);
+        assert!(is_sorted(&array, asc_nulls_first).unwrap());
+        let array = Float64Array::from(vec![0.0, -0.0]);
+        assert!(!is_sorted(&array, asc_nulls_first).unwrap());
+
+        let array = StringArray::from(vec![Some("b"), Some("a"), None]);
+        assert!(is_sorted(&array, desc_nulls_last).unwrap());
+        assert!(!is_sorted(&array, asc_nulls_last).unwrap());
+
+        // Empty arrays, single elements and all nulls are sorted in any order
+        for options in [asc_nulls_first, desc_nulls_last] {
+            assert!(is_sorted(&Int32Array::from(Vec::<i32>::new()), options).unwrap());
+            assert!(is_sorted(&Int32Array::from(vec![Some(7)]), options).unwrap());
+            assert!(is_sorted(&Int32Array::from(vec![None, None]), options).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_find_unsorted_lexicographic() {
+        let column = |values: ArrayRef, descending: bool, nulls_first: bool| SortColumn {
+            values,
+            options: Some(SortOptions {
+                descending,
+                nulls_first,
+            }),
+        };
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![
+            None,
+            Some(1),
+            Some(1),
+            Some(1),
+            Some(2),
+            Some(2),
+        ]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("z"),
+            Some("c"),
+            Some("a"),
+            None,
+            Some("b"),
+            Some("b"),
+        ]));
+
+        // Ties in the first column are ordered by the second
+        let columns = [
+            column(a.clone(), false, true),
+            column(b.clone(), true, false),
+        ];
+        assert!(is_sorted_lexicographic(&columns).unwrap());
+        assert_eq!(find_unsorted_lexicographic(&columns).unwrap(), None);
+
+        let columns = [
+            column(a.clone(), false, true),
+            column(b.clone(), true, true),
+        ];
+        assert_eq!(find_unsorted_lexicographic(&columns).unwrap(), Some(3));
+        let columns = [
+            column(a.clone(), false, true),
+            column(b.clone(), false, false),
+        ];
+        assert_eq!(find_unsorted_lexicographic(&columns).unwrap(), Some(2));
+        let columns = [
+            column(a.clone(), false, false),
+            column(b.clone(), true, false),
+        ];
+        assert_eq!(find_unsorted_lexicographic(&columns).unwrap(), Some(1));
+        assert!(!is_sorted_lexicographic(&columns).unwrap());
+
+        // The result of lexsort is sorted
+        let columns = [
+            column(b.clone(), false, true),
+            column(a.clone(), true, false),
+        ];
+        let sorted = lexsort(&columns, None).unwrap();
+        let sorted = [
+            column(sorted[0].clone(), false, true),
+            column(sorted[1].clone(), true, false),
+        ];
+        assert!(is_sorted_lexicographic(&sorted).unwrap());
+
+        let columns = [column(a.slice(0, 0), false, true)];
+        assert!(is_sorted_lexicographic(&columns).unwrap());
+
+        let err = is_sorted_lexicographic(&[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Sort requires at least one column"
+        );
+        let columns = [
+            column(a.clone(), false, true),
+            column(b.slice(0, 2), false, true),
+        ];
+        let err = is_sorted_lexicographic(&columns).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: lexical sort columns have different row counts"
+        );
+    }
 }
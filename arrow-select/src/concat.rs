@@ -31,14 +31,19 @@
 //! ```
 
 use crate::dictionary::{merge_dictionary_values, should_merge_dictionary_values};
-use arrow_array::builder::{BooleanBuilder, GenericByteBuilder, PrimitiveBuilder};
+use crate::take::take;
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::*;
-use arrow_buffer::{ArrowNativeType, BooleanBufferBuilder, NullBuffer, OffsetBuffer};
+use arrow_buffer::{
+    ArrowNativeType, BooleanBuffer, BooleanBufferBuilder, Buffer, MutableBuffer, NullBuffer,
+    OffsetBuffer, ScalarBuffer,
+};
 use arrow_data::transform::{Capacities, MutableArrayData};
-use arrow_schema::{ArrowError, DataType, FieldRef, SchemaRef};
-use std::{collections::HashSet, sync::Arc};
+use arrow_schema::{
+    ArrowError, DataType, FieldRef, SchemaRef, SortOptions, UnionFields, UnionMode,
+};
+use std::{cmp::Ordering, collections::HashSet, sync::Arc};
 
 fn binary_capacity<T: ByteArrayType>(arrays: &[&dyn Array]) -> Capacities {
     let mut item_capacity = 0;
@@ -93,6 +98,17 @@ fn concat_dictionaries<K: ArrowDictionaryKeyType>(
         .inspect(|d| output_len += d.len())
         .collect();
 
+    // Fast path for dictionaries sharing the same values, e.g. slices of a single
+    // dictionary, where only the keys need to be concatenated
+    let values = dictionaries[0].values();
+    if dictionaries.iter().all(|d| Arc::ptr_eq(d.values(), values)) {
+        let keys: Vec<&dyn Array> = dictionaries.iter().map(|d| d.keys() as _).collect();
+        let keys = concat_primitives::<K>(&keys)?.as_primitive::<K>().clone();
+        // SAFETY: the keys were all valid for `values`
+        let array = unsafe { DictionaryArray::new_unchecked(keys, Arc::clone(values)) };
+        return Ok(Arc::new(array));
+    }
+
     if !should_merge_dictionary_values::<K>(&dictionaries, output_len) {
         return concat_fallback(arrays, Capacities::Array(output_len));
     }
@@ -102,25 +118,13 @@ fn concat_dictionaries<K: ArrowDictionaryKeyType>(
     // Recompute keys
     let mut key_values = Vec::with_capacity(output_len);
 
-    let mut has_nulls = false;
     for (d, mapping) in dictionaries.iter().zip(merged.key_mappings) {
-        has_nulls |= d.null_count() != 0;
         for key in d.keys().values() {
             // Use get to safely handle nulls
             key_values.push(mapping.get(key.as_usize()).copied().unwrap_or_default())
         }
     }
-
-    let nulls = has_nulls.then(|| {
-        let mut nulls = BooleanBufferBuilder::new(output_len);
-        for d in &dictionaries {
-            match d.nulls() {
-                Some(n) => nulls.append_buffer(n.inner()),
-                None => nulls.append_n(d.len(), true),
-            }
-        }
-        NullBuffer::new(nulls.finish())
-    });
+    let nulls = concat_nulls(arrays, output_len);
 
     let keys = PrimitiveArray::<K>::new(key_values.into(), nulls);
     // Sanity check
@@ -130,12 +134,73 @@ fn concat_dictionaries<K: ArrowDictionaryKeyType>(
     Ok(Arc::new(array))
 }
 
+/// Returns the key type to concatenate `arrays` with, if they are all dictionaries
+/// with the same value type but not all with the same key type
+///
+/// This is the widest key type of `arrays`, and is unsigned if any of the widest
+/// key types is, as valid keys are never negative
+fn widest_dictionary_key_type(arrays: &[&dyn Array]) -> Option<DataType> {
+    let DataType::Dictionary(first_key, value_type) = arrays[0].data_type() else {
+        return None;
+    };
+    let mut widest = first_key.as_ref();
+    let mut has_different_keys = false;
+    for array in arrays {
+        let DataType::Dictionary(key, values) = array.data_type() else {
+            return None;
+        };
+        if values != value_type {
+            return None;
+        }
+        has_different_keys |= key != first_key;
+        let (width, widest_width) = (key.primitive_width(), widest.primitive_width());
+        if width > widest_width || (width == widest_width && key.is_unsigned_integer()) {
+            widest = key.as_ref();
+        }
+    }
+    has_different_keys.then(|| widest.clone())
+}
+
+/// Concatenates dictionaries with different key types, converting their keys to `K`
+fn concat_rekeyed_dictionaries<K: ArrowDictionaryKeyType>(
+    arrays: &[&dyn Array],
+) -> Result<ArrayRef, ArrowError> {
+    let dictionaries = arrays
+        .iter()
+        .map(|a| {
+            let dictionary = a.as_any_dictionary();
+            let values = dictionary.values();
+            let keys: ScalarBuffer<K::Native> = match values.is_empty() {
+                // All keys are null
+                true => vec![K::Native::default(); a.len()].into(),
+                // Valid keys are in bounds of the values and fit in the widest key type
+                false => dictionary
+                    .normalized_keys()
+                    .into_iter()
+                    .map(|k| K::Native::from_usize(k).unwrap_or_default())
+                    .collect(),
+            };
+            let keys = PrimitiveArray::<K>::new(keys, dictionary.keys().nulls().cloned());
+            // SAFETY: the valid keys were in bounds of `values`
+            unsafe { DictionaryArray::new_unchecked(keys, Arc::clone(values)) }
+        })
+        .collect::<Vec<_>>();
+    let dictionaries = dictionaries
+        .iter()
+        .map(|d| d as &dyn Array)
+        .collect::<Vec<_>>();
+    concat_dictionaries::<K>(&dictionaries)
+}
+
 fn concat_lists<OffsetSize: OffsetSizeTrait>(
     arrays: &[&dyn Array],
     field: &FieldRef,
 ) -> Result<ArrayRef, ArrowError> {
+    if let Some(array) = join_list_slices::<OffsetSize>(arrays) {
+        return Ok(array);
+    }
+
     let mut output_len = 0;
-    let mut list_has_nulls = false;
     let mut list_has_slices = false;
 
     let lists = arrays
@@ -143,22 +208,12 @@ fn concat_lists<OffsetSize: OffsetSizeTrait>(
         .map(|x| x.as_list::<OffsetSize>())
         .inspect(|l| {
             output_len += l.len();
-            list_has_nulls |= l.null_count() != 0;
             list_has_slices |= l.offsets()[0] > OffsetSize::zero()
                 || l.offsets().last().unwrap().as_usize() < l.values().len();
         })
         .collect::<Vec<_>>();
 
-    let lists_nulls = list_has_nulls.then(|| {
-        let mut nulls = BooleanBufferBuilder::new(output_len);
-        for l in &lists {
-            match l.nulls() {
-                Some(n) => nulls.append_buffer(n.inner()),
-                None => nulls.append_n(l.len(), true),
-            }
-        }
-        NullBuffer::new(nulls.finish())
-    });
+    let lists_nulls = concat_nulls(arrays, output_len);
 
     // If any of the lists have slices, we need to slice the values
     // to ensure that the offsets are correct
@@ -194,40 +249,381 @@ fn concat_lists<OffsetSize: OffsetSizeTrait>(
     Ok(Arc::new(array))
 }
 
+/// Joins `a` and `b` if `b` is the slice of bits immediately following `a`
+fn join_bits(a: &BooleanBuffer, b: &BooleanBuffer) -> Option<BooleanBuffer> {
+    let contiguous = a.inner().ptr_eq(b.inner()) && a.offset() + a.len() == b.offset();
+    contiguous.then(|| BooleanBuffer::new(a.inner().clone(), a.offset(), a.len() + b.len()))
+}
+
+/// Joins the null buffers of two consecutive slices, returning `None` if they
+/// are not contiguous
+fn join_nulls(a: Option<&NullBuffer>, b: Option<&NullBuffer>) -> Option<Option<NullBuffer>> {
+    match (a, b) {
+        (None, None) => Some(None),
+        (Some(a), Some(b)) => {
+            let buffer = join_bits(a.inner(), b.inner())?;
+            // SAFETY: the null count of the joined buffer is the sum of its parts
+            let nulls =
+                unsafe { NullBuffer::new_unchecked(buffer, a.null_count() + b.null_count()) };
+            Some(Some(nulls))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the array `arrays` were sliced from without copying, if they are
+/// consecutive, non-overlapping slices of the same memory in order
+fn join_primitive_slices<T: ArrowPrimitiveType>(arrays: &[&dyn Array]) -> Option<ArrayRef> {
+    let first = arrays[0].as_primitive::<T>();
+    let mut values = first.values().inner().clone();
+    let mut nulls = first.nulls().cloned();
+    for array in &arrays[1..] {
+        let array = array.as_primitive::<T>();
+        values = values.try_join(array.values().inner())?;
+        nulls = join_nulls(nulls.as_ref(), array.nulls())?;
+    }
+    let array = PrimitiveArray::<T>::new(values.into(), nulls);
+    Some(Arc::new(array.with_data_type(first.data_type().clone())))
+}
+
+/// See [`join_primitive_slices`]
+fn join_boolean_slices(arrays: &[&dyn Array]) -> Option<ArrayRef> {
+    let first = arrays[0].as_boolean();
+    let mut values = first.values().clone();
+    let mut nulls = first.nulls().cloned();
+    for array in &arrays[1..] {
+        let array = array.as_boolean();
+        values = join_bits(&values, array.values())?;
+        nulls = join_nulls(nulls.as_ref(), array.nulls())?;
+    }
+    Some(Arc::new(BooleanArray::new(values, nulls)))
+}
+
+/// See [`join_primitive_slices`]
+fn join_bytes_slices<T: ByteArrayType>(arrays: &[&dyn Array]) -> Option<ArrayRef> {
+    let size = std::mem::size_of::<T::Offset>();
+    let first = arrays[0].as_bytes::<T>();
+    let mut offsets = first.offsets().inner().inner().clone();
+    let mut nulls = first.nulls().cloned();
+    for array in &arrays[1..] {
+        let array = array.as_bytes::<T>();
+        if !array.values().ptr_eq(first.values()) {
+            return None;
+        }
+        // Consecutive slices share the offset at their boundary
+        let head = offsets.slice_with_length(0, offsets.len() - size);
+        offsets = head.try_join(array.offsets().inner().inner())?;
+        nulls = join_nulls(nulls.as_ref(), array.nulls())?;
+    }
+    // SAFETY: the offsets are the contiguous memory of several valid offset
+    // buffers into the same values, joined at shared boundary offsets
+    let array = unsafe {
+        let offsets = OffsetBuffer::new_unchecked(offsets.into());
+        GenericByteArray::<T>::new_unchecked(offsets, first.values().clone(), nulls)
+    };
+    Some(Arc::new(array))
+}
+
+/// See [`join_primitive_slices`]
+fn join_fixed_size_binary_slices(arrays: &[&dyn Array]) -> Option<ArrayRef> {
+    let first = arrays[0].as_fixed_size_binary();
+    let size = first.value_length();
+    if size == 0 {
+        return None;
+    }
+    let mut values = first.values().clone();
+    let mut nulls = first.nulls().cloned();
+    for array in &arrays[1..] {
+        let array = array.as_fixed_size_binary();
+        values = values.try_join(array.values())?;
+        nulls = join_nulls(nulls.as_ref(), array.nulls())?;
+    }
+    Some(Arc::new(FixedSizeBinaryArray::new(size, values, nulls)))
+}
+
+/// See [`join_primitive_slices`]
+fn join_list_slices<O: OffsetSizeTrait>(arrays: &[&dyn Array]) -> Option<ArrayRef> {
+    let size = std::mem::size_of::<O>();
+    let first = arrays[0].as_list::<O>();
+    let mut offsets = first.offsets().inner().inner().clone();
+    let mut nulls = first.nulls().cloned();
+    for array in &arrays[1..] {
+        let array = array.as_list::<O>();
+        if !Arc::ptr_eq(array.values(), first.values()) {
+            return None;
+        }
+        // Consecutive slices share the offset at their boundary
+        let head = offsets.slice_with_length(0, offsets.len() - size);
+        offsets = head.try_join(array.offsets().inner().inner())?;
+        nulls = join_nulls(nulls.as_ref(), array.nulls())?;
+    }
+    let field = match first.data_type() {
+        DataType::List(field) | DataType::LargeList(field) => Arc::clone(field),
+        _ => unreachable!(),
+    };
+    // SAFETY: the offsets are the contiguous memory of several valid offset
+    // buffers into the same values, joined at shared boundary offsets
+    let offsets = unsafe { OffsetBuffer::new_unchecked(offsets.into()) };
+    let array = GenericListArray::<O>::try_new(field, offsets, Arc::clone(first.values()), nulls);
+    Some(Arc::new(array.ok()?))
+}
+
+/// See [`join_primitive_slices`]
+fn join_fixed_size_list_slices(arrays: &[&dyn Array]) -> Option<ArrayRef> {
+    let first = arrays[0].as_fixed_size_list();
+    let DataType::FixedSizeList(field, size) = first.data_type() else {
+        unreachable!()
+    };
+    if *size == 0 {
+        return None;
+    }
+    let values: Vec<_> = arrays
+        .iter()
+        .map(|a| a.as_fixed_size_list().values().as_ref())
+        .collect();
+    let values = join_slices(&values)?;
+    let mut nulls = first.nulls().cloned();
+    for array in &arrays[1..] {
+        nulls = join_nulls(nulls.as_ref(), array.nulls())?;
+    }
+    let array = FixedSizeListArray::try_new(Arc::clone(field), *size, values, nulls);
+    Some(Arc::new(array.ok()?))
+}
+
+/// See [`join_primitive_slices`]
+fn join_struct_slices(arrays: &[&dyn Array]) -> Option<ArrayRef> {
+    let first = arrays[0].as_struct();
+    if first.num_columns() == 0 {
+        return None;
+    }
+    let columns = (0..first.num_columns())
+        .map(|i| {
+            let columns: Vec<_> = arrays
+                .iter()
+                .map(|a| a.as_struct().column(i).as_ref())
+                .collect();
+            join_slices(&columns)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let mut nulls = first.nulls().cloned();
+    for array in &arrays[1..] {
+        nulls = join_nulls(nulls.as_ref(), array.nulls())?;
+    }
+    let array = StructArray::try_new(first.fields().clone(), columns, nulls);
+    Some(Arc::new(array.ok()?))
+}
+
+macro_rules! join_primitive_helper {
+    ($t:ty, $arrays:expr) => {
+        join_primitive_slices::<$t>($arrays)
+    };
+}
+
+/// Returns the array `arrays` were sliced from without copying, as per
+/// [`join_primitive_slices`], for the data types that support it
+fn join_slices(arrays: &[&dyn Array]) -> Option<ArrayRef> {
+    downcast_primitive! {
+        arrays[0].data_type() => (join_primitive_helper, arrays),
+        DataType::Boolean => join_boolean_slices(arrays),
+        DataType::Utf8 => join_bytes_slices::<Utf8Type>(arrays),
+        DataType::LargeUtf8 => join_bytes_slices::<LargeUtf8Type>(arrays),
+        DataType::Binary => join_bytes_slices::<BinaryType>(arrays),
+        DataType::LargeBinary => join_bytes_slices::<LargeBinaryType>(arrays),
+        DataType::FixedSizeBinary(_) => join_fixed_size_binary_slices(arrays),
+        DataType::List(_) => join_list_slices::<i32>(arrays),
+        DataType::LargeList(_) => join_list_slices::<i64>(arrays),
+        DataType::FixedSizeList(_, _) => join_fixed_size_list_slices(arrays),
+        DataType::Struct(_) => join_struct_slices(arrays),
+        _ => None,
+    }
+}
+
+/// Concatenates the nulls of `arrays`, whose lengths sum to `len`, returning `None` if
+/// they have no nulls
+///
+/// The null count of the output is the sum of the null counts of `arrays`, and is not
+/// recomputed from the concatenated bits
+fn concat_nulls(arrays: &[&dyn Array], len: usize) -> Option<NullBuffer> {
+    let null_count = arrays.iter().map(|a| a.null_count()).sum();
+    if null_count == 0 {
+        return None;
+    }
+    let mut nulls = BooleanBufferBuilder::new(len);
+    for a in arrays {
+        match a.nulls() {
+            Some(n) => nulls.append_buffer(n.inner()),
+            None => nulls.append_n(a.len(), true),
+        }
+    }
+    // SAFETY: the null count of each array is the number of unset bits of its nulls
+    Some(unsafe { NullBuffer::new_unchecked(nulls.finish(), null_count) })
+}
+
 fn concat_primitives<T: ArrowPrimitiveType>(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
-    let mut builder = PrimitiveBuilder::<T>::with_capacity(arrays.iter().map(|a| a.len()).sum())
-        .with_data_type(arrays[0].data_type().clone());
+    if let Some(array) = join_primitive_slices::<T>(arrays) {
+        return Ok(array);
+    }
 
+    let len = arrays.iter().map(|a| a.len()).sum();
+    let mut values = MutableBuffer::new(len * std::mem::size_of::<T::Native>());
     for array in arrays {
-        builder.append_array(array.as_primitive());
+        values.extend_from_slice(array.as_primitive::<T>().values());
     }
 
-    Ok(Arc::new(builder.finish()))
+    let values = Buffer::from(values).into();
+    let array = PrimitiveArray::<T>::new(values, concat_nulls(arrays, len))
+        .with_data_type(arrays[0].data_type().clone());
+    Ok(Arc::new(array))
 }
 
 fn concat_boolean(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
-    let mut builder = BooleanBuilder::with_capacity(arrays.iter().map(|a| a.len()).sum());
+    if let Some(array) = join_boolean_slices(arrays) {
+        return Ok(array);
+    }
 
+    let len = arrays.iter().map(|a| a.len()).sum();
+    let mut values = BooleanBufferBuilder::new(len);
     for array in arrays {
-        builder.append_array(array.as_boolean());
+        values.append_buffer(array.as_boolean().values());
     }
 
-    Ok(Arc::new(builder.finish()))
+    let array = BooleanArray::new(values.finish(), concat_nulls(arrays, len));
+    Ok(Arc::new(array))
 }
 
 fn concat_bytes<T: ByteArrayType>(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    if let Some(array) = join_bytes_slices::<T>(arrays) {
+        return Ok(array);
+    }
+
     let (item_capacity, bytes_capacity) = match binary_capacity::<T>(arrays) {
         Capacities::Binary(item_capacity, Some(bytes_capacity)) => (item_capacity, bytes_capacity),
         _ => unreachable!(),
     };
+    if T::Offset::from_usize(bytes_capacity).is_none() {
+        return Err(ArrowError::ComputeError(format!(
+            "concat of {bytes_capacity} bytes overflows the offsets of {}",
+            arrays[0].data_type()
+        )));
+    }
 
-    let mut builder = GenericByteBuilder::<T>::with_capacity(item_capacity, bytes_capacity);
-
+    let mut offsets = MutableBuffer::new((item_capacity + 1) * std::mem::size_of::<T::Offset>());
+    offsets.push(T::Offset::usize_as(0));
+    let mut values = MutableBuffer::new(bytes_capacity);
     for array in arrays {
-        builder.append_array(array.as_bytes::<T>());
+        let array = array.as_bytes::<T>();
+        let array_offsets = array.value_offsets();
+        let start = array_offsets[0];
+        let shift = T::Offset::usize_as(values.len());
+        offsets.extend(array_offsets[1..].iter().map(|&o| o - start + shift));
+        let end = array_offsets[array.len()];
+        values.extend_from_slice(&array.value_data()[start.as_usize()..end.as_usize()]);
+    }
+
+    // SAFETY: the offsets are those of valid arrays, shifted to the start of their
+    // values in the concatenated values
+    let offsets = unsafe { OffsetBuffer::new_unchecked(Buffer::from(offsets).into()) };
+    let nulls = concat_nulls(arrays, item_capacity);
+    // SAFETY: the values between the offsets are those of valid arrays
+    let array = unsafe { GenericByteArray::<T>::new_unchecked(offsets, values.into(), nulls) };
+    Ok(Arc::new(array))
+}
+
+fn concat_fixed_size_binary(arrays: &[&dyn Array], size: i32) -> Result<ArrayRef, ArrowError> {
+    if let Some(array) = join_fixed_size_binary_slices(arrays) {
+        return Ok(array);
+    }
+
+    let output_len = arrays.iter().map(|a| a.len()).sum();
+
+    // The values of each array are contiguous, and copied at once
+    let mut values = MutableBuffer::new(output_len * size as usize);
+    for a in arrays {
+        values.extend_from_slice(a.as_fixed_size_binary().value_data());
     }
 
-    Ok(Arc::new(builder.finish()))
+    let nulls = concat_nulls(arrays, output_len);
+    let array = FixedSizeBinaryArray::try_new(size, values.into(), nulls)?;
+    Ok(Arc::new(array))
+}
+
+fn concat_unions(
+    arrays: &[&dyn Array],
+    fields: &UnionFields,
+    mode: UnionMode,
+) -> Result<ArrayRef, ArrowError> {
+    let unions = arrays.iter().map(|x| x.as_union()).collect::<Vec<_>>();
+    let output_len = unions.iter().map(|u| u.len()).sum();
+
+    let mut type_ids = Vec::with_capacity(output_len);
+    for u in &unions {
+        type_ids.extend_from_slice(u.type_ids());
+    }
+
+    let (offsets, children) = match mode {
+        UnionMode::Sparse => {
+            // Sparse children are sliced along with the union
+            let children = fields
+                .iter()
+                .map(|(type_id, _)| {
+                    let children = unions.iter().map(|u| u.child(type_id).as_ref());
+                    concat(&children.collect::<Vec<_>>())
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            (None, children)
+        }
+        UnionMode::Dense => {
+            // Find the range of child values referenced by each union, indexed by type id
+            let mut ranges = vec![[(i32::MAX, -1); 128]; unions.len()];
+            for (u, ranges) in unions.iter().zip(&mut ranges) {
+                let offsets = u.offsets().unwrap();
+                for (type_id, offset) in u.type_ids().iter().zip(offsets.iter()) {
+                    let (start, end) = &mut ranges[*type_id as usize];
+                    *start = (*start).min(*offset);
+                    *end = (*end).max(*offset);
+                }
+            }
+
+            // Slice children to the referenced ranges, recording the amount to
+            // adjust the offsets of each union by within the concatenated child
+            let mut shifts = vec![[0; 128]; unions.len()];
+            let children = fields
+                .iter()
+                .map(|(type_id, _)| {
+                    let mut len = 0;
+                    let mut children = Vec::with_capacity(unions.len());
+                    for ((u, ranges), shifts) in unions.iter().zip(&ranges).zip(&mut shifts) {
+                        let (start, end) = ranges[type_id as usize];
+                        if start <= end {
+                            shifts[type_id as usize] = len - start;
+                            len += end - start + 1;
+                            let child = u.child(type_id);
+                            children.push(child.slice(start as usize, (end - start + 1) as usize));
+                        }
+                    }
+
+                    match children.is_empty() {
+                        true => Ok(unions[0].child(type_id).slice(0, 0)),
+                        false => concat(&children.iter().map(|c| c.as_ref()).collect::<Vec<_>>()),
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let mut offsets = Vec::with_capacity(output_len);
+            for (u, shifts) in unions.iter().zip(&shifts) {
+                let type_ids = u.type_ids().iter();
+                offsets.extend(
+                    type_ids
+                        .zip(u.offsets().unwrap().iter())
+                        .map(|(type_id, offset)| offset + shifts[*type_id as usize]),
+                );
+            }
+            (Some(offsets.into()), children)
+        }
+    };
+
+    let array = UnionArray::try_new(fields.clone(), type_ids.into(), offsets, children)?;
+    Ok(Arc::new(array))
 }
 
 macro_rules! dict_helper {
@@ -236,6 +632,18 @@ macro_rules! dict_helper {
     };
 }
 
+macro_rules! rekey_helper {
+    ($t:ty, $arrays:expr) => {
+        return concat_rekeyed_dictionaries::<$t>($arrays)
+    };
+}
+
+macro_rules! unify_helper {
+    ($t:ty, $arrays:expr, $value_type:expr) => {
+        concat_unify_dictionaries::<$t>($arrays, $value_type)
+    };
+}
+
 macro_rules! primitive_concat {
     ($t:ty, $arrays:expr) => {
         return Ok(Arc::new(concat_primitives::<$t>($arrays)?) as _)
@@ -254,6 +662,9 @@ fn get_capacity(arrays: &[&dyn Array], data_type: &DataType) -> Capacities {
 }
 
 /// Concatenate multiple [Array] of the same type into a single [ArrayRef].
+///
+/// Dictionaries with the same value type but different key types are concatenated
+/// into a dictionary with the widest of their key types.
 pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
     if arrays.is_empty() {
         return Err(ArrowError::ComputeError(
@@ -265,6 +676,23 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
     }
 
     let d = arrays[0].data_type();
+    if let DataType::FixedSizeBinary(size) = d {
+        let other = arrays.iter().find_map(|array| match array.data_type() {
+            DataType::FixedSizeBinary(other) if other != size => Some(other),
+            _ => None,
+        });
+        if let Some(other) = other {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "It is not possible to concatenate FixedSizeBinary arrays of different byte widths ({size}, {other})."
+            )));
+        }
+    }
+    if let Some(key_type) = widest_dictionary_key_type(arrays) {
+        downcast_integer! {
+            &key_type => (rekey_helper, arrays),
+            _ => unreachable!("illegal dictionary key type {key_type}")
+        }
+    }
     if arrays.iter().skip(1).any(|array| array.data_type() != d) {
         // Create error message with up to 10 unique data types in the order they appear
         let error_message = {
@@ -291,6 +719,12 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
 
             error_message.push_str(").");
 
+            let is_string = |d: &DataType| matches!(d, DataType::Utf8 | DataType::LargeUtf8);
+            if arrays.iter().all(|a| is_string(a.data_type())) {
+                error_message
+                    .push_str(" Cast the Utf8 arrays to LargeUtf8, or use concat_promote_large.");
+            }
+
             error_message
         };
 
@@ -312,6 +746,13 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
         DataType::LargeUtf8 => concat_bytes::<LargeUtf8Type>(arrays),
         DataType::Binary => concat_bytes::<BinaryType>(arrays),
         DataType::LargeBinary => concat_bytes::<LargeBinaryType>(arrays),
+        DataType::Union(fields, mode) => concat_unions(arrays, fields, *mode),
+        // Zero-sized values cannot determine the length of the output
+        DataType::FixedSizeBinary(size) if *size > 0 => concat_fixed_size_binary(arrays, *size),
+        DataType::FixedSizeList(_, _) | DataType::Struct(_) => match join_slices(arrays) {
+            Some(array) => Ok(array),
+            None => concat_fallback(arrays, get_capacity(arrays, d)),
+        },
         _ => {
             let capacity = get_capacity(arrays, d);
             concat_fallback(arrays, capacity)
@@ -319,6 +760,100 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
     }
 }
 
+/// Concatenates `arrays` as per [`concat`] into chunks of at most `max_chunk_rows` rows
+///
+/// All chunks but the last have exactly `max_chunk_rows` rows. A chunk that lies within
+/// a single input array is a zero-copy slice of it, otherwise the slices of the inputs
+/// making up the chunk are concatenated. No chunks are returned if `arrays` have no rows.
+///
+/// # Errors
+/// This function errors if `max_chunk_rows` is zero, `arrays` do not all have the same
+/// data type, or [`concat`] returns an error
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, Int32Array};
+/// # use arrow_select::concat::concat_chunked;
+/// let a = Int32Array::from(vec![1, 2, 3]);
+/// let b = Int32Array::from(vec![4, 5]);
+/// let chunks = concat_chunked(&[&a, &b], 2).unwrap();
+/// let lengths: Vec<_> = chunks.iter().map(|c| c.len()).collect();
+/// assert_eq!(lengths, vec![2, 2, 1]);
+/// assert_eq!(chunks[1].as_ref(), &Int32Array::from(vec![3, 4]));
+/// ```
+pub fn concat_chunked(
+    arrays: &[&dyn Array],
+    max_chunk_rows: usize,
+) -> Result<Vec<ArrayRef>, ArrowError> {
+    if max_chunk_rows == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "concat_chunked requires max_chunk_rows to be greater than 0".to_string(),
+        ));
+    }
+    if let Some(first) = arrays.first() {
+        if let Some(other) = arrays.iter().find(|a| a.data_type() != first.data_type()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "It is not possible to concatenate arrays of different data types ({}, {}).",
+                first.data_type(),
+                other.data_type()
+            )));
+        }
+    }
+
+    let total: usize = arrays.iter().map(|a| a.len()).sum();
+    let mut chunks = Vec::with_capacity(total.div_ceil(max_chunk_rows));
+    let mut pieces: Vec<ArrayRef> = Vec::new();
+    let mut rows = 0;
+    for array in arrays {
+        let mut offset = 0;
+        while offset < array.len() {
+            let len = (array.len() - offset).min(max_chunk_rows - rows);
+            pieces.push(array.slice(offset, len));
+            offset += len;
+            rows += len;
+            if rows == max_chunk_rows {
+                chunks.push(concat_pieces(&pieces)?);
+                pieces.clear();
+                rows = 0;
+            }
+        }
+    }
+    if !pieces.is_empty() {
+        chunks.push(concat_pieces(&pieces)?);
+    }
+    Ok(chunks)
+}
+
+/// Concatenates slices making up a chunk of [`concat_chunked`]
+fn concat_pieces(pieces: &[ArrayRef]) -> Result<ArrayRef, ArrowError> {
+    match pieces {
+        [piece] => Ok(Arc::clone(piece)),
+        _ => concat(&pieces.iter().map(|p| p.as_ref()).collect::<Vec<_>>()),
+    }
+}
+
+/// Concatenates `arrays` as per [`concat`], additionally returning the null count
+/// of the result
+///
+/// The null count is the sum of the null counts of `arrays`, which are already
+/// known, and matches [`Array::null_count`] of the result.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, Int32Array};
+/// # use arrow_select::concat::concat_with_stats;
+/// let a = Int32Array::from(vec![Some(1), None]);
+/// let b = Int32Array::from(vec![None, None, Some(4)]);
+/// let (result, null_count) = concat_with_stats(&[&a, &b]).unwrap();
+/// assert_eq!(result.len(), 5);
+/// assert_eq!(null_count, 3);
+/// ```
+pub fn concat_with_stats(arrays: &[&dyn Array]) -> Result<(ArrayRef, usize), ArrowError> {
+    let result = concat(arrays)?;
+    let null_count = arrays.iter().map(|a| a.null_count()).sum();
+    Ok((result, null_count))
+}
+
 /// Concatenates arrays using MutableArrayData
 ///
 /// This will naively concatenate dictionaries
@@ -334,6 +869,238 @@ fn concat_fallback(arrays: &[&dyn Array], capacity: Capacities) -> Result<ArrayR
     Ok(make_array(mutable.freeze()))
 }
 
+/// Concatenates `arrays` as per [`concat`], additionally allowing a mix of
+/// [`DictionaryArray`] and plain arrays of the dictionary's value type.
+///
+/// The output is dictionary-encoded with the type of the first dictionary in `arrays`.
+/// Plain arrays are encoded as dictionaries with one key per row, which are then
+/// concatenated with the other dictionaries as per [`concat`], and so may have their
+/// values copied and merged. If the key type cannot index the values of all
+/// dictionaries together with all rows of the plain arrays, the dictionaries are
+/// instead decoded, and the output is a plain array of the value type.
+///
+/// # Errors
+/// As per [`concat`], e.g. if `arrays` contain data types other than a single
+/// dictionary type and its value type
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, DictionaryArray, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::concat::concat_unify_encoding;
+/// let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+/// let plain = StringArray::from(vec!["c", "a"]);
+///
+/// let result = concat_unify_encoding(&[&dict, &plain]).unwrap();
+/// let result = result.as_dictionary::<Int32Type>();
+/// let values = result.downcast_dict::<StringArray>().unwrap();
+/// let values: Vec<_> = values.into_iter().flatten().collect();
+/// assert_eq!(values, vec!["a", "b", "a", "c", "a"]);
+/// ```
+pub fn concat_unify_encoding(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    let dictionary = arrays
+        .iter()
+        .map(|a| a.data_type())
+        .find(|d| matches!(d, DataType::Dictionary(_, _)));
+
+    match dictionary {
+        Some(DataType::Dictionary(k, v)) if arrays.iter().any(|a| a.data_type() == v.as_ref()) => {
+            downcast_integer! {
+                k.as_ref() => (unify_helper, arrays, v),
+                _ => unreachable!("illegal dictionary key type {k}")
+            }
+        }
+        _ => concat(arrays),
+    }
+}
+
+fn concat_unify_dictionaries<K: ArrowDictionaryKeyType>(
+    arrays: &[&dyn Array],
+    value_type: &DataType,
+) -> Result<ArrayRef, ArrowError> {
+    // The concatenated dictionary may hold the values of all dictionaries and
+    // every row of the plain arrays, which must all be indexable by the keys
+    let num_values: usize = arrays
+        .iter()
+        .map(|a| match a.as_dictionary_opt::<K>() {
+            Some(d) => d.values().len(),
+            None => a.len(),
+        })
+        .sum();
+
+    let arrays: Vec<ArrayRef> = match K::Native::from_usize(num_values.saturating_sub(1)) {
+        Some(_) => arrays
+            .iter()
+            .map(|a| match a.data_type() == value_type {
+                true => encode_plain::<K>(*a),
+                false => a.slice(0, a.len()),
+            })
+            .collect(),
+        None => arrays
+            .iter()
+            .map(|a| match a.as_dictionary_opt::<K>() {
+                Some(d) => take(d.values().as_ref(), d.keys(), None),
+                None => Ok(a.slice(0, a.len())),
+            })
+            .collect::<Result<_, _>>()?,
+    };
+    concat(&arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>())
+}
+
+/// Encodes `array` as a dictionary with one key per row
+///
+/// # Panics
+/// If the key type cannot index all rows of `array`
+fn encode_plain<K: ArrowDictionaryKeyType>(array: &dyn Array) -> ArrayRef {
+    let keys = (0..array.len())
+        .map(|i| K::Native::from_usize(i).unwrap())
+        .collect::<Vec<_>>();
+    let keys = PrimitiveArray::<K>::new(keys.into(), array.nulls().cloned());
+    // Safety: all keys are in bounds of `array`
+    let dictionary = unsafe { DictionaryArray::new_unchecked(keys, array.slice(0, array.len())) };
+    Arc::new(dictionary)
+}
+
+/// Concatenates `arrays` as per [`concat`], additionally promoting [`StringArray`]s
+/// to [`LargeStringArray`]s if any of `arrays` is a [`LargeStringArray`].
+///
+/// Promoted arrays share their values and nulls with the input, and only their
+/// offsets are widened.
+///
+/// # Errors
+/// As per [`concat`], e.g. if `arrays` contain data types other than [`DataType::Utf8`]
+/// and [`DataType::LargeUtf8`]
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, LargeStringArray, StringArray};
+/// # use arrow_select::concat::concat_promote_large;
+/// let a = StringArray::from(vec!["a", "b"]);
+/// let b = LargeStringArray::from(vec!["c"]);
+/// let result = concat_promote_large(&[&a, &b]).unwrap();
+/// assert_eq!(result.as_ref(), &LargeStringArray::from(vec!["a", "b", "c"]));
+/// ```
+pub fn concat_promote_large(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    if !arrays.iter().any(|a| a.data_type() == &DataType::LargeUtf8) {
+        return concat(arrays);
+    }
+    let promoted = arrays
+        .iter()
+        .map(|a| match a.as_string_opt::<i32>() {
+            Some(a) => Arc::new(promote_large(a)),
+            None => a.slice(0, a.len()),
+        })
+        .collect::<Vec<_>>();
+    concat(&promoted.iter().map(|a| a.as_ref()).collect::<Vec<_>>())
+}
+
+fn promote_large(array: &StringArray) -> LargeStringArray {
+    let offsets: ScalarBuffer<i64> = array.offsets().iter().map(|o| *o as i64).collect();
+    // Safety: the offsets are those of a valid StringArray
+    let offsets = unsafe { OffsetBuffer::new_unchecked(offsets) };
+    let values = array.values().clone();
+    // Safety: the offsets and values are those of a valid StringArray
+    unsafe { LargeStringArray::new_unchecked(offsets, values, array.nulls().cloned()) }
+}
+
+/// Concatenates `arrays` as per [`concat`], additionally returning whether the result
+/// is sorted according to `options`, given that each of `arrays` is sorted.
+///
+/// This only compares the last value of each non-empty array with the first value of
+/// the next, and so does not verify that the individual arrays are sorted. Nulls are
+/// ordered according to `nulls_first`, and floats using IEEE 754 totalOrder.
+///
+/// The returned flag is conservative, and is `false` for data types other than
+/// primitive, boolean and byte arrays.
+///
+/// # Example
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_schema::SortOptions;
+/// # use arrow_select::concat::concat_check_sorted;
+/// let a = Int32Array::from(vec![1, 3]);
+/// let b = Int32Array::from(vec![3, 4]);
+/// let c = Int32Array::from(vec![2]);
+///
+/// let (result, sorted) = concat_check_sorted(&[&a, &b], SortOptions::default()).unwrap();
+/// assert_eq!(result.len(), 4);
+/// assert!(sorted);
+///
+/// let (_, sorted) = concat_check_sorted(&[&a, &b, &c], SortOptions::default()).unwrap();
+/// assert!(!sorted);
+/// ```
+pub fn concat_check_sorted(
+    arrays: &[&dyn Array],
+    options: SortOptions,
+) -> Result<(ArrayRef, bool), ArrowError> {
+    let result = concat(arrays)?;
+
+    let non_empty: Vec<_> = arrays.iter().filter(|a| !a.is_empty()).collect();
+    let sorted = is_comparable(result.data_type())
+        && non_empty.windows(2).all(|w| {
+            let ordering = compare_values(*w[0], w[0].len() - 1, *w[1], 0, options);
+            ordering.is_some_and(|o| o.is_le())
+        });
+    Ok((result, sorted))
+}
+
+/// Returns whether [`compare_values`] supports arrays of `data_type`
+fn is_comparable(data_type: &DataType) -> bool {
+    use DataType::*;
+    data_type.is_primitive()
+        || matches!(
+            data_type,
+            Boolean | Utf8 | LargeUtf8 | Utf8View | Binary | LargeBinary | BinaryView
+        )
+}
+
+/// Compares `left[i]` with `right[j]` according to `options`, returning `None`
+/// if the data type is not supported
+fn compare_values(
+    left: &dyn Array,
+    i: usize,
+    right: &dyn Array,
+    j: usize,
+    options: SortOptions,
+) -> Option<Ordering> {
+    let nulls = match options.nulls_first {
+        true => Ordering::Less,
+        false => Ordering::Greater,
+    };
+    match (left.is_valid(i), right.is_valid(j)) {
+        (false, false) => return Some(Ordering::Equal),
+        (false, true) => return Some(nulls),
+        (true, false) => return Some(nulls.reverse()),
+        (true, true) => {}
+    }
+
+    let ordering = downcast_primitive_array! {
+        left => compare_primitive(left, i, right, j),
+        DataType::Boolean => left.as_boolean().value(i).cmp(&right.as_boolean().value(j)),
+        DataType::Utf8 => left.as_string::<i32>().value(i).cmp(right.as_string::<i32>().value(j)),
+        DataType::LargeUtf8 => left.as_string::<i64>().value(i).cmp(right.as_string::<i64>().value(j)),
+        DataType::Utf8View => left.as_string_view().value(i).cmp(right.as_string_view().value(j)),
+        DataType::Binary => left.as_binary::<i32>().value(i).cmp(right.as_binary::<i32>().value(j)),
+        DataType::LargeBinary => left.as_binary::<i64>().value(i).cmp(right.as_binary::<i64>().value(j)),
+        DataType::BinaryView => left.as_binary_view().value(i).cmp(right.as_binary_view().value(j)),
+        _ => return None,
+    };
+    Some(match options.descending {
+        true => ordering.reverse(),
+        false => ordering,
+    })
+}
+
+fn compare_primitive<T: ArrowPrimitiveType>(
+    left: &PrimitiveArray<T>,
+    i: usize,
+    right: &dyn Array,
+    j: usize,
+) -> Ordering {
+    left.value(i).compare(right.as_primitive::<T>().value(j))
+}
+
 /// Concatenates `batches` together into a single [`RecordBatch`].
 ///
 /// The output batch has the specified `schemas`; The schema of the
@@ -374,7 +1141,7 @@ pub fn concat_batches<'a>(
 mod tests {
     use super::*;
     use arrow_array::builder::{GenericListBuilder, StringDictionaryBuilder};
-    use arrow_schema::{Field, Schema};
+    use arrow_schema::{Field, Fields, Schema};
     use std::fmt::Debug;
 
     #[test]
@@ -999,6 +1766,33 @@ mod tests {
         assert!(!new.values().to_data().ptr_eq(&com.values().to_data()));
     }
 
+    #[test]
+    fn test_dictionary_concat_shared_values() {
+        let values = Int64Array::from(vec![10, 20, 30]);
+        let keys = Int16Array::from(vec![Some(0), Some(2), None, Some(1), Some(2), Some(0)]);
+        let array = DictionaryArray::new(keys, Arc::new(values));
+
+        let batches = [array.slice(0, 2), array.slice(2, 3), array.slice(5, 1)];
+        let batches: Vec<&dyn Array> = batches.iter().map(|b| b as _).collect();
+        let combined = concat(&batches).unwrap();
+        let combined = combined.as_dictionary::<Int16Type>();
+
+        assert_eq!(combined, &array);
+        assert!(Arc::ptr_eq(combined.values(), array.values()));
+        // Consecutive slices of the keys are joined without copying
+        assert_eq!(
+            combined.keys().values().as_ptr(),
+            array.keys().values().as_ptr()
+        );
+
+        // Non-consecutive slices still reuse the values
+        let combined = concat(&[&array.slice(3, 3), &array.slice(0, 3)]).unwrap();
+        let combined = combined.as_dictionary::<Int16Type>();
+        assert!(Arc::ptr_eq(combined.values(), array.values()));
+        let expected = Int16Array::from(vec![Some(1), Some(2), Some(0), Some(0), Some(2), None]);
+        assert_eq!(combined.keys(), &expected);
+    }
+
     #[test]
     fn concat_record_batches() {
         let schema = Arc::new(Schema::new(vec![
@@ -1080,6 +1874,51 @@ mod tests {
         assert_eq!(error.to_string(), "Invalid argument error: It is not possible to concatenate arrays of different data types (Int32, Utf8).");
     }
 
+    #[test]
+    fn test_concat_null_count() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), None]);
+        let b = Int32Array::from(vec![None, Some(5), None]);
+        let strings = StringArray::from(vec![Some("a"), None, Some("c"), None]);
+        let booleans = BooleanArray::from(vec![None, Some(true), None, Some(false)]);
+        let binary = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some(b"ab".to_vec()), None, Some(b"cd".to_vec())].into_iter(),
+            2,
+        )
+        .unwrap();
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+        ]);
+        let dict: DictionaryArray<Int32Type> =
+            vec![Some("a"), None, Some("b")].into_iter().collect();
+        let other_dict: DictionaryArray<Int32Type> = vec![None, Some("c")].into_iter().collect();
+
+        let cases: Vec<Vec<ArrayRef>> = vec![
+            vec![
+                Arc::new(a.clone()),
+                Arc::new(b.clone()),
+                Arc::new(a.slice(1, 2)),
+            ],
+            vec![Arc::new(a.slice(0, 1)), Arc::new(a.slice(2, 1))],
+            vec![Arc::new(strings.clone()), Arc::new(strings.slice(1, 2))],
+            vec![Arc::new(booleans.slice(1, 3)), Arc::new(booleans.clone())],
+            vec![Arc::new(binary.clone()), Arc::new(binary.slice(1, 1))],
+            vec![Arc::new(list.clone()), Arc::new(list.slice(1, 2))],
+            vec![Arc::new(dict.clone()), Arc::new(other_dict)],
+            vec![Arc::new(dict.clone()), Arc::new(dict.slice(1, 2))],
+        ];
+        for arrays in cases {
+            let arrays: Vec<&dyn Array> = arrays.iter().map(|a| a.as_ref()).collect();
+            let result = concat(&arrays).unwrap();
+            let expected: usize = arrays.iter().map(|a| a.null_count()).sum();
+            assert_eq!(result.null_count(), expected);
+            let unset = result.nulls().map(|n| n.len() - n.inner().count_set_bits());
+            assert_eq!(unset.unwrap_or_default(), expected);
+            result.to_data().validate_full().unwrap();
+        }
+    }
+
     #[test]
     fn concat_capacity() {
         let a = Int32Array::from_iter_values(0..100);
@@ -1267,4 +2106,621 @@ mod tests {
             "There are duplicates in the value list (the value list here is sorted which is only for the assertion)"
         );
     }
+
+    fn union_fields() -> UnionFields {
+        UnionFields::new(
+            vec![0, 1],
+            vec![
+                Field::new("int", DataType::Int32, true),
+                Field::new("str", DataType::Utf8, true),
+            ],
+        )
+    }
+
+    /// Returns the values of a union with `union_fields` as a vector of (type_id, value)
+    fn union_values(array: &dyn Array) -> Vec<(i8, String)> {
+        let union = array.as_union();
+        (0..union.len())
+            .map(|i| {
+                let type_id = union.type_id(i);
+                let value = union.value(i);
+                let value = match type_id {
+                    0 => format!("{:?}", value.as_primitive::<Int32Type>().iter().next()),
+                    _ => format!("{:?}", value.as_string::<i32>().iter().next()),
+                };
+                (type_id, value)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_concat_sparse_union() {
+        let a = UnionArray::try_new(
+            union_fields(),
+            vec![0_i8, 1, 0, 1].into(),
+            None,
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, None, None])),
+                Arc::new(StringArray::from(vec![None, Some("a"), None, Some("b")])),
+            ],
+        )
+        .unwrap();
+        let b = UnionArray::try_new(
+            union_fields(),
+            vec![1_i8, 1, 0].into(),
+            None,
+            vec![
+                Arc::new(Int32Array::from(vec![None, None, Some(5)])),
+                Arc::new(StringArray::from(vec![Some("c"), None, None])),
+            ],
+        )
+        .unwrap();
+        let a_slice = a.slice(1, 3);
+
+        let arr = concat(&[&a_slice, &b]).unwrap();
+        let union = arr.as_union();
+        assert_eq!(union.data_type(), a.data_type());
+        assert!(union.offsets().is_none());
+        assert_eq!(union.type_ids(), &[1, 0, 1, 1, 1, 0]);
+        assert_eq!(union.child(0).len(), 6);
+        assert_eq!(union.child(1).len(), 6);
+
+        let mut expected = union_values(&a_slice);
+        expected.extend(union_values(&b));
+        assert_eq!(union_values(&arr), expected);
+    }
+
+    #[test]
+    fn test_concat_dense_union() {
+        let a = UnionArray::try_new(
+            union_fields(),
+            vec![0_i8, 1, 0, 1, 0].into(),
+            Some(vec![0, 0, 1, 1, 2].into()),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+                Arc::new(StringArray::from(vec![Some("a"), Some("b")])),
+            ],
+        )
+        .unwrap();
+        let b = UnionArray::try_new(
+            union_fields(),
+            vec![1_i8, 1, 0].into(),
+            Some(vec![0, 1, 0].into()),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(5)])),
+                Arc::new(StringArray::from(vec![None, Some("d")])),
+            ],
+        )
+        .unwrap();
+        let a_slice = a.slice(2, 3);
+
+        let arr = concat(&[&a, &b, &a_slice]).unwrap();
+        let union = arr.as_union();
+        assert_eq!(union.data_type(), a.data_type());
+        assert_eq!(union.type_ids(), &[0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 0]);
+
+        let mut expected = union_values(&a);
+        expected.extend(union_values(&b));
+        expected.extend(union_values(&a_slice));
+        assert_eq!(union_values(&arr), expected);
+
+        // Only the children referenced by the slice are included
+        let arr = concat(&[&a_slice, &a_slice]).unwrap();
+        let union = arr.as_union();
+        assert_eq!(union.offsets().unwrap(), &[0, 0, 1, 2, 1, 3]);
+        assert_eq!(union.child(0).len(), 4);
+        assert_eq!(union.child(1).len(), 2);
+
+        let mut expected = union_values(&a_slice);
+        expected.extend(union_values(&a_slice));
+        assert_eq!(union_values(&arr), expected);
+    }
+
+    #[test]
+    fn test_concat_consecutive_slices() {
+        let array = Int32Array::from(vec![
+            Some(1),
+            None,
+            Some(3),
+            Some(4),
+            None,
+            Some(6),
+            Some(7),
+        ]);
+        let slices = [array.slice(0, 2), array.slice(2, 4), array.slice(6, 1)];
+        let slices = slices.iter().map(|a| a as &dyn Array).collect::<Vec<_>>();
+        let result = concat(&slices).unwrap();
+        assert_eq!(result.as_primitive::<Int32Type>(), &array);
+        // The result shares the buffers of the original array
+        assert!(result.to_data().ptr_eq(&array.to_data()));
+
+        let array = BooleanArray::from(vec![Some(true), None, Some(false), Some(true), None]);
+        let slices = [array.slice(0, 1), array.slice(1, 3), array.slice(4, 1)];
+        let slices = slices.iter().map(|a| a as &dyn Array).collect::<Vec<_>>();
+        let result = concat(&slices).unwrap();
+        assert_eq!(result.as_boolean(), &array);
+        assert!(result.to_data().ptr_eq(&array.to_data()));
+
+        let array = StringArray::from(vec![Some("a"), None, Some("bc"), Some(""), Some("def")]);
+        let slices = [array.slice(0, 2), array.slice(2, 0), array.slice(2, 3)];
+        let slices = slices.iter().map(|a| a as &dyn Array).collect::<Vec<_>>();
+        let result = concat(&slices).unwrap();
+        assert_eq!(result.as_string::<i32>(), &array);
+        assert!(result.to_data().ptr_eq(&array.to_data()));
+
+        // Slices of a sliced array reconstruct that slice
+        let sliced = array.slice(1, 3);
+        let slices = [sliced.slice(0, 1), sliced.slice(1, 2)];
+        let slices = slices.iter().map(|a| a as &dyn Array).collect::<Vec<_>>();
+        let result = concat(&slices).unwrap();
+        assert!(result.to_data().ptr_eq(&sliced.to_data()));
+    }
+
+    #[test]
+    fn test_concat_consecutive_nested_slices() {
+        fn check(array: &dyn Array, ranges: &[(usize, usize)]) -> ArrayRef {
+            let slices: Vec<_> = ranges.iter().map(|&(o, l)| array.slice(o, l)).collect();
+            let slices: Vec<_> = slices.iter().map(|a| a.as_ref()).collect();
+            let result = concat(&slices).unwrap();
+            assert_eq!(result.as_ref(), array);
+            result
+        }
+
+        let array = FixedSizeBinaryArray::from(vec![Some(b"ab".as_slice()), None, Some(b"cd")]);
+        let result = check(&array, &[(0, 1), (1, 2)]);
+        assert!(result.to_data().ptr_eq(&array.to_data()));
+
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4)]),
+        ]);
+        let result = check(&list, &[(0, 2), (2, 0), (2, 2)]);
+        assert!(result.to_data().ptr_eq(&list.to_data()));
+        let sliced = list.slice(1, 3);
+        let result = check(&sliced, &[(0, 1), (1, 2)]);
+        assert!(result.to_data().ptr_eq(&sliced.to_data()));
+
+        let values = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5, 6]));
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let fixed = FixedSizeListArray::new(field, 2, values, Some(nulls));
+        let result = check(&fixed, &[(0, 1), (1, 2)]);
+        assert!(result.to_data().ptr_eq(&fixed.to_data()));
+
+        let strukt = StructArray::new(
+            Fields::from(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", list.data_type().clone(), true),
+            ]),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)])),
+                Arc::new(list.clone()),
+            ],
+            Some(NullBuffer::from(vec![true, true, false, true])),
+        );
+        let result = check(&strukt, &[(0, 3), (3, 1)]);
+        assert!(result.to_data().ptr_eq(&strukt.to_data()));
+
+        // Slices of different arrays are copied
+        let other = StructArray::new(
+            strukt.fields().clone(),
+            vec![
+                strukt.column(0).clone(),
+                Arc::new(ListArray::from(list.to_data())),
+            ],
+            strukt.nulls().cloned(),
+        );
+        let result = concat(&[&strukt.slice(0, 2), &other.slice(2, 2)]).unwrap();
+        assert_eq!(result.as_ref(), &strukt);
+        assert!(!result.to_data().ptr_eq(&strukt.to_data()));
+    }
+
+    #[test]
+    fn test_concat_non_consecutive_slices() {
+        let array = Int32Array::from(vec![1, 2, 3, 4, 5, 6]);
+
+        // Out of order, overlapping and with gaps are copied
+        for ranges in [[(3, 3), (0, 3)], [(0, 4), (2, 4)], [(0, 2), (3, 3)]] {
+            let slices = ranges.map(|(offset, len)| array.slice(offset, len));
+            let slices = slices.iter().map(|a| a as &dyn Array).collect::<Vec<_>>();
+            let result = concat(&slices).unwrap();
+            assert!(!result.to_data().ptr_eq(&array.to_data()));
+
+            let expected = ranges
+                .iter()
+                .flat_map(|&(offset, len)| array.values()[offset..offset + len].to_vec())
+                .collect::<Int32Array>();
+            assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+        }
+
+        let array = StringArray::from(vec!["a", "b", "c"]);
+        let other = StringArray::from(vec!["a", "b", "c"]);
+        let result = concat(&[&array.slice(0, 1), &other.slice(1, 2)]).unwrap();
+        assert_eq!(result.as_string::<i32>(), &array);
+        assert!(!result.to_data().ptr_eq(&array.to_data()));
+    }
+
+    #[test]
+    fn test_concat_fixed_size_binary() {
+        let a = FixedSizeBinaryArray::from(vec![Some(b"ab".as_slice()), None, Some(b"cd")]);
+        let b = FixedSizeBinaryArray::from(vec![b"ef".as_slice(), b"gh", b"ij"]);
+
+        let result = concat(&[&a, &b.slice(1, 2), &a.slice(0, 1)]).unwrap();
+        let expected = FixedSizeBinaryArray::from(vec![
+            Some(b"ab".as_slice()),
+            None,
+            Some(b"cd"),
+            Some(b"gh"),
+            Some(b"ij"),
+            Some(b"ab"),
+        ]);
+        assert_eq!(result.as_fixed_size_binary(), &expected);
+
+        // No null buffer is allocated without nulls
+        let result = concat(&[&b, &b]).unwrap();
+        assert!(result.nulls().is_none());
+        assert_eq!(result.as_fixed_size_binary().value_data(), b"efghijefghij");
+
+        // Zero-sized values
+        let empty = FixedSizeBinaryArray::new_null(0, 1);
+        let result = concat(&[&empty, &FixedSizeBinaryArray::new_null(0, 2)]).unwrap();
+        assert_eq!(result.data_type(), &DataType::FixedSizeBinary(0));
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn test_concat_fixed_size_binary_different_widths() {
+        let a = FixedSizeBinaryArray::from(vec![b"ab".as_slice()]);
+        let b = FixedSizeBinaryArray::from(vec![b"abc".as_slice()]);
+        let err = concat(&[&a, &a, &b]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: It is not possible to concatenate FixedSizeBinary arrays of different byte widths (2, 3)."
+        );
+    }
+
+    #[test]
+    fn test_concat_dictionaries_different_key_types() {
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let int8 = DictionaryArray::new(
+            Int8Array::from(vec![Some(2), None, Some(0)]),
+            Arc::clone(&values),
+        );
+        let int32 = DictionaryArray::new(Int32Array::from(vec![1, 1]), Arc::clone(&values));
+
+        let result = concat(&[&int8, &int32, &int8]).unwrap();
+        assert_eq!(
+            result.data_type(),
+            &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        );
+        let result = result.as_dictionary::<Int32Type>();
+        // The shared values are preserved
+        assert!(Arc::ptr_eq(result.values(), &values));
+        let result: Vec<_> = result
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        let expected = vec![Some("c"), None, Some("a"), Some("b"), Some("b")];
+        assert_eq!(result, [expected.clone(), expected[..3].to_vec()].concat());
+
+        // Different values are merged
+        let other = DictionaryArray::new(
+            UInt16Array::from(vec![0, 1]),
+            Arc::new(StringArray::from(vec!["d", "a"])),
+        );
+        let result = concat(&[&int8, &other]).unwrap();
+        let result = result.as_dictionary::<UInt16Type>();
+        let result: Vec<_> = result
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(
+            result,
+            vec![Some("c"), None, Some("a"), Some("d"), Some("a")]
+        );
+
+        // Unsigned keys are used for key types of the same width
+        let uint8 = DictionaryArray::new(UInt8Array::from(vec![1]), Arc::clone(&values));
+        let result = concat(&[&int8, &uint8]).unwrap();
+        assert_eq!(
+            result.data_type(),
+            &DataType::Dictionary(Box::new(DataType::UInt8), Box::new(DataType::Utf8))
+        );
+
+        // Null dictionaries without values
+        let nulls =
+            DictionaryArray::new(Int64Array::new_null(2), Arc::new(StringArray::new_null(0)));
+        let result = concat(&[&int8, &nulls]).unwrap();
+        let result = result.as_dictionary::<Int64Type>();
+        assert_eq!(result.len(), 5);
+        assert_eq!(result.null_count(), 3);
+
+        // Different value types are not concatenated
+        let other = DictionaryArray::new(
+            Int32Array::from(vec![0]),
+            Arc::new(Int32Array::from(vec![1])),
+        );
+        let err = concat(&[&int8, &other]).unwrap_err();
+        assert!(err.to_string().contains("different data types"), "{err}");
+    }
+
+    #[test]
+    fn test_concat_utf8_large_utf8() {
+        let a = StringArray::from(vec![Some("a"), None, Some("bc")]);
+        let b = LargeStringArray::from(vec![Some("def"), Some("")]);
+
+        let err = concat(&[&a, &b]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: It is not possible to concatenate arrays of different data types (Utf8, LargeUtf8). Cast the Utf8 arrays to LargeUtf8, or use concat_promote_large."
+        );
+
+        let sliced = a.slice(1, 2);
+        let result = concat_promote_large(&[&b, &sliced, &a]).unwrap();
+        let expected = LargeStringArray::from(vec![
+            Some("def"),
+            Some(""),
+            None,
+            Some("bc"),
+            Some("a"),
+            None,
+            Some("bc"),
+        ]);
+        assert_eq!(result.as_string::<i64>(), &expected);
+
+        // Without a large array the output is not promoted
+        let result = concat_promote_large(&[&a, &sliced]).unwrap();
+        assert_eq!(result.data_type(), &DataType::Utf8);
+
+        // Other data types are not promoted
+        let c = Int32Array::from(vec![1]);
+        let err = concat_promote_large(&[&a, &b, &c]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: It is not possible to concatenate arrays of different data types (LargeUtf8, Int32)."
+        );
+    }
+
+    #[test]
+    fn test_concat_chunked() {
+        let a = Int32Array::from_iter_values(0..300);
+        let b = Int32Array::from_iter((300..500).map(|v| (v % 3 != 0).then_some(v)));
+        let c = Int32Array::from_iter_values(500..1000);
+        let expected = concat(&[&a, &b, &c]).unwrap();
+
+        let chunks = concat_chunked(&[&a, &b, &c], 256).unwrap();
+        let lengths: Vec<_> = chunks.iter().map(|c| c.len()).collect();
+        assert_eq!(lengths, vec![256, 256, 256, 232]);
+        let refs: Vec<_> = chunks.iter().map(|c| c.as_ref()).collect();
+        assert_eq!(&concat(&refs).unwrap(), &expected);
+
+        // Chunks within a single input are zero-copy slices
+        let first = chunks[0].as_primitive::<Int32Type>();
+        assert_eq!(first.values().as_ptr(), a.values().as_ptr());
+        let last = chunks[3].as_primitive::<Int32Type>();
+        assert_eq!(last.values().as_ptr(), c.values()[268..].as_ptr());
+
+        let chunks = concat_chunked(&[&a, &b, &c], 1000).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(&chunks[0], &expected);
+
+        let empty = Int32Array::from(Vec::<i32>::new());
+        assert!(concat_chunked(&[&empty], 10).unwrap().is_empty());
+        assert!(concat_chunked(&[], 10).unwrap().is_empty());
+
+        let err = concat_chunked(&[&a], 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: concat_chunked requires max_chunk_rows to be greater than 0"
+        );
+        let err = concat_chunked(&[&a, &StringArray::from(vec!["a"])], 10).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: It is not possible to concatenate arrays of different data types (Int32, Utf8)."
+        );
+    }
+
+    #[test]
+    fn test_concat_with_stats() {
+        let a = StringArray::from(vec![Some("a"), None, Some("c")]);
+        let b = StringArray::new_null(2);
+        let c = StringArray::from(vec!["d"]);
+
+        let (result, null_count) = concat_with_stats(&[&a, &b, &c]).unwrap();
+        assert_eq!(result.len(), 6);
+        assert_eq!(null_count, 3);
+        assert_eq!(null_count, result.null_count());
+
+        // Sliced inputs
+        let sliced = a.slice(0, 1);
+        let (result, null_count) = concat_with_stats(&[&sliced, &c]).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(null_count, 0);
+
+        let d: DictionaryArray<Int32Type> = vec![None, Some("x")].into_iter().collect();
+        let e: DictionaryArray<Int32Type> = vec![Some("y"), None].into_iter().collect();
+        let (result, null_count) = concat_with_stats(&[&d, &e]).unwrap();
+        assert_eq!(null_count, 2);
+        assert_eq!(null_count, result.null_count());
+
+        let err = concat_with_stats(&[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: concat requires input of at least one array"
+        );
+    }
+
+    #[test]
+    fn test_concat_unify_encoding() {
+        let dict: DictionaryArray<Int32Type> =
+            vec![Some("a"), None, Some("b")].into_iter().collect();
+        let plain = StringArray::from(vec![Some("c"), None, Some("a")]);
+
+        let collect = |a: &ArrayRef| {
+            let a = a.as_dictionary::<Int32Type>();
+            let a = a.downcast_dict::<StringArray>().unwrap();
+            a.into_iter()
+                .map(|v| v.map(str::to_string))
+                .collect::<Vec<_>>()
+        };
+        let strings =
+            |v: &[Option<&str>]| v.iter().map(|v| v.map(str::to_string)).collect::<Vec<_>>();
+
+        // Dictionary then plain
+        let result = concat_unify_encoding(&[&dict, &plain]).unwrap();
+        assert_eq!(result.data_type(), dict.data_type());
+        assert_eq!(
+            collect(&result),
+            strings(&[Some("a"), None, Some("b"), Some("c"), None, Some("a")])
+        );
+        assert_eq!(result.null_count(), 2);
+
+        // Plain then dictionary, including a sliced plain array
+        let sliced = plain.slice(1, 2);
+        let result = concat_unify_encoding(&[&sliced, &dict, &plain]).unwrap();
+        assert_eq!(result.data_type(), dict.data_type());
+        let expected = [
+            None,
+            Some("a"),
+            Some("a"),
+            None,
+            Some("b"),
+            Some("c"),
+            None,
+            Some("a"),
+        ];
+        assert_eq!(collect(&result), strings(&expected));
+
+        // Arrays of a single type are concatenated as per concat
+        let result = concat_unify_encoding(&[&plain, &plain]).unwrap();
+        assert_eq!(result.data_type(), &DataType::Utf8);
+        let result = concat_unify_encoding(&[&dict, &dict]).unwrap();
+        assert_eq!(result.len(), 6);
+
+        let err = concat_unify_encoding(&[&dict, &plain, &Int32Array::from(vec![1])]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: It is not possible to concatenate arrays of different data types (Dictionary(Int32, Utf8), Int32)."
+        );
+    }
+
+    #[test]
+    fn test_concat_unify_encoding_decodes_on_key_overflow() {
+        let dict: DictionaryArray<Int8Type> = vec![Some("x"), None].into_iter().collect();
+        let plain = StringArray::from_iter_values((0..200).map(|i| i.to_string()));
+
+        // Int8 keys cannot index the 200 rows of the plain array
+        let result = concat_unify_encoding(&[&plain, &dict]).unwrap();
+        assert_eq!(result.data_type(), &DataType::Utf8);
+        let result = result.as_string::<i32>();
+        assert_eq!(result.len(), 202);
+        assert_eq!(result.value(199), "199");
+        assert_eq!(result.value(200), "x");
+        assert!(result.is_null(201));
+    }
+
+    #[test]
+    fn test_concat_unify_encoding_decodes_on_total_key_overflow() {
+        let dict: DictionaryArray<Int8Type> = vec![Some("x"), None].into_iter().collect();
+        let a = StringArray::from_iter_values((0..100).map(|i| i.to_string()));
+        let b = StringArray::from_iter_values((100..200).map(|i| i.to_string()));
+
+        // Each plain array fits Int8 keys, but together they do not
+        let result = concat_unify_encoding(&[&dict, &a, &b]).unwrap();
+        assert_eq!(result.data_type(), &DataType::Utf8);
+        let result = result.as_string::<i32>();
+        assert_eq!(result.len(), 202);
+        assert_eq!(result.value(0), "x");
+        assert!(result.is_null(1));
+        assert_eq!(result.value(201), "199");
+
+        let keys = Int8Array::from(vec![Some(0), None]);
+        let dict = DictionaryArray::new(keys, Arc::new(Int32Array::from(vec![1])));
+        let a = Int32Array::from_iter_values(0..100);
+        let b = Int32Array::from_iter_values(100..200);
+        let result = concat_unify_encoding(&[&dict, &a, &b]).unwrap();
+        assert_eq!(result.data_type(), &DataType::Int32);
+        let result = result.as_primitive::<Int32Type>();
+        assert_eq!(result.len(), 202);
+        assert_eq!(result.value(0), 1);
+        assert!(result.is_null(1));
+        assert_eq!(result.value(201), 199);
+    }
+
+    #[test]
+    fn test_concat_check_sorted() {
+        let options = SortOptions {
+            descending: false,
+            nulls_first: true,
+        };
+        let a = Int32Array::from(vec![None, Some(1), Some(3)]);
+        let b = Int32Array::from(Vec::<i32>::new());
+        let c = Int32Array::from(vec![3, 7]);
+        let d = Int32Array::from(vec![5, 6]);
+
+        // Equal boundaries and empty arrays preserve sortedness
+        let (result, sorted) = concat_check_sorted(&[&a, &b, &c], options).unwrap();
+        assert_eq!(
+            result.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![None, Some(1), Some(3), Some(3), Some(7)])
+        );
+        assert!(sorted);
+
+        // 7 > 5 breaks the order
+        let (result, sorted) = concat_check_sorted(&[&a, &c, &d], options).unwrap();
+        assert_eq!(result.len(), 7);
+        assert!(!sorted);
+
+        // A null after values breaks the order only if nulls are first
+        let e = Int32Array::from(vec![None, None]);
+        let (_, sorted) = concat_check_sorted(&[&c, &e], options).unwrap();
+        assert!(!sorted);
+        let nulls_last = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let (_, sorted) = concat_check_sorted(&[&c, &e, &e], nulls_last).unwrap();
+        assert!(sorted);
+
+        let descending = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let x = Int32Array::from(vec![7, 3]);
+        let y = Int32Array::from(vec![Some(3), Some(2), None]);
+        let (_, sorted) = concat_check_sorted(&[&x, &y, &e], descending).unwrap();
+        assert!(sorted);
+        let (_, sorted) = concat_check_sorted(&[&y, &x], descending).unwrap();
+        assert!(!sorted);
+    }
+
+    #[test]
+    fn test_concat_check_sorted_strings() {
+        let options = SortOptions::default();
+        let a = StringArray::from(vec!["apple", "banana"]);
+        let b = StringArray::from(vec!["banana", "cherry"]);
+        let (_, sorted) = concat_check_sorted(&[&a, &b], options).unwrap();
+        assert!(sorted);
+        let (_, sorted) = concat_check_sorted(&[&b, &a], options).unwrap();
+        assert!(!sorted);
+
+        // Floats use total order, such that NaN is greater than all other values
+        let a = Float64Array::from(vec![1.0, f64::INFINITY]);
+        let b = Float64Array::from(vec![f64::NAN]);
+        let (_, sorted) = concat_check_sorted(&[&a, &b], options).unwrap();
+        assert!(sorted);
+        let (_, sorted) = concat_check_sorted(&[&b, &a], options).unwrap();
+        assert!(!sorted);
+
+        // Unsupported types are never reported as sorted
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+        let (_, sorted) = concat_check_sorted(&[&list, &list], options).unwrap();
+        assert!(!sorted);
+        let (_, sorted) = concat_check_sorted(&[&list], options).unwrap();
+        assert!(!sorted);
+    }
 }
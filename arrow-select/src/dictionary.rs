@@ -22,10 +22,40 @@ use arrow_array::cast::AsArray;
 use arrow_array::types::{
     ArrowDictionaryKeyType, BinaryType, ByteArrayType, LargeBinaryType, LargeUtf8Type, Utf8Type,
 };
-use arrow_array::{Array, ArrayRef, DictionaryArray, GenericByteArray};
+use arrow_array::{Array, ArrayRef, BooleanArray, DictionaryArray, GenericByteArray};
 use arrow_buffer::{ArrowNativeType, BooleanBuffer, ScalarBuffer};
 use arrow_schema::{ArrowError, DataType};
 
+/// Removes the values of `dict` that are not referenced by its valid keys, keeping
+/// the relative order of the retained values
+pub(crate) fn compact_dictionary<K: ArrowDictionaryKeyType>(
+    dict: &DictionaryArray<K>,
+) -> Result<DictionaryArray<K>, ArrowError> {
+    let keys = dict.keys();
+    let mut used = vec![false; dict.values().len()];
+    keys.iter()
+        .flatten()
+        .for_each(|k| used[k.as_usize()] = true);
+
+    let mut retained = 0;
+    let mapping: Vec<K::Native> = used
+        .iter()
+        .map(|used| match used {
+            true => {
+                retained += 1;
+                K::Native::usize_as(retained - 1)
+            }
+            false => K::Native::default(),
+        })
+        .collect();
+
+    let values = crate::filter::filter(dict.values(), &BooleanArray::from(used))?;
+    // Keys of null slots may map to any value
+    let keys = keys.unary(|k| mapping.get(k.as_usize()).copied().unwrap_or_default());
+    // Safety: the valid keys map to the positions of the retained values
+    Ok(unsafe { DictionaryArray::new_unchecked(keys, values) })
+}
+
 /// A best effort interner that maintains a fixed number of buckets
 /// and interns keys based on their hash value
 ///
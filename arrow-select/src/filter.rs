@@ -17,9 +17,10 @@
 
 //! Defines filter kernels
 
-use std::ops::AddAssign;
+use std::ops::{AddAssign, Range};
 use std::sync::Arc;
 
+use crate::dictionary::compact_dictionary;
 use arrow_array::builder::BooleanBufferBuilder;
 use arrow_array::cast::AsArray;
 use arrow_array::types::{
@@ -156,6 +157,28 @@ pub fn prep_null_mask_filter(filter: &BooleanArray) -> BooleanArray {
     BooleanArray::new(mask, None)
 }
 
+/// Returns the contiguous ranges of `predicate` that are `true`, in ascending order
+///
+/// Null values of `predicate` are treated as `false`. This allows downstream code to
+/// copy selected values in bulk, rather than index by index.
+///
+/// # Example
+/// ```
+/// # use arrow_array::BooleanArray;
+/// # use arrow_select::filter::filter_runs;
+/// let predicate = BooleanArray::from(vec![
+///     Some(true), Some(true), Some(false), None, Some(true), Some(true), Some(true),
+/// ]);
+/// assert_eq!(filter_runs(&predicate), vec![0..2, 4..7]);
+/// ```
+pub fn filter_runs(predicate: &BooleanArray) -> Vec<Range<usize>> {
+    let values = match predicate.nulls() {
+        Some(nulls) if nulls.null_count() > 0 => predicate.values() & nulls.inner(),
+        _ => predicate.values().clone(),
+    };
+    values.set_slices().map(|(start, end)| start..end).collect()
+}
+
 /// Returns a filtered `values` [Array] where the corresponding elements of
 /// `predicate` are `true`.
 ///
@@ -185,6 +208,26 @@ pub fn filter(values: &dyn Array, predicate: &BooleanArray) -> Result<ArrayRef,
     filter_array(values, &predicate)
 }
 
+/// Returns a filtered [`ArrayData`] where the corresponding elements of `predicate`
+/// are `true`, as per [`filter`]
+///
+/// This allows composing selections on [`ArrayData`] without converting to and from
+/// an [`ArrayRef`] at each step. The buffers of `data` are shared rather than copied
+/// when wrapping it in the typed array to filter.
+///
+/// # Example
+/// ```rust
+/// # use arrow_array::{Array, BooleanArray, Int32Array};
+/// # use arrow_select::filter::filter_data;
+/// let data = Int32Array::from(vec![5, 6, 7, 8, 9]).into_data();
+/// let predicate = BooleanArray::from(vec![true, false, false, true, false]);
+/// let filtered = filter_data(&data, &predicate).unwrap();
+/// assert_eq!(Int32Array::from(filtered), Int32Array::from(vec![5, 8]));
+/// ```
+pub fn filter_data(data: &ArrayData, predicate: &BooleanArray) -> Result<ArrayData, ArrowError> {
+    filter(make_array(data.clone()).as_ref(), predicate).map(|a| a.into_data())
+}
+
 fn multiple_arrays(data_type: &DataType) -> bool {
     match data_type {
         DataType::Struct(fields) => {
@@ -220,12 +263,61 @@ pub fn filter_record_batch(
     RecordBatch::try_new_with_options(record_batch.schema(), filtered_arrays, &options)
 }
 
+/// Filters an array imported via the [C Data Interface] as per [`filter`], exporting
+/// the result into `out` and `out_schema`
+///
+/// The imported array is fully validated before filtering, returning an error if its
+/// layout is not valid for its data type. Any existing contents of `out` and
+/// `out_schema` are released and overwritten. They are left unchanged if an error is
+/// returned.
+///
+/// [C Data Interface]: https://arrow.apache.org/docs/format/CDataInterface.html
+///
+/// # Safety
+///
+/// As for [`from_ffi`](arrow_array::ffi::from_ffi), `array` and `schema` must agree
+/// with the C Data Interface.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{make_array, Array, BooleanArray, StringArray};
+/// # use arrow_array::ffi::{from_ffi, to_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+/// # use arrow_select::filter::filter_ffi;
+/// let values = StringArray::from(vec!["a", "b", "c"]);
+/// let (array, schema) = to_ffi(&values.to_data()).unwrap();
+///
+/// let predicate = BooleanArray::from(vec![true, false, true]);
+/// let mut out = FFI_ArrowArray::empty();
+/// let mut out_schema = FFI_ArrowSchema::empty();
+/// unsafe { filter_ffi(array, &schema, &predicate, &mut out, &mut out_schema) }.unwrap();
+///
+/// let data = unsafe { from_ffi(out, &out_schema) }.unwrap();
+/// assert_eq!(make_array(data).as_ref(), &StringArray::from(vec!["a", "c"]));
+/// ```
+#[cfg(feature = "ffi")]
+pub unsafe fn filter_ffi(
+    array: arrow_data::ffi::FFI_ArrowArray,
+    schema: &arrow_schema::ffi::FFI_ArrowSchema,
+    predicate: &BooleanArray,
+    out: &mut arrow_data::ffi::FFI_ArrowArray,
+    out_schema: &mut arrow_schema::ffi::FFI_ArrowSchema,
+) -> Result<(), ArrowError> {
+    let data = arrow_array::ffi::from_ffi(array, schema)?;
+    data.validate_full()?;
+
+    let filtered = filter(make_array(data).as_ref(), predicate)?.to_data();
+    *out_schema = arrow_schema::ffi::FFI_ArrowSchema::try_from(filtered.data_type())?;
+    *out = arrow_data::ffi::FFI_ArrowArray::new(&filtered);
+    Ok(())
+}
+
 /// A builder to construct [`FilterPredicate`]
 #[derive(Debug)]
 pub struct FilterBuilder {
     filter: BooleanArray,
     count: usize,
     strategy: IterationStrategy,
+    compact_dictionaries: bool,
 }
 
 impl FilterBuilder {
@@ -243,9 +335,21 @@ impl FilterBuilder {
             filter,
             count,
             strategy,
+            compact_dictionaries: false,
         }
     }
 
+    /// Whether to remove the values of filtered dictionaries that are no longer
+    /// referenced by the selected keys, defaults to `false`
+    ///
+    /// By default the filtered dictionaries keep all values of the input, which avoids
+    /// copying the values, but retains values that may no longer be used. Dictionaries
+    /// are returned unchanged if all rows are selected.
+    pub fn with_compact_dictionaries(mut self, compact_dictionaries: bool) -> Self {
+        self.compact_dictionaries = compact_dictionaries;
+        self
+    }
+
     /// Compute an optimised representation of the provided `filter` mask that can be
     /// applied to an array more quickly.
     ///
@@ -272,6 +376,7 @@ impl FilterBuilder {
             filter: self.filter,
             count: self.count,
             strategy: self.strategy,
+            compact_dictionaries: self.compact_dictionaries,
         }
     }
 }
@@ -323,6 +428,7 @@ pub struct FilterPredicate {
     filter: BooleanArray,
     count: usize,
     strategy: IterationStrategy,
+    compact_dictionaries: bool,
 }
 
 impl FilterPredicate {
@@ -335,9 +441,93 @@ impl FilterPredicate {
     pub fn count(&self) -> usize {
         self.count
     }
+
+    /// Returns a copy of this [`FilterPredicate`] with the selection precomputed as
+    /// per [`FilterBuilder::optimize`], or `None` if it is already precomputed
+    fn precompute(&self) -> Option<Self> {
+        let strategy = match self.strategy {
+            IterationStrategy::SlicesIterator => {
+                IterationStrategy::Slices(SlicesIterator::new(&self.filter).collect())
+            }
+            IterationStrategy::IndexIterator => {
+                IterationStrategy::Indices(IndexIterator::new(&self.filter, self.count).collect())
+            }
+            _ => return None,
+        };
+        Some(Self {
+            filter: self.filter.clone(),
+            count: self.count,
+            strategy,
+            compact_dictionaries: self.compact_dictionaries,
+        })
+    }
 }
 
-fn filter_array(values: &dyn Array, predicate: &FilterPredicate) -> Result<ArrayRef, ArrowError> {
+/// Reusable state for filtering many arrays in a loop, see [`FilterContext::filter_reuse`]
+///
+/// ```
+/// # use arrow_array::{BooleanArray, Int32Array};
+/// # use arrow_select::filter::{FilterBuilder, FilterContext};
+/// let predicate = FilterBuilder::new(&BooleanArray::from(vec![true, false, true])).build();
+/// let mut ctx = FilterContext::new();
+/// for i in 0..3 {
+///     let values = Int32Array::from(vec![i, i + 1, i + 2]);
+///     let filtered = ctx.filter_reuse(&values, &predicate).unwrap();
+///     assert_eq!(filtered.as_ref(), &Int32Array::from(vec![i, i + 2]));
+///     // `filtered` is dropped, allowing its allocation to be reused
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct FilterContext {
+    /// The values buffer of the last filtered array
+    values: Option<Buffer>,
+}
+
+impl FilterContext {
+    /// Create a new [`FilterContext`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects rows from `values` based on `predicate`, like [`FilterPredicate::filter`]
+    ///
+    /// For primitive arrays, the values buffer of the array returned by the previous
+    /// call is reused if that array has been dropped, avoiding an allocation. Other
+    /// types are filtered as by [`FilterPredicate::filter`]
+    pub fn filter_reuse(
+        &mut self,
+        values: &dyn Array,
+        predicate: &FilterPredicate,
+    ) -> Result<ArrayRef, ArrowError> {
+        if !values.data_type().is_primitive()
+            || matches!(
+                predicate.strategy,
+                IterationStrategy::None | IterationStrategy::All
+            )
+        {
+            return filter_array(values, predicate);
+        }
+        check_filter_len(values, predicate)?;
+
+        // Only reuse the buffer if nothing else references it
+        let buffer = self
+            .values
+            .take()
+            .and_then(|b| b.into_mutable().ok())
+            .unwrap_or_else(|| MutableBuffer::new(0));
+
+        downcast_primitive_array! {
+            values => {
+                let array = filter_primitive_with(values, predicate, buffer);
+                self.values = Some(array.values().inner().clone());
+                Ok(Arc::new(array))
+            }
+            _ => filter_array(values, predicate),
+        }
+    }
+}
+
+fn check_filter_len(values: &dyn Array, predicate: &FilterPredicate) -> Result<(), ArrowError> {
     if predicate.filter.len() > values.len() {
         return Err(ArrowError::InvalidArgumentError(format!(
             "Filter predicate of length {} is larger than target array of length {}",
@@ -345,6 +535,11 @@ fn filter_array(values: &dyn Array, predicate: &FilterPredicate) -> Result<Array
             values.len()
         )));
     }
+    Ok(())
+}
+
+fn filter_array(values: &dyn Array, predicate: &FilterPredicate) -> Result<ArrayRef, ArrowError> {
+    check_filter_len(values, predicate)?;
 
     match predicate.strategy {
         IterationStrategy::None => Ok(new_empty_array(values.data_type())),
@@ -384,7 +579,7 @@ fn filter_array(values: &dyn Array, predicate: &FilterPredicate) -> Result<Array
                 }
             }
             DataType::Dictionary(_, _) => downcast_dictionary_array! {
-                values => Ok(Arc::new(filter_dict(values, predicate))),
+                values => Ok(Arc::new(filter_dict(values, predicate)?)),
                 t => unimplemented!("Filter not supported for dictionary type {:?}", t)
             }
             DataType::Struct(_) => {
@@ -393,6 +588,9 @@ fn filter_array(values: &dyn Array, predicate: &FilterPredicate) -> Result<Array
             DataType::Union(_, UnionMode::Sparse) => {
                 Ok(Arc::new(filter_sparse_union(values.as_union(), predicate)?))
             }
+            DataType::Union(_, UnionMode::Dense) => {
+                Ok(Arc::new(filter_dense_union(values.as_union(), predicate)?))
+            }
             _ => {
                 let data = values.to_data();
                 // fallback to using MutableArrayData
@@ -550,49 +748,58 @@ fn filter_boolean(array: &BooleanArray, predicate: &FilterPredicate) -> BooleanA
     BooleanArray::from(data)
 }
 
+/// Filters `values` into `buffer`, which is cleared first to allow reusing its allocation
 #[inline(never)]
-fn filter_native<T: ArrowNativeType>(values: &[T], predicate: &FilterPredicate) -> Buffer {
+fn filter_native<T: ArrowNativeType>(
+    values: &[T],
+    predicate: &FilterPredicate,
+    mut buffer: MutableBuffer,
+) -> Buffer {
     assert!(values.len() >= predicate.filter.len());
 
-    let buffer = match &predicate.strategy {
+    buffer.clear();
+    buffer.reserve(predicate.count * T::get_byte_width());
+    match &predicate.strategy {
         IterationStrategy::SlicesIterator => {
-            let mut buffer = MutableBuffer::with_capacity(predicate.count * T::get_byte_width());
             for (start, end) in SlicesIterator::new(&predicate.filter) {
                 buffer.extend_from_slice(&values[start..end]);
             }
-            buffer
         }
         IterationStrategy::Slices(slices) => {
-            let mut buffer = MutableBuffer::with_capacity(predicate.count * T::get_byte_width());
             for (start, end) in slices {
                 buffer.extend_from_slice(&values[*start..*end]);
             }
-            buffer
         }
         IterationStrategy::IndexIterator => {
             let iter = IndexIterator::new(&predicate.filter, predicate.count).map(|x| values[x]);
-
-            // SAFETY: IndexIterator is trusted length
-            unsafe { MutableBuffer::from_trusted_len_iter(iter) }
-        }
-        IterationStrategy::Indices(indices) => {
-            let iter = indices.iter().map(|x| values[*x]);
-            // SAFETY: `Vec::iter` is trusted length
-            unsafe { MutableBuffer::from_trusted_len_iter(iter) }
+            buffer.extend(iter)
         }
+        IterationStrategy::Indices(indices) => buffer.extend(indices.iter().map(|x| values[*x])),
         IterationStrategy::All | IterationStrategy::None => unreachable!(),
-    };
+    }
 
     buffer.into()
 }
 
 /// `filter` implementation for primitive arrays
 fn filter_primitive<T>(array: &PrimitiveArray<T>, predicate: &FilterPredicate) -> PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+{
+    filter_primitive_with(array, predicate, MutableBuffer::new(0))
+}
+
+/// [`filter_primitive`] writing the values into `buffer`
+fn filter_primitive_with<T>(
+    array: &PrimitiveArray<T>,
+    predicate: &FilterPredicate,
+    buffer: MutableBuffer,
+) -> PrimitiveArray<T>
 where
     T: ArrowPrimitiveType,
 {
     let values = array.values();
-    let buffer = filter_native(values, predicate);
+    let buffer = filter_native(values, predicate, buffer);
     let mut builder = ArrayDataBuilder::new(array.data_type().clone())
         .len(predicate.count)
         .add_buffer(buffer);
@@ -727,7 +934,7 @@ fn filter_byte_view<T: ByteViewType>(
     array: &GenericByteViewArray<T>,
     predicate: &FilterPredicate,
 ) -> GenericByteViewArray<T> {
-    let new_view_buffer = filter_native(array.views(), predicate);
+    let new_view_buffer = filter_native(array.views(), predicate, MutableBuffer::new(0));
 
     let mut builder = ArrayDataBuilder::new(T::DATA_TYPE)
         .len(predicate.count)
@@ -800,7 +1007,10 @@ fn filter_fixed_size_binary(
 }
 
 /// `filter` implementation for dictionaries
-fn filter_dict<T>(array: &DictionaryArray<T>, predicate: &FilterPredicate) -> DictionaryArray<T>
+fn filter_dict<T>(
+    array: &DictionaryArray<T>,
+    predicate: &FilterPredicate,
+) -> Result<DictionaryArray<T>, ArrowError>
 where
     T: ArrowDictionaryKeyType,
     T::Native: num::Num,
@@ -813,7 +1023,11 @@ where
 
     // SAFETY:
     // Keys were valid before, filtered subset is therefore still valid
-    DictionaryArray::from(unsafe { builder.build_unchecked() })
+    let filtered = DictionaryArray::from(unsafe { builder.build_unchecked() });
+    match predicate.compact_dictionaries {
+        true => compact_dictionary(&filtered),
+        false => Ok(filtered),
+    }
 }
 
 /// `filter` implementation for structs
@@ -821,6 +1035,11 @@ fn filter_struct(
     array: &StructArray,
     predicate: &FilterPredicate,
 ) -> Result<StructArray, ArrowError> {
+    // Compute the selection once, rather than for each child and the null mask
+    let filtered = array.num_columns() + array.nulls().is_some() as usize;
+    let precomputed = (filtered > 1).then(|| predicate.precompute()).flatten();
+    let predicate = precomputed.as_ref().unwrap_or(predicate);
+
     let columns = array
         .columns()
         .iter()
@@ -835,6 +1054,11 @@ fn filter_struct(
         None
     };
 
+    if array.fields().is_empty() {
+        // The length cannot be inferred from the columns
+        return Ok(StructArray::new_empty_fields(predicate.count, nulls));
+    }
+
     Ok(unsafe { StructArray::new_unchecked(array.fields().clone(), columns, nulls) })
 }
 
@@ -859,11 +1083,63 @@ fn filter_sparse_union(
     })
 }
 
+/// `filter` implementation for dense unions
+fn filter_dense_union(
+    array: &UnionArray,
+    predicate: &FilterPredicate,
+) -> Result<UnionArray, ArrowError> {
+    let DataType::Union(fields, UnionMode::Dense) = array.data_type() else {
+        unreachable!()
+    };
+
+    let type_ids = filter_primitive(&Int8Array::new(array.type_ids().clone(), None), predicate);
+    let offsets = filter_primitive(
+        &Int32Array::new(array.offsets().unwrap().clone(), None),
+        predicate,
+    );
+
+    // Gather the values referenced by the selected rows of each child, as dense
+    // offsets need not be ordered or unique
+    let children = fields
+        .iter()
+        .map(|(child_type_id, _)| {
+            let indices = type_ids
+                .values()
+                .iter()
+                .zip(offsets.values())
+                .filter_map(|(type_id, offset)| (*type_id == child_type_id).then_some(*offset))
+                .collect::<Int32Array>();
+            crate::take::take(array.child(child_type_id), &indices, None)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut child_offsets = [0; 128];
+    let offsets = type_ids
+        .values()
+        .iter()
+        .map(|&type_id| {
+            let offset = child_offsets[type_id as usize];
+            child_offsets[type_id as usize] += 1;
+            offset
+        })
+        .collect();
+
+    Ok(unsafe {
+        UnionArray::new_unchecked(
+            fields.clone(),
+            type_ids.into_parts().1,
+            Some(offsets),
+            children,
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use arrow_array::builder::*;
     use arrow_array::cast::as_run_array;
     use arrow_array::types::*;
+    use arrow_buffer::i256;
     use rand::distr::uniform::{UniformSampler, UniformUsize};
     use rand::distr::{Alphanumeric, StandardUniform};
     use rand::prelude::*;
@@ -971,6 +1247,110 @@ mod tests {
         assert_eq!(9, d.value(1));
     }
 
+    #[test]
+    fn test_filter_data() {
+        let a = StringArray::from(vec![Some("a"), None, Some("c"), Some("d"), Some("e")]);
+        let predicate = BooleanArray::from(vec![true, true, false, true, false]);
+
+        let data = filter_data(&a.to_data(), &predicate).unwrap();
+        data.validate_full().unwrap();
+        assert_eq!(data, filter(&a, &predicate).unwrap().to_data());
+
+        // Selections can be composed on ArrayData
+        let data = filter_data(&data, &BooleanArray::from(vec![false, true, true])).unwrap();
+        assert_eq!(
+            StringArray::from(data),
+            StringArray::from(vec![None, Some("d")])
+        );
+
+        let sliced = a.slice(1, 3).to_data();
+        let data = filter_data(&sliced, &BooleanArray::from(vec![false, true, true])).unwrap();
+        assert_eq!(StringArray::from(data), StringArray::from(vec!["c", "d"]));
+
+        let err = filter_data(&sliced, &predicate).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Filter predicate of length 5 is larger than target array of length 3"
+        );
+    }
+
+    #[test]
+    fn test_filter_decimal256() {
+        let values = (0..70)
+            .map(|i| (i % 4 != 1).then(|| i256::from_parts(u128::MAX / 70 * i as u128, i - 35)))
+            .collect::<Vec<_>>();
+        let array = Decimal256Array::from(values.clone())
+            .with_precision_and_scale(76, -2)
+            .unwrap();
+
+        // Exercise both the slices and indices strategies, on full and sliced arrays
+        for keep in [|i: usize| i % 3 == 0, |i: usize| (10..60).contains(&i)] {
+            for (offset, len) in [(0, 70), (3, 64), (13, 1)] {
+                let sliced = array.slice(offset, len);
+                let predicate = BooleanArray::from_iter((0..len).map(|i| Some(keep(i))));
+                let filtered = filter(&sliced, &predicate).unwrap();
+                assert_eq!(filtered.data_type(), &DataType::Decimal256(76, -2));
+
+                let expected = values[offset..offset + len]
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, v)| keep(i).then_some(*v))
+                    .collect::<Vec<_>>();
+                let expected = Decimal256Array::from(expected)
+                    .with_precision_and_scale(76, -2)
+                    .unwrap();
+                assert_eq!(filtered.as_primitive::<Decimal256Type>(), &expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_filter_runs() {
+        let runs = |v: Vec<Option<bool>>| filter_runs(&BooleanArray::from(v));
+        assert_eq!(runs(vec![]), vec![]);
+        assert_eq!(runs(vec![Some(false); 3]), vec![]);
+        assert_eq!(runs(vec![Some(true); 3]), vec![0..3]);
+        assert_eq!(runs(vec![None; 3]), vec![]);
+        let alternating = (0..6).map(|i| Some(i % 2 == 0)).collect();
+        assert_eq!(runs(alternating), vec![0..1, 2..3, 4..5]);
+
+        // Nulls split runs, even if their value bits are set
+        let values = BooleanBuffer::from(vec![true; 6]);
+        let nulls = NullBuffer::from(vec![true, true, false, true, false, false]);
+        let predicate = BooleanArray::new(values, Some(nulls));
+        assert_eq!(filter_runs(&predicate), vec![0..2, 3..4]);
+
+        // Sliced predicates
+        let predicate = predicate.slice(1, 3);
+        assert_eq!(filter_runs(&predicate), vec![0..1, 2..3]);
+
+        // Runs crossing 64 bit chunks, checked against the set positions
+        let mut rng = StdRng::seed_from_u64(42);
+        for null_density in [0.0, 0.2] {
+            let len = 500;
+            let mut state = false;
+            let predicate = (0..len)
+                .map(|_| {
+                    if rng.random_bool(0.05) {
+                        state = !state;
+                    }
+                    (!rng.random_bool(null_density)).then_some(state)
+                })
+                .collect::<BooleanArray>();
+            let predicate = predicate.slice(7, len - 7);
+
+            let ranges = filter_runs(&predicate);
+            let covered = ranges.iter().flat_map(|r| r.clone()).collect::<Vec<_>>();
+            let expected = (0..predicate.len())
+                .filter(|i| predicate.is_valid(*i) && predicate.value(*i))
+                .collect::<Vec<_>>();
+            assert_eq!(covered, expected);
+            // ranges are non-empty and never adjacent
+            assert!(ranges.iter().all(|r| !r.is_empty()));
+            assert!(ranges.windows(2).all(|w| w[0].end < w[1].start));
+        }
+    }
+
     #[test]
     fn test_filter_array_low_density() {
         // this test exercises the all 0's branch of the filter algorithm
@@ -1322,6 +1702,51 @@ mod tests {
         assert_eq!("world", values.value(d.keys().value(1) as usize));
     }
 
+    #[test]
+    fn test_filter_compact_dictionaries() {
+        let a: Int8DictionaryArray = vec!["a", "b", "c", "b", "a", "d"].into_iter().collect();
+        // Drops all rows referencing "a" and "d"
+        let b = BooleanArray::from(vec![false, true, true, true, false, false]);
+
+        let predicate = FilterBuilder::new(&b).build();
+        let filtered = predicate.filter(&a).unwrap();
+        assert_eq!(filtered.as_dictionary::<Int8Type>().values().len(), 4);
+
+        let predicate = FilterBuilder::new(&b)
+            .with_compact_dictionaries(true)
+            .optimize()
+            .build();
+        let filtered = predicate.filter(&a).unwrap();
+        let filtered = filtered.as_dictionary::<Int8Type>();
+        let values = filtered.values().as_string::<i32>();
+        assert_eq!(values, &StringArray::from(vec!["b", "c"]));
+        assert_eq!(filtered.keys(), &Int8Array::from(vec![0, 1, 0]));
+
+        // Dictionaries nested in a struct are compacted
+        let strukt = StructArray::from(vec![(
+            Arc::new(Field::new("d", a.data_type().clone(), false)),
+            Arc::new(a.clone()) as ArrayRef,
+        )]);
+        let filtered = predicate.filter(&strukt).unwrap();
+        let nested = filtered.as_struct().column(0).as_dictionary::<Int8Type>();
+        assert_eq!(nested.values().len(), 2);
+
+        // Nulls are preserved
+        let a: Int8DictionaryArray = vec![Some("a"), None, Some("b")].into_iter().collect();
+        let b = BooleanArray::from(vec![false, true, true]);
+        let predicate = FilterBuilder::new(&b)
+            .with_compact_dictionaries(true)
+            .build();
+        let filtered = predicate.filter(&a).unwrap();
+        let filtered = filtered.as_dictionary::<Int8Type>();
+        assert_eq!(
+            filtered.values().as_string::<i32>(),
+            &StringArray::from(vec!["b"])
+        );
+        assert!(filtered.is_null(0));
+        assert_eq!(filtered.keys().value(1), 0);
+    }
+
     #[test]
     fn test_filter_list_array() {
         let value_data = ArrayData::builder(DataType::Int32)
@@ -1936,6 +2361,141 @@ mod tests {
         compare_union_arrays(filtered, &expected_array);
     }
 
+    #[test]
+    fn test_filter_union_array_remove_type_id() {
+        let fields = UnionFields::new(
+            vec![0, 1],
+            vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ],
+        );
+        let type_ids = arrow_buffer::ScalarBuffer::from(vec![0_i8, 1, 0, 1, 0]);
+        let mask = BooleanArray::from(vec![true, false, true, false, true]);
+
+        let sparse = UnionArray::try_new(
+            fields.clone(),
+            type_ids.clone(),
+            None,
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, None, None, Some(5)])),
+                Arc::new(StringArray::from(vec![
+                    None,
+                    Some("b"),
+                    None,
+                    Some("d"),
+                    None,
+                ])),
+            ],
+        )
+        .unwrap();
+
+        let c = filter(&sparse, &mask).unwrap();
+        let filtered = c.as_union();
+        assert_eq!(filtered.type_ids(), &[0, 0, 0]);
+        assert!(filtered.offsets().is_none());
+        let a = filtered.child(0).as_primitive::<Int32Type>();
+        assert_eq!(a, &Int32Array::from(vec![Some(1), None, Some(5)]));
+        let b = filtered.child(1).as_string::<i32>();
+        assert_eq!(b, &StringArray::new_null(3));
+
+        // Dense offsets need not be ordered, and may reference the same value
+        let dense = UnionArray::try_new(
+            fields,
+            type_ids,
+            Some(arrow_buffer::ScalarBuffer::from(vec![2_i32, 0, 0, 1, 2])),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(5)])),
+                Arc::new(StringArray::from(vec!["b", "d"])),
+            ],
+        )
+        .unwrap();
+
+        let c = filter(&dense, &mask).unwrap();
+        let filtered = c.as_union();
+        assert_eq!(filtered.type_ids(), &[0, 0, 0]);
+        assert_eq!(filtered.offsets().unwrap(), &[0, 1, 2]);
+        let a = filtered.child(0).as_primitive::<Int32Type>();
+        assert_eq!(a, &Int32Array::from(vec![Some(5), Some(1), Some(5)]));
+        assert!(filtered.child(1).is_empty());
+
+        let mask = BooleanArray::from(vec![false, true, false, true, false]);
+        let c = filter(&dense, &mask).unwrap();
+        let filtered = c.as_union();
+        assert_eq!(filtered.type_ids(), &[1, 1]);
+        assert_eq!(filtered.offsets().unwrap(), &[0, 1]);
+        assert!(filtered.child(0).is_empty());
+        let b = filtered.child(1).as_string::<i32>();
+        assert_eq!(b, &StringArray::from(vec!["b", "d"]));
+    }
+
+    #[test]
+    fn test_filter_context() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut ctx = FilterContext::new();
+        for _ in 0..100 {
+            let len = rng.random_range(0..64);
+            let values = (0..len)
+                .map(|_| rng.random_bool(0.8).then(|| rng.random::<i64>()))
+                .collect::<Int64Array>();
+            let mask = (0..len)
+                .map(|_| Some(rng.random_bool(0.5)))
+                .collect::<BooleanArray>();
+            let predicate = FilterBuilder::new(&mask).build();
+
+            let actual = ctx.filter_reuse(&values, &predicate).unwrap();
+            let expected = filter(&values, &mask).unwrap();
+            assert_eq!(&actual, &expected);
+        }
+
+        let strings = StringArray::from(vec![Some("a"), None, Some("c")]);
+        let mask = BooleanArray::from(vec![true, true, false]);
+        let predicate = FilterBuilder::new(&mask).build();
+        let actual = ctx.filter_reuse(&strings, &predicate).unwrap();
+        assert_eq!(
+            actual.as_string::<i32>(),
+            &StringArray::from(vec![Some("a"), None])
+        );
+
+        let err = ctx
+            .filter_reuse(&strings.slice(0, 2), &predicate)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Filter predicate of length 3 is larger than target array of length 2"
+        );
+    }
+
+    #[test]
+    fn test_filter_context_reuses_buffer() {
+        let values = Int32Array::from_iter_values(0..100);
+        let mask = BooleanArray::from_iter((0..100).map(|i| Some(i % 3 == 0)));
+        let predicate = FilterBuilder::new(&mask).build();
+        let mut ctx = FilterContext::new();
+
+        let first = ctx.filter_reuse(&values, &predicate).unwrap();
+        let first_ptr = first.as_primitive::<Int32Type>().values().as_ptr();
+
+        // The previous result is still alive, so a new allocation is required
+        let second = ctx.filter_reuse(&values, &predicate).unwrap();
+        let second_ptr = second.as_primitive::<Int32Type>().values().as_ptr();
+        assert_ne!(first_ptr, second_ptr);
+        assert_eq!(&first, &second);
+        drop(second);
+
+        // The previous result was dropped, so its allocation is reused
+        let values = Int32Array::from_iter_values(100..200);
+        let third = ctx.filter_reuse(&values, &predicate).unwrap();
+        let third = third.as_primitive::<Int32Type>();
+        assert_eq!(third.values().as_ptr(), second_ptr);
+        let expected = Int32Array::from_iter_values((100..200).step_by(3));
+        assert_eq!(third, &expected);
+
+        // The first result is unaffected
+        let expected = Int32Array::from_iter_values((0..100).step_by(3));
+        assert_eq!(first.as_primitive::<Int32Type>(), &expected);
+    }
+
     fn compare_union_arrays(union1: &UnionArray, union2: &UnionArray) {
         assert_eq!(union1.len(), union2.len());
 
@@ -2045,4 +2605,184 @@ mod tests {
 
         assert_eq!(result.to_data(), expected.to_data());
     }
+
+    #[test]
+    fn test_filter_struct_parent_nulls() {
+        let a = Arc::new(Int32Array::from(vec![
+            Some(1),
+            None,
+            Some(3),
+            Some(4),
+            Some(5),
+        ]));
+        let b = Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e"]));
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ]);
+        let nulls = NullBuffer::from(vec![true, false, true, false, true]);
+        let array = StructArray::new(fields.clone(), vec![a, b], Some(nulls));
+
+        // Exercise each iteration strategy
+        let predicates = [
+            BooleanArray::from(vec![false, true, true, true, false]),
+            BooleanArray::from(vec![false, true, false, true, false]),
+        ];
+        for predicate in predicates {
+            for optimize in [false, true] {
+                let mut builder = FilterBuilder::new(&predicate);
+                if optimize {
+                    builder = builder.optimize();
+                }
+                let result = builder.build().filter(&array).unwrap();
+                let result = result.as_struct();
+
+                let kept: Vec<_> = predicate.values().set_indices().collect();
+                assert_eq!(result.len(), kept.len());
+                for (i, idx) in kept.into_iter().enumerate() {
+                    assert_eq!(result.is_null(i), array.is_null(idx));
+                    assert_eq!(
+                        result.column(0).as_primitive::<Int32Type>().is_null(i),
+                        array.column(0).is_null(idx)
+                    );
+                    assert_eq!(
+                        result.column(1).as_string::<i32>().value(i),
+                        array.column(1).as_string::<i32>().value(idx)
+                    );
+                }
+            }
+        }
+
+        let predicate = BooleanArray::from(vec![false, true, true, true, false]);
+        let result = filter(&array, &predicate).unwrap();
+        let expected = StructArray::new(
+            fields,
+            vec![
+                Arc::new(Int32Array::from(vec![None, Some(3), Some(4)])),
+                Arc::new(StringArray::from(vec!["b", "c", "d"])),
+            ],
+            Some(NullBuffer::from(vec![false, true, false])),
+        );
+        assert_eq!(result.as_struct(), &expected);
+    }
+
+    #[test]
+    fn test_filter_struct_empty_fields_parent_nulls() {
+        let nulls = NullBuffer::from(vec![true, false, true, false, true]);
+        let array = StructArray::new_empty_fields(5, Some(nulls));
+
+        let predicate = BooleanArray::from(vec![true, true, false, true, false]);
+        let result = filter(&array, &predicate).unwrap();
+        let expected =
+            StructArray::new_empty_fields(3, Some(NullBuffer::from(vec![true, false, false])));
+        assert_eq!(result.as_struct(), &expected);
+
+        let predicate = BooleanArray::from(vec![true, false, true, false, true]);
+        let result = filter(&array, &predicate).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.null_count(), 0);
+    }
+
+    #[test]
+    fn test_filter_nested_struct_precompute() {
+        let a: ArrayRef = Arc::new(Int32Array::from_iter(
+            (0..100).map(|i| (i % 3 != 0).then_some(i)),
+        ));
+        let b: ArrayRef = Arc::new(StringArray::from_iter_values(
+            (0..100).map(|i| format!("s{i}")),
+        ));
+        let inner = StructArray::new(
+            Fields::from(vec![Field::new("b", DataType::Utf8, false)]),
+            vec![b],
+            Some(NullBuffer::from_iter((0..100).map(|i| i % 7 != 0))),
+        );
+        let outer = StructArray::new(
+            Fields::from(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("inner", inner.data_type().clone(), true),
+            ]),
+            vec![a, Arc::new(inner)],
+            None,
+        );
+
+        // Low and high selectivity, exercising indices and slices respectively
+        for predicate in [
+            BooleanArray::from_iter((0..100).map(|i| Some(i % 10 == 0))),
+            BooleanArray::from_iter((0..100).map(|i| Some(i % 10 != 0))),
+        ] {
+            let lazy = FilterBuilder::new(&predicate).build();
+            assert!(lazy.precompute().is_some());
+            let optimized = FilterBuilder::new(&predicate).optimize().build();
+            assert!(optimized.precompute().is_none());
+
+            let expected = crate::take::take(
+                &outer,
+                &UInt32Array::from_iter_values(predicate.values().set_indices().map(|i| i as u32)),
+                None,
+            )
+            .unwrap();
+            assert_eq!(lazy.filter(&outer).unwrap().as_ref(), expected.as_ref());
+            assert_eq!(
+                optimized.filter(&outer).unwrap().as_ref(),
+                expected.as_ref()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ffi")]
+    fn test_filter_ffi() {
+        use arrow_array::ffi::{from_ffi, to_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+
+        let a = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let b = StringArray::from(vec![Some("a"), Some("b"), None, Some("d")]);
+        let values = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int32, true)),
+                Arc::new(a) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::Utf8, true)),
+                Arc::new(b) as ArrayRef,
+            ),
+        ]);
+        let predicate = BooleanArray::from(vec![Some(true), Some(true), None, Some(false)]);
+        let expected = filter(&values, &predicate).unwrap();
+
+        let (array, schema) = to_ffi(&values.to_data()).unwrap();
+        let mut out = FFI_ArrowArray::empty();
+        let mut out_schema = FFI_ArrowSchema::empty();
+        unsafe { filter_ffi(array, &schema, &predicate, &mut out, &mut out_schema) }.unwrap();
+        assert_eq!(out.len(), 2);
+
+        let data = unsafe { from_ffi(out, &out_schema) }.unwrap();
+        assert_eq!(make_array(data).as_ref(), expected.as_ref());
+    }
+
+    #[test]
+    #[cfg(feature = "ffi")]
+    fn test_filter_ffi_invalid_layout() {
+        use arrow_array::ffi::{to_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+
+        // Offsets must be monotonically increasing
+        let data = unsafe {
+            ArrayData::builder(DataType::Utf8)
+                .len(2)
+                .add_buffer(Buffer::from_slice_ref([0_i32, 3, 1]))
+                .add_buffer(Buffer::from_slice_ref(b"abc"))
+                .build_unchecked()
+        };
+        let (array, schema) = to_ffi(&data).unwrap();
+
+        let predicate = BooleanArray::from(vec![true, false]);
+        let mut out = FFI_ArrowArray::empty();
+        let mut out_schema = FFI_ArrowSchema::empty();
+        let err = unsafe { filter_ffi(array, &schema, &predicate, &mut out, &mut out_schema) }
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Offset invariant failure"),
+            "{err}"
+        );
+        assert!(out.is_released());
+    }
 }
@@ -25,7 +25,7 @@ use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, BooleanBuffer, MutableBuffer, NullBuffer, OffsetBuffer};
 use arrow_data::transform::MutableArrayData;
 use arrow_data::ByteView;
-use arrow_schema::{ArrowError, DataType};
+use arrow_schema::{ArrowError, DataType, UnionFields, UnionMode};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -105,6 +105,7 @@ pub fn interleave(
             k.as_ref() => (dict_helper, values, indices),
             _ => unreachable!("illegal dictionary key type {k}")
         },
+        DataType::Union(fields, mode) => interleave_unions(values, indices, fields, *mode),
         _ => interleave_fallback(values, indices)
     }
 }
@@ -233,6 +234,68 @@ fn interleave_dictionaries<K: ArrowDictionaryKeyType>(
     Ok(Arc::new(array))
 }
 
+fn interleave_unions(
+    values: &[&dyn Array],
+    indices: &[(usize, usize)],
+    fields: &UnionFields,
+    mode: UnionMode,
+) -> Result<ArrayRef, ArrowError> {
+    let unions: Vec<_> = values.iter().map(|x| x.as_union()).collect();
+    let type_ids: Vec<i8> = indices
+        .iter()
+        .map(|(a, b)| unions[*a].type_id(*b))
+        .collect();
+
+    let (offsets, children) = match mode {
+        UnionMode::Sparse => {
+            // Each child is interleaved with the same indices as the union
+            let children = fields
+                .iter()
+                .map(|(type_id, _)| {
+                    let children: Vec<_> =
+                        unions.iter().map(|u| u.child(type_id).as_ref()).collect();
+                    interleave(&children, indices)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            (None, children)
+        }
+        UnionMode::Dense => {
+            // Each child is interleaved with the offsets of the rows of its type id
+            let children = fields
+                .iter()
+                .map(|(type_id, _)| {
+                    let children: Vec<_> =
+                        unions.iter().map(|u| u.child(type_id).as_ref()).collect();
+                    let child_indices: Vec<_> = indices
+                        .iter()
+                        .zip(&type_ids)
+                        .filter(|(_, t)| **t == type_id)
+                        .map(|((a, b), _)| (*a, unions[*a].value_offset(*b)))
+                        .collect();
+                    match child_indices.is_empty() {
+                        true => Ok(new_empty_array(children[0].data_type())),
+                        false => interleave(&children, &child_indices),
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let mut child_offsets = [0; 128];
+            let offsets = type_ids
+                .iter()
+                .map(|type_id| {
+                    let offset = child_offsets[*type_id as usize];
+                    child_offsets[*type_id as usize] += 1;
+                    offset
+                })
+                .collect();
+            (Some(offsets), children)
+        }
+    };
+
+    let array = UnionArray::try_new(fields.clone(), type_ids.into(), offsets, children)?;
+    Ok(Arc::new(array))
+}
+
 fn interleave_views<T: ByteViewType>(
     values: &[&dyn Array],
     indices: &[(usize, usize)],
@@ -363,10 +426,71 @@ pub fn interleave_record_batch(
     RecordBatch::try_new(schema, columns)
 }
 
+/// Scatters the rows of `array` into `num_sources` arrays, placing each row in the
+/// array identified by the corresponding integer value of `source_ids`
+///
+/// This is the inverse of [`interleave`], with the rows of each output array in the
+/// order they appear in `array`. Outputs with no rows tagged to them are empty.
+///
+/// # Errors
+/// This function errors if `source_ids` does not have the same length as `array`, or
+/// contains a null or a value that is not less than `num_sources`
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int32Array, UInt8Array};
+/// # use arrow_select::interleave::deinterleave;
+/// let array = Int32Array::from(vec![1, 2, 3, 4, 5]);
+/// let source_ids = UInt8Array::from(vec![1, 0, 1, 1, 0]);
+/// let sources = deinterleave(&array, &source_ids, 3).unwrap();
+/// assert_eq!(sources[0].as_ref(), &Int32Array::from(vec![2, 5]));
+/// assert_eq!(sources[1].as_ref(), &Int32Array::from(vec![1, 3, 4]));
+/// assert_eq!(sources[2].as_ref(), &Int32Array::from(Vec::<i32>::new()));
+/// ```
+pub fn deinterleave<T>(
+    array: &dyn Array,
+    source_ids: &PrimitiveArray<T>,
+    num_sources: usize,
+) -> Result<Vec<ArrayRef>, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: TryInto<usize>,
+{
+    if source_ids.len() != array.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "deinterleave requires source ids of the same length as the array, got {} and {}",
+            source_ids.len(),
+            array.len()
+        )));
+    }
+    if source_ids.null_count() != 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "deinterleave requires source ids without nulls".to_string(),
+        ));
+    }
+
+    let mut indices = vec![vec![]; num_sources];
+    for (row, id) in source_ids.values().iter().enumerate() {
+        match (*id).try_into().ok().and_then(|id| indices.get_mut(id)) {
+            Some(indices) => indices.push(row as u64),
+            None => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "deinterleave requires source ids less than {num_sources}, got {id:?}"
+                )))
+            }
+        }
+    }
+    indices
+        .into_iter()
+        .map(|indices| crate::take::take(array, &UInt64Array::from(indices), None))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use arrow_array::builder::{Int32Builder, ListBuilder};
+    use arrow_schema::Field;
 
     #[test]
     fn test_primitive() {
@@ -727,4 +851,143 @@ mod tests {
             ]
         );
     }
+
+    fn union_fields() -> UnionFields {
+        UnionFields::new(
+            vec![0, 1],
+            vec![
+                Field::new("int", DataType::Int32, true),
+                Field::new("str", DataType::Utf8, true),
+            ],
+        )
+    }
+
+    /// Asserts that row `i` of `actual` equals row `j` of `expected`
+    fn assert_union_row_eq(actual: &UnionArray, i: usize, expected: &UnionArray, j: usize) {
+        assert_eq!(actual.type_id(i), expected.type_id(j));
+        assert_eq!(actual.value(i).to_data(), expected.value(j).to_data());
+    }
+
+    #[test]
+    fn test_interleave_sparse_union() {
+        let a = UnionArray::try_new(
+            union_fields(),
+            vec![0_i8, 1, 0, 1].into(),
+            None,
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, None, None])),
+                Arc::new(StringArray::from(vec![None, Some("a"), None, Some("b")])),
+            ],
+        )
+        .unwrap();
+        let b = UnionArray::try_new(
+            union_fields(),
+            vec![1_i8, 0, 0].into(),
+            None,
+            vec![
+                Arc::new(Int32Array::from(vec![None, Some(4), Some(5)])),
+                Arc::new(StringArray::from(vec![Some("c"), None, None])),
+            ],
+        )
+        .unwrap();
+        let b = b.slice(1, 2);
+
+        let indices = &[(0, 3), (1, 1), (0, 0), (0, 1), (1, 0), (0, 2)];
+        let values = interleave(&[&a, &b], indices).unwrap();
+        let v = values.as_union();
+        assert_eq!(v.data_type(), a.data_type());
+        assert!(v.offsets().is_none());
+        assert_eq!(v.len(), indices.len());
+
+        let arrays = [&a, &b];
+        for (i, (array, row)) in indices.iter().enumerate() {
+            assert_union_row_eq(v, i, arrays[*array], *row);
+        }
+    }
+
+    #[test]
+    fn test_interleave_dense_union() {
+        let a = UnionArray::try_new(
+            union_fields(),
+            vec![0_i8, 1, 0, 1, 0].into(),
+            Some(vec![0, 0, 1, 1, 2].into()),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+                Arc::new(StringArray::from(vec![Some("a"), Some("b")])),
+            ],
+        )
+        .unwrap();
+        let b = UnionArray::try_new(
+            union_fields(),
+            vec![1_i8, 1, 0].into(),
+            Some(vec![0, 1, 0].into()),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(5)])),
+                Arc::new(StringArray::from(vec![None, Some("d")])),
+            ],
+        )
+        .unwrap();
+
+        let indices = &[(1, 2), (0, 4), (0, 1), (1, 0), (0, 2), (1, 1), (0, 4)];
+        let values = interleave(&[&a, &b], indices).unwrap();
+        let v = values.as_union();
+        assert_eq!(v.data_type(), a.data_type());
+        assert_eq!(v.offsets().unwrap(), &[0, 1, 0, 1, 2, 2, 3]);
+        assert_eq!(v.child(0).len(), 4);
+        assert_eq!(v.child(1).len(), 3);
+
+        let arrays = [&a, &b];
+        for (i, (array, row)) in indices.iter().enumerate() {
+            assert_union_row_eq(v, i, arrays[*array], *row);
+        }
+
+        // No rows of type id 0
+        let values = interleave(&[&a, &b], &[(0, 1), (1, 1)]).unwrap();
+        let v = values.as_union();
+        assert_eq!(v.type_ids(), &[1, 1]);
+        assert_eq!(v.child(0).len(), 0);
+        assert_union_row_eq(v, 0, &a, 1);
+        assert_union_row_eq(v, 1, &b, 1);
+    }
+
+    #[test]
+    fn test_deinterleave() {
+        let a = StringArray::from(vec![Some("a"), None, Some("c")]);
+        let b = StringArray::from(vec![Some("d")]);
+        let c = StringArray::from(vec![None, Some("f")]);
+        let indices = [(1, 0), (0, 0), (2, 0), (0, 1), (2, 1), (0, 2)];
+        let combined = interleave(&[&a, &b, &c], &indices).unwrap();
+
+        let source_ids = Int32Array::from_iter_values(indices.iter().map(|(s, _)| *s as i32));
+        let sources = deinterleave(combined.as_ref(), &source_ids, 4).unwrap();
+        assert_eq!(sources.len(), 4);
+        assert_eq!(sources[0].as_string::<i32>(), &a);
+        assert_eq!(sources[1].as_string::<i32>(), &b);
+        assert_eq!(sources[2].as_string::<i32>(), &c);
+        assert_eq!(sources[3].len(), 0);
+        assert_eq!(sources[3].data_type(), &DataType::Utf8);
+
+        let err = deinterleave(combined.as_ref(), &source_ids, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: deinterleave requires source ids less than 2, got 2"
+        );
+        let source_ids = Int32Array::from(vec![0, -1, 0, 0, 0, 0]);
+        let err = deinterleave(combined.as_ref(), &source_ids, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: deinterleave requires source ids less than 2, got -1"
+        );
+        let source_ids = Int32Array::from(vec![Some(0), None, Some(0), Some(0), Some(0), Some(0)]);
+        let err = deinterleave(combined.as_ref(), &source_ids, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: deinterleave requires source ids without nulls"
+        );
+        let err = deinterleave(&a, &source_ids, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: deinterleave requires source ids of the same length as the array, got 6 and 3"
+        );
+    }
 }
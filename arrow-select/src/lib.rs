@@ -28,7 +28,10 @@ pub mod concat;
 mod dictionary;
 pub mod filter;
 pub mod interleave;
+pub mod map;
+pub mod minmax;
 pub mod nullif;
+pub mod sample;
 pub mod take;
 pub mod union_extract;
 pub mod window;
@@ -0,0 +1,250 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`map_lookup`]: look up values of a [`MapArray`] by key
+
+use crate::take::take;
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+
+/// Returns the value associated with `keys[i]` in the map of row `i` of `map`
+///
+/// The result is null where the map or key is null, or where the map does not
+/// contain the key. If a map contains the key more than once, the value of its first
+/// entry is returned. Keys are compared by equality, and entries are not required
+/// to be sorted.
+///
+/// # Errors
+/// This function errors if `keys` does not have the same length as `map` and the
+/// same data type as its keys, or the key type is not supported
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, Int32Array, MapArray, StringArray};
+/// # use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::map::map_lookup;
+/// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+/// builder.keys().append_value("a");
+/// builder.values().append_value(1);
+/// builder.keys().append_value("b");
+/// builder.values().append_value(2);
+/// builder.append(true).unwrap();
+/// builder.keys().append_value("c");
+/// builder.values().append_value(3);
+/// builder.append(true).unwrap();
+/// let map = builder.finish();
+///
+/// let keys = StringArray::from(vec!["b", "b"]);
+/// let values = map_lookup(&map, &keys).unwrap();
+/// assert_eq!(values.as_primitive::<Int32Type>(), &Int32Array::from(vec![Some(2), None]));
+/// ```
+pub fn map_lookup(map: &MapArray, keys: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    if keys.len() != map.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "map_lookup requires keys of the same length as the map, got {} and {}",
+            keys.len(),
+            map.len()
+        )));
+    }
+    if keys.data_type() != map.key_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "map_lookup requires keys of type {}, got {}",
+            map.key_type(),
+            keys.data_type()
+        )));
+    }
+
+    let indices = downcast_primitive_array! {
+        keys => lookup(map, map.keys().as_primitive(), keys),
+        DataType::Utf8 => lookup_bytes::<Utf8Type>(map, keys),
+        DataType::LargeUtf8 => lookup_bytes::<LargeUtf8Type>(map, keys),
+        DataType::Binary => lookup_bytes::<BinaryType>(map, keys),
+        DataType::LargeBinary => lookup_bytes::<LargeBinaryType>(map, keys),
+        DataType::Utf8View => lookup(map, map.keys().as_string_view(), keys.as_string_view()),
+        DataType::BinaryView => lookup(map, map.keys().as_binary_view(), keys.as_binary_view()),
+        DataType::FixedSizeBinary(_) => lookup(
+            map,
+            map.keys().as_fixed_size_binary(),
+            keys.as_fixed_size_binary(),
+        ),
+        d => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "map_lookup not supported for key type {d}"
+            )))
+        }
+    };
+
+    take(map.values().as_ref(), &indices, None)
+}
+
+fn lookup_bytes<T>(map: &MapArray, keys: &dyn Array) -> UInt32Array
+where
+    T: ByteArrayType,
+    T::Native: PartialEq,
+{
+    lookup(map, map.keys().as_bytes::<T>(), keys.as_bytes::<T>())
+}
+
+/// Returns the index into the entries of `map` of the first entry of each row
+/// whose key equals the corresponding value of `keys`
+fn lookup<A>(map: &MapArray, map_keys: A, keys: A) -> UInt32Array
+where
+    A: ArrayAccessor,
+    A::Item: PartialEq,
+{
+    let offsets = map.value_offsets();
+    (0..map.len())
+        .map(|i| {
+            if map.is_null(i) || keys.is_null(i) {
+                return None;
+            }
+            let key = keys.value(i);
+            let start = offsets[i] as usize;
+            let end = offsets[i + 1] as usize;
+            (start..end)
+                .find(|&j| map_keys.is_valid(j) && map_keys.value(j) == key)
+                .map(|j| j as u32)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    use arrow_buffer::OffsetBuffer;
+    use arrow_schema::Field;
+    use std::sync::Arc;
+
+    type Entries<'a> = &'a [(&'a str, Option<i32>)];
+
+    fn build_map(rows: &[Option<Entries>]) -> MapArray {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        for row in rows {
+            for (k, v) in row.unwrap_or_default() {
+                builder.keys().append_value(k);
+                builder.values().append_option(*v);
+            }
+            builder.append(row.is_some()).unwrap();
+        }
+        builder.finish()
+    }
+
+    #[test]
+    fn test_map_lookup() {
+        let map = build_map(&[
+            // Sorted entries
+            Some(&[("a", Some(1)), ("b", Some(2)), ("c", Some(3))]),
+            // Unsorted entries
+            Some(&[("z", Some(26)), ("b", None), ("m", Some(13))]),
+            Some(&[("a", Some(1))]),
+            Some(&[]),
+            None,
+            Some(&[("x", Some(24)), ("x", Some(-1))]),
+            Some(&[("c", Some(3)), ("a", Some(1))]),
+        ]);
+        let keys = StringArray::from(vec![
+            Some("b"),
+            Some("m"),
+            Some("q"),
+            Some("a"),
+            Some("a"),
+            Some("x"),
+            None,
+        ]);
+
+        let values = map_lookup(&map, &keys).unwrap();
+        let expected = Int32Array::from(vec![
+            Some(2),
+            Some(13),
+            // Absent keys
+            None,
+            None,
+            // Null map
+            None,
+            // The first matching entry is used
+            Some(24),
+            // Null key
+            None,
+        ]);
+        assert_eq!(values.as_primitive::<Int32Type>(), &expected);
+
+        // A present key with a null value
+        let keys = StringArray::from(vec!["c", "b", "a", "a", "a", "a", "a"]);
+        let values = map_lookup(&map, &keys).unwrap();
+        let expected = Int32Array::from(vec![Some(3), None, Some(1), None, None, None, Some(1)]);
+        assert_eq!(values.as_primitive::<Int32Type>(), &expected);
+
+        // Sliced maps
+        let sliced = map.slice(1, 2);
+        let keys = StringArray::from(vec!["z", "a"]);
+        let values = map_lookup(&sliced, &keys).unwrap();
+        let expected = Int32Array::from(vec![26, 1]);
+        assert_eq!(values.as_primitive::<Int32Type>(), &expected);
+    }
+
+    #[test]
+    fn test_map_lookup_primitive_keys() {
+        let keys = Int64Array::from(vec![5, 1, 3, 2]);
+        let values = StringArray::from(vec!["five", "one", "three", "two"]);
+        let entries = StructArray::from(vec![
+            (
+                Arc::new(Field::new("keys", DataType::Int64, false)),
+                Arc::new(keys) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("values", DataType::Utf8, true)),
+                Arc::new(values) as ArrayRef,
+            ),
+        ]);
+        let field = Arc::new(Field::new("entries", entries.data_type().clone(), false));
+        let offsets = OffsetBuffer::new(vec![0, 3, 4].into());
+        let map = MapArray::new(field, offsets, entries, None, false);
+
+        let keys = Int64Array::from(vec![Some(3), Some(2)]);
+        let values = map_lookup(&map, &keys).unwrap();
+        let expected = StringArray::from(vec!["three", "two"]);
+        assert_eq!(values.as_string::<i32>(), &expected);
+
+        let keys = Int64Array::from(vec![Some(2), None]);
+        let values = map_lookup(&map, &keys).unwrap();
+        assert_eq!(values.null_count(), 2);
+    }
+
+    #[test]
+    fn test_map_lookup_invalid() {
+        let map = build_map(&[Some(&[("a", Some(1))])]);
+
+        let keys = StringArray::from(vec!["a", "b"]);
+        let err = map_lookup(&map, &keys).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: map_lookup requires keys of the same length as the map, got 2 and 1"
+        );
+
+        let keys = Int32Array::from(vec![1]);
+        let err = map_lookup(&map, &keys).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: map_lookup requires keys of type Utf8, got Int32"
+        );
+    }
+}
@@ -0,0 +1,260 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`greatest`] and [`least`]: select the largest or smallest value of each row
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_schema::ArrowError;
+
+/// Returns the largest non-null value of each row across `arrays`, or null if
+/// all values of the row are null. This can be used to implement SQL `GREATEST`
+///
+/// Floating point values are compared as per IEEE 754's totalOrder predicate, see
+/// [`f64::total_cmp`], so that `NaN` is greater than all other values, including
+/// positive infinity, and `-0.0` is less than `+0.0`
+///
+/// # Errors
+/// This function errors if `arrays` is empty, the arrays do not all have the same
+/// primitive data type and length
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, Float64Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Float64Type;
+/// # use arrow_select::minmax::greatest;
+/// let a = Float64Array::from(vec![Some(1.0), None, Some(f64::NAN), None]);
+/// let b = Float64Array::from(vec![Some(2.0), Some(3.0), Some(f64::INFINITY), None]);
+/// let result = greatest(&[&a, &b]).unwrap();
+/// let result = result.as_primitive::<Float64Type>();
+/// assert_eq!(result.value(0), 2.0);
+/// assert_eq!(result.value(1), 3.0);
+/// assert!(result.value(2).is_nan());
+/// assert!(result.is_null(3));
+/// ```
+pub fn greatest(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    select(arrays, Ordering::Greater, "greatest")
+}
+
+/// Returns the smallest non-null value of each row across `arrays`, or null if
+/// all values of the row are null. This can be used to implement SQL `LEAST`
+///
+/// Floating point values are compared as per IEEE 754's totalOrder predicate, see
+/// [`f64::total_cmp`], so that `NaN` is greater than all other values, including
+/// positive infinity, and `-0.0` is less than `+0.0`
+///
+/// # Errors
+/// This function errors if `arrays` is empty, the arrays do not all have the same
+/// primitive data type and length
+///
+/// # Example
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_select::minmax::least;
+/// let a = Int32Array::from(vec![Some(1), None, Some(5)]);
+/// let b = Int32Array::from(vec![Some(2), Some(3), Some(4)]);
+/// let result = least(&[&a, &b]).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![1, 3, 4]));
+/// ```
+pub fn least(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    select(arrays, Ordering::Less, "least")
+}
+
+macro_rules! primitive_helper {
+    ($t:ty, $arrays:expr, $keep:expr) => {
+        Ok(Arc::new(select_primitive::<$t>($arrays, $keep)))
+    };
+}
+
+fn select(arrays: &[&dyn Array], keep: Ordering, name: &str) -> Result<ArrayRef, ArrowError> {
+    let Some(first) = arrays.first() else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "{name} requires input of at least one array"
+        )));
+    };
+
+    let data_type = first.data_type();
+    if arrays.iter().any(|a| a.data_type() != data_type) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "{name} requires all arrays to have the same data type"
+        )));
+    }
+    if arrays.iter().any(|a| a.len() != first.len()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "{name} requires all arrays to have the same length"
+        )));
+    }
+
+    downcast_primitive! {
+        data_type => (primitive_helper, arrays, keep),
+        d => Err(ArrowError::InvalidArgumentError(format!(
+            "{name} not supported for data type {d}"
+        )))
+    }
+}
+
+/// Selects the value of each row for which no other value compares as `keep`,
+/// keeping the first such value in case of ties
+fn select_primitive<T: ArrowPrimitiveType>(
+    arrays: &[&dyn Array],
+    keep: Ordering,
+) -> PrimitiveArray<T> {
+    let first = arrays[0].as_primitive::<T>();
+    let mut values = first.iter().collect::<Vec<_>>();
+
+    for array in &arrays[1..] {
+        let array = array.as_primitive::<T>();
+        for (current, value) in values.iter_mut().zip(array.iter()) {
+            match (*current, value) {
+                (_, None) => {}
+                (Some(c), Some(v)) if v.compare(c) != keep => {}
+                (_, v) => *current = v,
+            }
+        }
+    }
+
+    PrimitiveArray::<T>::from_iter(values).with_data_type(first.data_type().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_greatest_least_int() {
+        let a = Int32Array::from(vec![Some(1), None, Some(5), None, Some(-2)]);
+        let b = Int32Array::from(vec![Some(2), Some(3), None, None, Some(-7)]);
+        let c = Int32Array::from(vec![Some(0), Some(1), Some(4), None, Some(-3)]);
+
+        let result = greatest(&[&a, &b, &c]).unwrap();
+        let expected = Int32Array::from(vec![Some(2), Some(3), Some(5), None, Some(-2)]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        let result = least(&[&a, &b, &c]).unwrap();
+        let expected = Int32Array::from(vec![Some(0), Some(1), Some(4), None, Some(-7)]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        // A single array is returned unchanged
+        let result = greatest(&[&a]).unwrap();
+        assert_eq!(result.as_primitive::<Int32Type>(), &a);
+    }
+
+    #[test]
+    fn test_greatest_least_float_total_order() {
+        let a = Float64Array::from(vec![
+            f64::NAN,
+            1.0,
+            -0.0,
+            0.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            -f64::NAN,
+        ]);
+        let b = Float64Array::from(vec![
+            f64::INFINITY,
+            f64::NAN,
+            0.0,
+            -0.0,
+            f64::NAN,
+            -f64::NAN,
+            f64::NEG_INFINITY,
+        ]);
+
+        let bits = |a: ArrayRef| {
+            let a = a.as_primitive::<Float64Type>();
+            a.values().iter().map(|v| v.to_bits()).collect::<Vec<_>>()
+        };
+        let expected = |v: &[f64]| v.iter().map(|v| v.to_bits()).collect::<Vec<_>>();
+
+        // The result does not depend on the order of the arguments
+        for (x, y) in [(&a, &b), (&b, &a)] {
+            let result = greatest(&[x, y]).unwrap();
+            assert_eq!(
+                bits(result),
+                expected(&[
+                    f64::NAN,
+                    f64::NAN,
+                    0.0,
+                    0.0,
+                    f64::NAN,
+                    f64::NEG_INFINITY,
+                    f64::NEG_INFINITY,
+                ])
+            );
+
+            let result = least(&[x, y]).unwrap();
+            assert_eq!(
+                bits(result),
+                expected(&[
+                    f64::INFINITY,
+                    1.0,
+                    -0.0,
+                    -0.0,
+                    f64::INFINITY,
+                    -f64::NAN,
+                    -f64::NAN,
+                ])
+            );
+        }
+    }
+
+    #[test]
+    fn test_greatest_least_preserves_data_type() {
+        let a = TimestampSecondArray::from(vec![1, 5]).with_timezone("+01:00");
+        let b = TimestampSecondArray::from(vec![3, 2]).with_timezone("+01:00");
+        let result = greatest(&[&a, &b]).unwrap();
+        assert_eq!(result.data_type(), a.data_type());
+        let expected = TimestampSecondArray::from(vec![3, 5]).with_timezone("+01:00");
+        assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected);
+    }
+
+    #[test]
+    fn test_greatest_least_invalid() {
+        let err = greatest(&[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: greatest requires input of at least one array"
+        );
+
+        let a = Int32Array::from(vec![1, 2]);
+        let b = Int64Array::from(vec![1, 2]);
+        let err = least(&[&a, &b]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: least requires all arrays to have the same data type"
+        );
+
+        let b = Int32Array::from(vec![1]);
+        let err = least(&[&a, &b]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: least requires all arrays to have the same length"
+        );
+
+        let a = StringArray::from(vec!["a"]);
+        let err = greatest(&[&a, &a]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: greatest not supported for data type Utf8"
+        );
+    }
+}
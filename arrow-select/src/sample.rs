@@ -0,0 +1,374 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`sample`] and [`shuffle`]: select random rows, or all rows in random order
+//!
+//! Random numbers are generated by [SplitMix64] seeded with the given seed, which is
+//! implemented in this module so that the rows selected for a seed do not change with
+//! the version of a dependency.
+//!
+//! Rows sampled without replacement are selected with Knuth's selection sampling
+//! (Algorithm S), and are returned in their original order. Rows sampled with
+//! replacement are selected independently, and are returned in the order sampled.
+//! Rows are shuffled with the Fisher-Yates shuffle.
+//!
+//! [SplitMix64]: https://prng.di.unimi.it/splitmix64.c
+
+use crate::take::take;
+use arrow_array::{Array, ArrayRef, RecordBatch, RecordBatchOptions, UInt64Array};
+use arrow_schema::ArrowError;
+
+/// Returns `n` randomly selected rows of `array`, as per [`take`]
+///
+/// If `with_replacement` is `false`, each row is selected at most once, and the rows
+/// keep their order in `array`. Otherwise, each of the `n` rows is selected
+/// independently from all rows of `array`. The same `seed` selects the same rows, see
+/// the [module docs](self) for details.
+///
+/// # Errors
+/// This function errors if `n` is greater than the length of `array` without
+/// replacement, or `n` is not zero and `array` is empty with replacement
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, Int32Array};
+/// # use arrow_select::sample::sample;
+/// let array = Int32Array::from_iter_values(0..100);
+/// let sampled = sample(&array, 5, 42, false).unwrap();
+/// assert_eq!(sampled.len(), 5);
+/// assert_eq!(sampled.as_ref(), sample(&array, 5, 42, false).unwrap().as_ref());
+/// ```
+pub fn sample(
+    array: &dyn Array,
+    n: usize,
+    seed: u64,
+    with_replacement: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let indices = sample_indices(array.len(), n, seed, with_replacement)?;
+    take(array, &indices, None)
+}
+
+/// Returns `n` randomly selected rows of `record_batch`, selecting the same rows of
+/// each column as per [`sample`]
+///
+/// # Errors
+/// This function errors under the same conditions as [`sample`]
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Array, Int32Array, RecordBatch, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::sample::sample_record_batch;
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from_iter_values(0..10)) as _),
+///     ("b", Arc::new(StringArray::from_iter_values((0..10).map(|i| i.to_string()))) as _),
+/// ])
+/// .unwrap();
+///
+/// let sampled = sample_record_batch(&batch, 3, 7, true).unwrap();
+/// assert_eq!(sampled.num_rows(), 3);
+/// let a = sampled.column(0).as_primitive::<Int32Type>();
+/// let b = sampled.column(1).as_string::<i32>();
+/// for i in 0..3 {
+///     assert_eq!(a.value(i).to_string(), b.value(i));
+/// }
+/// ```
+pub fn sample_record_batch(
+    record_batch: &RecordBatch,
+    n: usize,
+    seed: u64,
+    with_replacement: bool,
+) -> Result<RecordBatch, ArrowError> {
+    let indices = sample_indices(record_batch.num_rows(), n, seed, with_replacement)?;
+    let columns = record_batch
+        .columns()
+        .iter()
+        .map(|c| take(c, &indices, None))
+        .collect::<Result<Vec<_>, _>>()?;
+    let options = RecordBatchOptions::new().with_row_count(Some(n));
+    RecordBatch::try_new_with_options(record_batch.schema(), columns, &options)
+}
+
+/// Returns the rows of `array` in a random order, as per [`take`]
+///
+/// The same `seed` returns the same order for arrays of the same length, see the
+/// [module docs](self) for details.
+///
+/// # Errors
+/// This function errors under the same conditions as [`take`]
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, Int32Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::sample::shuffle;
+/// let array = Int32Array::from_iter_values(0..10);
+/// let shuffled = shuffle(&array, 42).unwrap();
+/// let mut values = shuffled.as_primitive::<Int32Type>().values().to_vec();
+/// values.sort();
+/// assert_eq!(values, (0..10).collect::<Vec<_>>());
+/// ```
+pub fn shuffle(array: &dyn Array, seed: u64) -> Result<ArrayRef, ArrowError> {
+    take(array, &permutation(array.len(), seed), None)
+}
+
+/// Returns the rows of `record_batch` in a random order, reordering each column by
+/// the same permutation as per [`shuffle`]
+///
+/// # Errors
+/// This function errors under the same conditions as [`take`]
+pub fn shuffle_record_batch(
+    record_batch: &RecordBatch,
+    seed: u64,
+) -> Result<RecordBatch, ArrowError> {
+    let indices = permutation(record_batch.num_rows(), seed);
+    let columns = record_batch
+        .columns()
+        .iter()
+        .map(|c| take(c, &indices, None))
+        .collect::<Result<Vec<_>, _>>()?;
+    let options = RecordBatchOptions::new().with_row_count(Some(record_batch.num_rows()));
+    RecordBatch::try_new_with_options(record_batch.schema(), columns, &options)
+}
+
+/// Returns a random permutation of `0..len`
+fn permutation(len: usize, seed: u64) -> UInt64Array {
+    let mut rng = SplitMix64(seed);
+    let mut indices: Vec<u64> = (0..len as u64).collect();
+    for i in (1..len).rev() {
+        let j = rng.next_below(i as u64 + 1) as usize;
+        indices.swap(i, j);
+    }
+    UInt64Array::from(indices)
+}
+
+/// Returns the indices of `n` random rows of `len` rows
+fn sample_indices(
+    len: usize,
+    n: usize,
+    seed: u64,
+    with_replacement: bool,
+) -> Result<UInt64Array, ArrowError> {
+    let mut rng = SplitMix64(seed);
+    if with_replacement {
+        if len == 0 && n > 0 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "cannot sample {n} rows with replacement from 0 rows"
+            )));
+        }
+        let indices = (0..n).map(|_| rng.next_below(len as u64));
+        return Ok(UInt64Array::from_iter_values(indices));
+    }
+
+    if n > len {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "cannot sample {n} rows without replacement from {len} rows"
+        )));
+    }
+    // Selects each row with probability (rows still needed) / (rows remaining)
+    let mut indices = Vec::with_capacity(n);
+    for row in 0..len {
+        let needed = n - indices.len();
+        if needed == 0 {
+            break;
+        }
+        if rng.next_below((len - row) as u64) < needed as u64 {
+            indices.push(row as u64);
+        }
+    }
+    Ok(UInt64Array::from(indices))
+}
+
+/// The SplitMix64 random number generator
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..bound`, with a bias of at most `bound / 2^64`
+    fn next_below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Int32Array, StringArray};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_split_mix_64() {
+        // The first outputs of the reference implementation for a seed of 1234567
+        let mut rng = SplitMix64(1234567);
+        let values: Vec<_> = (0..3).map(|_| rng.next_u64()).collect();
+        assert_eq!(
+            values,
+            [
+                6457827717110365317,
+                3203168211198807973,
+                9817491932198370423
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        let array = Int32Array::from_iter_values(0..1000);
+
+        // The rows are distinct and in order of the input
+        let sampled = sample(&array, 100, 1, false).unwrap();
+        let values = sampled.as_primitive::<Int32Type>().values();
+        assert_eq!(values.len(), 100);
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+
+        // Seeds are reproducible
+        assert_eq!(&sampled, &sample(&array, 100, 1, false).unwrap());
+        assert_ne!(&sampled, &sample(&array, 100, 2, false).unwrap());
+
+        // All rows, with nulls
+        let array = Int32Array::from_iter((0..1000).map(|i| (i % 10 != 0).then_some(i)));
+        let sampled = sample(&array, 1000, 3, false).unwrap();
+        assert_eq!(sampled.as_primitive::<Int32Type>(), &array);
+
+        // With replacement rows can repeat
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let sampled = sample(&array, 50, 4, true).unwrap();
+        let sampled = sampled.as_primitive::<Int32Type>();
+        assert_eq!(sampled.len(), 50);
+        for v in 1..=3 {
+            assert!(sampled.values().contains(&v), "{v} not sampled");
+        }
+
+        assert_eq!(sample(&array, 0, 5, false).unwrap().len(), 0);
+        assert_eq!(sample(&array.slice(0, 0), 0, 5, true).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_sample_invalid() {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let err = sample(&array, 4, 0, false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: cannot sample 4 rows without replacement from 3 rows"
+        );
+
+        let err = sample(&array.slice(0, 0), 1, 0, true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: cannot sample 1 rows with replacement from 0 rows"
+        );
+    }
+
+    #[test]
+    fn test_sample_record_batch() {
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(Int32Array::from_iter_values(0..100)) as _),
+            (
+                "b",
+                Arc::new(StringArray::from_iter_values(
+                    (0..100).map(|i| format!("{i}")),
+                )) as _,
+            ),
+        ])
+        .unwrap();
+
+        for with_replacement in [false, true] {
+            let sampled = sample_record_batch(&batch, 20, 9, with_replacement).unwrap();
+            assert_eq!(sampled.num_rows(), 20);
+            assert_eq!(sampled.schema(), batch.schema());
+            let a = sampled.column(0);
+            assert_eq!(
+                a,
+                &sample(batch.column(0), 20, 9, with_replacement).unwrap()
+            );
+            let a = a.as_primitive::<Int32Type>();
+            let b = sampled.column(1).as_string::<i32>();
+            assert!((0..20).all(|i| a.value(i).to_string() == b.value(i)));
+        }
+
+        // Batches without columns
+        let empty = RecordBatch::try_new_with_options(
+            batch.schema().project(&[]).unwrap().into(),
+            vec![],
+            &RecordBatchOptions::new().with_row_count(Some(10)),
+        )
+        .unwrap();
+        let sampled = sample_record_batch(&empty, 4, 0, false).unwrap();
+        assert_eq!(sampled.num_rows(), 4);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let array = StringArray::from_iter((0..100).map(|i| (i % 7 != 0).then(|| i.to_string())));
+        let shuffled = shuffle(&array, 11).unwrap();
+        let shuffled = shuffled.as_string::<i32>();
+        assert_eq!(shuffled.null_count(), array.null_count());
+        assert_ne!(shuffled, &array);
+
+        let mut values: Vec<_> = shuffled.iter().collect();
+        values.sort();
+        let mut expected: Vec<_> = array.iter().collect();
+        expected.sort();
+        assert_eq!(values, expected);
+
+        // Seeds are reproducible
+        assert_eq!(
+            &shuffle(&array, 11).unwrap(),
+            &(Arc::new(shuffled.clone()) as ArrayRef)
+        );
+        assert_ne!(&shuffle(&array, 12).unwrap(), &shuffle(&array, 11).unwrap());
+
+        assert_eq!(shuffle(&array.slice(0, 0), 0).unwrap().len(), 0);
+        assert_eq!(
+            shuffle(&array.slice(1, 1), 0).unwrap().as_ref(),
+            &array.slice(1, 1)
+        );
+    }
+
+    #[test]
+    fn test_shuffle_record_batch() {
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(Int32Array::from_iter_values(0..50)) as _),
+            (
+                "b",
+                Arc::new(StringArray::from_iter_values(
+                    (0..50).map(|i| format!("{i}")),
+                )) as _,
+            ),
+        ])
+        .unwrap();
+
+        let shuffled = shuffle_record_batch(&batch, 5).unwrap();
+        assert_eq!(shuffled.num_rows(), 50);
+        assert_eq!(shuffled.column(0), &shuffle(batch.column(0), 5).unwrap());
+        let a = shuffled.column(0).as_primitive::<Int32Type>();
+        let b = shuffled.column(1).as_string::<i32>();
+        assert!((0..50).all(|i| a.value(i).to_string() == b.value(i)));
+    }
+}
@@ -17,18 +17,20 @@
 
 //! Defines take kernel for [Array]
 
+use std::ops::Range;
 use std::sync::Arc;
 
+use crate::dictionary::compact_dictionary;
 use arrow_array::builder::{BufferBuilder, UInt32Builder};
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{
-    bit_util, ArrowNativeType, BooleanBuffer, Buffer, MutableBuffer, NullBuffer, OffsetBuffer,
-    ScalarBuffer,
+    bit_util, ArrowNativeType, BooleanBuffer, Buffer, MutableBuffer, NullBuffer, NullBufferBuilder,
+    OffsetBuffer, ScalarBuffer,
 };
 use arrow_data::ArrayDataBuilder;
-use arrow_schema::{ArrowError, DataType, FieldRef, UnionMode};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, UnionMode};
 
 use num::{One, Zero};
 
@@ -154,6 +156,103 @@ pub fn take_arrays(
         .collect()
 }
 
+/// Indices validated ahead of time for use with [`take_prepared`]
+///
+/// When taking the same indices from many arrays, such as the columns of a
+/// [`RecordBatch`], this avoids converting and bounds checking the indices for
+/// every array: the indices are converted to an unsigned type, and their maximum
+/// computed, once on construction.
+///
+/// ```
+/// # use arrow_array::{Int32Array, StringArray, UInt8Array, cast::AsArray};
+/// # use arrow_schema::ArrowError;
+/// # use arrow_select::take::{take_prepared, TakeIndices};
+/// let indices = TakeIndices::try_new(&UInt8Array::from(vec![Some(2), None, Some(0)])).unwrap();
+/// assert_eq!(indices.max_index(), Some(2));
+///
+/// let values = StringArray::from(vec!["zero", "one", "two"]);
+/// let taken = take_prepared(&values, &indices).unwrap();
+/// let expected = StringArray::from(vec![Some("two"), None, Some("zero")]);
+/// assert_eq!(taken.as_string::<i32>(), &expected);
+///
+/// let values = Int32Array::from(vec![0, 1]);
+/// assert!(matches!(take_prepared(&values, &indices), Err(ArrowError::ComputeError(_))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TakeIndices {
+    /// Either a [`UInt32Array`] or a [`UInt64Array`]
+    indices: ArrayRef,
+    max: Option<usize>,
+}
+
+impl TakeIndices {
+    /// Validates and converts `indices`, returning an error if they are not integers
+    /// or any non-null index cannot be converted to `usize`
+    pub fn try_new(indices: &dyn Array) -> Result<Self, ArrowError> {
+        downcast_integer_array!(
+            indices => {
+                let max = max_index(indices)?;
+                let indices = Arc::new(indices.to_indices());
+                Ok(Self { indices, max })
+            },
+            d => Err(ArrowError::InvalidArgumentError(format!("Take only supported for integers, got {d:?}")))
+        )
+    }
+
+    /// Returns the number of indices
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns true if there are no indices
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns the largest non-null index, or `None` if all indices are null
+    pub fn max_index(&self) -> Option<usize> {
+        self.max
+    }
+}
+
+/// Take elements by index from [Array] as per [`take`], using indices validated
+/// ahead of time by [`TakeIndices`]
+///
+/// # Errors
+/// This function errors if [`TakeIndices::max_index`] is out of bounds for `values`
+pub fn take_prepared(values: &dyn Array, indices: &TakeIndices) -> Result<ArrayRef, ArrowError> {
+    if let Some(max) = indices.max.filter(|max| *max >= values.len()) {
+        return Err(ArrowError::ComputeError(format!(
+            "Array index out of bounds, cannot get item at index {max} from {} entries",
+            values.len()
+        )));
+    }
+    match indices.indices.data_type() {
+        DataType::UInt32 => take_impl(values, indices.indices.as_primitive::<UInt32Type>()),
+        DataType::UInt64 => take_impl(values, indices.indices.as_primitive::<UInt64Type>()),
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the largest of the non-null values of `indices`
+fn max_index<T: ArrowPrimitiveType>(
+    indices: &PrimitiveArray<T>,
+) -> Result<Option<usize>, ArrowError> {
+    let mut max = None;
+    let mut update = |index: T::Native| {
+        max = max.max(Some(maybe_usize(index)?));
+        Ok::<_, ArrowError>(())
+    };
+    match indices.nulls().filter(|n| n.null_count() > 0) {
+        Some(nulls) => nulls.try_for_each_valid_idx(|i| update(indices.value(i)))?,
+        None => indices
+            .values()
+            .iter()
+            .try_for_each(|index| update(*index))?,
+    }
+    Ok(max)
+}
+
 /// Verifies that the non-null values of `indices` are all `< len`
 fn check_bounds<T: ArrowPrimitiveType>(
     len: usize,
@@ -296,7 +395,26 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
         DataType::Union(fields, UnionMode::Sparse) => {
             let mut children = Vec::with_capacity(fields.len());
             let values = values.as_any().downcast_ref::<UnionArray>().unwrap();
-            let type_ids = take_native(values.type_ids(), indices);
+            let type_ids = match indices.nulls().filter(|n| n.null_count() > 0) {
+                // A null index may be out of bounds, in which case the row is given
+                // the first type id, as `T::default()` may not be a valid type id
+                Some(n) => {
+                    let default_id = fields.iter().next().map(|(id, _)| id).unwrap_or_default();
+                    indices
+                        .values()
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, index)| match values.type_ids().get(index.as_usize()) {
+                            Some(v) => *v,
+                            None => match n.is_null(idx) {
+                                true => default_id,
+                                false => panic!("Out-of-bounds index {index:?}"),
+                            },
+                        })
+                        .collect()
+                }
+                None => take_native(values.type_ids(), indices),
+            };
             for (type_id, _field) in fields.iter() {
                 let values = values.child(type_id);
                 let values = take_impl(values, indices)?;
@@ -452,13 +570,51 @@ fn take_bits<I: ArrowPrimitiveType>(
 }
 
 /// `take` implementation for boolean arrays
+///
+/// Gathers the bits of `values` directly into the output bitmaps. If `values` contains
+/// nulls, the values and validity bitmaps are gathered in a single pass over `indices`,
+/// with null indices leaving both the value and validity bits unset.
 fn take_boolean<IndexType: ArrowPrimitiveType>(
     values: &BooleanArray,
     indices: &PrimitiveArray<IndexType>,
 ) -> BooleanArray {
-    let val_buf = take_bits(values.values(), indices);
-    let null_buf = take_nulls(values.nulls(), indices);
-    BooleanArray::new(val_buf, null_buf)
+    let value_nulls = match values.nulls().filter(|n| n.null_count() > 0) {
+        Some(n) => n,
+        None => {
+            let val_buf = take_bits(values.values(), indices);
+            return BooleanArray::new(val_buf, indices.nulls().cloned());
+        }
+    };
+
+    let len = indices.len();
+    let mut val_buf = MutableBuffer::new_null(len);
+    let mut null_buf = MutableBuffer::new_null(len);
+    let val_slice = val_buf.as_slice_mut();
+    let null_slice = null_buf.as_slice_mut();
+
+    let mut gather = |idx: usize, index: usize| {
+        if value_nulls.is_valid(index) {
+            bit_util::set_bit(null_slice, idx);
+            if values.values().value(index) {
+                bit_util::set_bit(val_slice, idx);
+            }
+        }
+    };
+
+    match indices.nulls().filter(|n| n.null_count() > 0) {
+        Some(index_nulls) => index_nulls
+            .valid_indices()
+            .for_each(|idx| gather(idx, indices.value(idx).as_usize())),
+        None => indices
+            .values()
+            .iter()
+            .enumerate()
+            .for_each(|(idx, index)| gather(idx, index.as_usize())),
+    }
+
+    let val_buf = BooleanBuffer::new(val_buf.into(), 0, len);
+    let null_buf = NullBuffer::new(BooleanBuffer::new(null_buf.into(), 0, len));
+    BooleanArray::new(val_buf, Some(null_buf).filter(|n| n.null_count() > 0))
 }
 
 /// `take` implementation for string arrays
@@ -742,6 +898,134 @@ fn take_run<T: RunEndIndexType, I: ArrowPrimitiveType>(
     Ok(array_data.into())
 }
 
+/// Take elements by index from [Array] as per [`take`], returning a [`RunArray`] that
+/// coalesces consecutive repeated indices into a single run
+///
+/// This saves memory when indices repeat consecutively, e.g. sorted indices such as
+/// `[0, 0, 1, 1, 1, 2]`, as each value is only taken once per run. Consecutive null
+/// indices form a single null run.
+///
+/// # Errors
+/// This function errors if `indices` are not integers, the number of indices does
+/// not fit in `R`, or under the same conditions as [`take`]
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, Int32Array, StringArray, UInt32Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::take::take_run_output;
+/// let values = StringArray::from(vec!["a", "b", "c"]);
+/// let indices = UInt32Array::from(vec![0, 0, 1, 1, 1, 2]);
+/// let taken = take_run_output::<Int32Type>(&values, &indices).unwrap();
+/// assert_eq!(taken.len(), 6);
+/// assert_eq!(taken.run_ends().values(), &[2, 5, 6]);
+/// assert_eq!(taken.values().as_string::<i32>(), &values);
+/// ```
+pub fn take_run_output<R: RunEndIndexType>(
+    values: &dyn Array,
+    indices: &dyn Array,
+) -> Result<RunArray<R>, ArrowError> {
+    downcast_integer_array!(
+        indices => take_run_output_impl(values, indices),
+        d => Err(ArrowError::InvalidArgumentError(format!("Take only supported for integers, got {d:?}")))
+    )
+}
+
+fn take_run_output_impl<R: RunEndIndexType, I: ArrowPrimitiveType>(
+    values: &dyn Array,
+    indices: &PrimitiveArray<I>,
+) -> Result<RunArray<R>, ArrowError> {
+    let run_end = |end: usize| {
+        R::Native::from_usize(end).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "take_run_output of {} indices overflows run end type {}",
+                indices.len(),
+                R::DATA_TYPE
+            ))
+        })
+    };
+
+    let mut run_ends = Vec::new();
+    let mut run_indices = Vec::new();
+    let mut iter = indices.iter().enumerate();
+    if let Some((_, first)) = iter.next() {
+        let mut current = first;
+        for (i, index) in iter {
+            if index != current {
+                run_ends.push(run_end(i)?);
+                run_indices.push(current);
+                current = index;
+            }
+        }
+        run_ends.push(run_end(indices.len())?);
+        run_indices.push(current);
+    }
+
+    let taken = take(values, &PrimitiveArray::<I>::from_iter(run_indices), None)?;
+    RunArray::try_new(&PrimitiveArray::<R>::new(run_ends.into(), None), &taken)
+}
+
+/// Takes elements by index from a [`RunArray`], returning a plain array of the
+/// run array's values type instead of a [`RunArray`]
+///
+/// Each index is resolved to its run with a binary search of the run ends, so
+/// `indices` need not be sorted, and the run array is never fully decoded. Null
+/// indices produce null values.
+///
+/// # Errors
+/// This function errors if `values` is not a [`RunArray`], `indices` are not integers,
+/// or an index is out of bounds
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, Int32Array, RunArray, StringArray, UInt32Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::take::take_run_decoded;
+/// let run_ends = Int32Array::from(vec![2, 3, 6]);
+/// let values = StringArray::from(vec!["a", "b", "c"]);
+/// let array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+///
+/// let indices = UInt32Array::from(vec![Some(5), None, Some(0), Some(2)]);
+/// let taken = take_run_decoded(&array, &indices).unwrap();
+/// let expected = StringArray::from(vec![Some("c"), None, Some("a"), Some("b")]);
+/// assert_eq!(taken.as_string::<i32>(), &expected);
+/// ```
+pub fn take_run_decoded(values: &dyn Array, indices: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    downcast_run_array!(
+        values => downcast_integer_array!(
+            indices => take_run_decoded_impl(values, indices),
+            d => Err(ArrowError::InvalidArgumentError(format!("Take only supported for integers, got {d:?}")))
+        ),
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "take_run_decoded requires a run array, got {t}"
+        )))
+    )
+}
+
+fn take_run_decoded_impl<T: RunEndIndexType, I: ArrowPrimitiveType>(
+    run_array: &RunArray<T>,
+    indices: &PrimitiveArray<I>,
+) -> Result<ArrayRef, ArrowError> {
+    let len = run_array.len();
+    let physical_indices = indices
+        .iter()
+        .map(|index| {
+            let Some(index) = index else {
+                return Ok(None);
+            };
+            match index.to_usize().filter(|i| *i < len) {
+                Some(i) => Ok(Some(run_array.get_physical_index(i) as u64)),
+                None => Err(ArrowError::InvalidArgumentError(format!(
+                    "Array index out of bounds, cannot get item at index {index:?} from {len} entries"
+                ))),
+            }
+        })
+        .collect::<Result<UInt64Array, _>>()?;
+    take(run_array.values(), &physical_indices, None)
+}
+
 /// Takes/filters a list array's inner data using the offsets of the list array.
 ///
 /// Where a list array has indices `[0,2,5,10]`, taking indices of `[2,0]` returns
@@ -954,13 +1238,346 @@ pub fn take_record_batch(
     RecordBatch::try_new(record_batch.schema(), columns)
 }
 
+/// Returns a zero-copy slice of `len` rows of `record_batch` starting at `offset`
+///
+/// Unlike [`RecordBatch::slice`], this does not panic if `offset + len` exceeds the
+/// number of rows, and instead clamps the slice to the rows of `record_batch`,
+/// returning an empty [`RecordBatch`] if `offset` is past the end.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Int32Array, RecordBatch};
+/// # use arrow_select::take::slice_record_batch;
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as _),
+/// ])
+/// .unwrap();
+///
+/// let sliced = slice_record_batch(&batch, 2, 10);
+/// assert_eq!(sliced.num_rows(), 2);
+///
+/// let sliced = slice_record_batch(&batch, 5, 1);
+/// assert_eq!(sliced.num_rows(), 0);
+/// ```
+pub fn slice_record_batch(record_batch: &RecordBatch, offset: usize, len: usize) -> RecordBatch {
+    let num_rows = record_batch.num_rows();
+    let offset = offset.min(num_rows);
+    let len = len.min(num_rows - offset);
+    record_batch.slice(offset, len)
+}
+
+/// Take elements by index from [Array], packaging every `group_size` consecutive
+/// taken values into a row of a [`FixedSizeListArray`]
+///
+/// This is equivalent to calling [`take`] followed by reshaping the result into
+/// a list of `indices.len() / group_size` rows, each containing `group_size` values,
+/// and is useful for constructing fixed-size windows over `values`.
+///
+/// # Errors
+/// This function errors whenever:
+/// * `group_size` is zero or does not fit in an `i32`
+/// * `indices.len()` is not a multiple of `group_size`
+/// * [`take`] returns an error
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, Int32Array, UInt32Array, cast::AsArray, types::Int32Type};
+/// # use arrow_select::take::take_reshape;
+/// let values = Int32Array::from(vec![10, 20, 30, 40]);
+/// let indices = UInt32Array::from(vec![0, 1, 2, 1, 2, 3]);
+/// let windows = take_reshape(&values, &indices, 3).unwrap();
+///
+/// assert_eq!(windows.len(), 2);
+/// assert_eq!(windows.value(1).as_primitive::<Int32Type>().values(), &[20, 30, 40]);
+/// ```
+pub fn take_reshape(
+    values: &dyn Array,
+    indices: &dyn Array,
+    group_size: usize,
+) -> Result<FixedSizeListArray, ArrowError> {
+    let size = match i32::try_from(group_size) {
+        Ok(size) if size > 0 => size,
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "take_reshape group size must be between 1 and {}, got {group_size}",
+                i32::MAX
+            )))
+        }
+    };
+    if indices.len() % group_size != 0 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "take_reshape indices length {} is not a multiple of group size {group_size}",
+            indices.len()
+        )));
+    }
+
+    let taken = take(values, indices, None)?;
+    let field = Arc::new(Field::new_list_field(values.data_type().clone(), true));
+    FixedSizeListArray::try_new(field, size, taken, None)
+}
+
+/// Takes `count` elements of `values` at regularly spaced positions `start`,
+/// `start + stride`, ..., `start + (count - 1) * stride`
+///
+/// This is equivalent to calling [`take`] with these positions as indices, but a
+/// slice of `values` is returned when `stride` is `1`, and no indices are constructed
+/// for primitive arrays.
+///
+/// # Errors
+/// This function errors if `stride` is zero, or the last position is out of bounds
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, Int32Array};
+/// # use arrow_select::take::take_strided;
+/// let values = Int32Array::from(vec![0, 1, 2, 3, 4, 5, 6]);
+/// let taken = take_strided(&values, 1, 2, 3).unwrap();
+/// assert_eq!(taken.as_ref(), &Int32Array::from(vec![1, 3, 5]));
+///
+/// assert!(take_strided(&values, 1, 2, 4).is_err());
+/// ```
+pub fn take_strided(
+    values: &dyn Array,
+    start: usize,
+    stride: usize,
+    count: usize,
+) -> Result<ArrayRef, ArrowError> {
+    if stride == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "take_strided stride must be greater than 0".to_string(),
+        ));
+    }
+    if count == 0 {
+        return Ok(values.slice(0, 0));
+    }
+
+    let len = values.len();
+    let last = (count - 1)
+        .checked_mul(stride)
+        .and_then(|o| o.checked_add(start))
+        .filter(|last| *last < len);
+    if last.is_none() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "take_strided of {count} elements with stride {stride} from {start} is out of bounds for array of length {len}"
+        )));
+    }
+
+    if stride == 1 {
+        return Ok(values.slice(start, count));
+    }
+
+    downcast_primitive_array! {
+        values => Ok(Arc::new(take_strided_primitive(values, start, stride, count))),
+        _ => {
+            let indices = (0..count).map(|i| (start + i * stride) as u64);
+            take(values, &UInt64Array::from_iter_values(indices), None)
+        }
+    }
+}
+
+fn take_strided_primitive<T: ArrowPrimitiveType>(
+    values: &PrimitiveArray<T>,
+    start: usize,
+    stride: usize,
+    count: usize,
+) -> PrimitiveArray<T> {
+    let native = values.values();
+    let buffer: ScalarBuffer<T::Native> = (0..count).map(|i| native[start + i * stride]).collect();
+    let nulls = values.nulls().map(|nulls| {
+        NullBuffer::new(BooleanBuffer::collect_bool(count, |i| {
+            nulls.is_valid(start + i * stride)
+        }))
+    });
+    PrimitiveArray::new(buffer, nulls).with_data_type(values.data_type().clone())
+}
+
+/// Takes elements of `values` at the `indices` for which `keep` is true, in order
+///
+/// This is equivalent to `take(values, &filter(indices, keep)?, None)`, see
+/// [`crate::filter::filter`], where a null in `keep` drops the index. For primitive
+/// values the kept indices are gathered directly, without first filtering `indices`
+/// into an intermediate array.
+///
+/// # Errors
+/// This function errors if `keep` does not have the same length as `indices`, or
+/// under the same conditions as [`take`]
+///
+/// # Panics
+/// Taking an index that is out of bounds of `values` panics, as per [`take`]
+///
+/// # Example
+/// ```
+/// # use arrow_array::{BooleanArray, StringArray, UInt32Array};
+/// # use arrow_select::take::take_filtered;
+/// let values = StringArray::from(vec!["a", "b", "c", "d"]);
+/// let indices = UInt32Array::from(vec![3, 0, 2, 1]);
+/// let keep = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+/// let taken = take_filtered(&values, &indices, &keep).unwrap();
+/// assert_eq!(taken.as_ref(), &StringArray::from(vec!["d", "b"]));
+/// ```
+pub fn take_filtered(
+    values: &dyn Array,
+    indices: &dyn Array,
+    keep: &BooleanArray,
+) -> Result<ArrayRef, ArrowError> {
+    if keep.len() != indices.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "take_filtered requires a keep mask of the same length as the indices, got {} and {}",
+            keep.len(),
+            indices.len()
+        )));
+    }
+    if !values.data_type().is_primitive() {
+        let indices = crate::filter::filter(indices, keep)?;
+        return take(values, &indices, None);
+    }
+
+    let keep = match keep.nulls() {
+        Some(nulls) if nulls.null_count() > 0 => keep.values() & nulls.inner(),
+        _ => keep.values().clone(),
+    };
+    downcast_integer_array!(
+        indices => {
+            let indices = indices.to_indices();
+            downcast_primitive_array! {
+                values => Ok(Arc::new(take_filtered_primitive(values, &indices, &keep)?)),
+                _ => unreachable!("primitive type"),
+            }
+        },
+        d => Err(ArrowError::InvalidArgumentError(format!("Take only supported for integers, got {d:?}")))
+    )
+}
+
+fn take_filtered_primitive<T: ArrowPrimitiveType, I: ArrowPrimitiveType>(
+    values: &PrimitiveArray<T>,
+    indices: &PrimitiveArray<I>,
+    keep: &BooleanBuffer,
+) -> Result<PrimitiveArray<T>, ArrowError> {
+    let count = keep.count_set_bits();
+    let native = values.values();
+    let mut buffer = Vec::with_capacity(count);
+    let mut nulls = NullBufferBuilder::new(count);
+    for i in keep.set_indices() {
+        if indices.is_null(i) {
+            buffer.push(T::Native::default());
+            nulls.append_null();
+            continue;
+        }
+        let index = maybe_usize(indices.value(i))?;
+        buffer.push(native[index]);
+        nulls.append(values.is_valid(index));
+    }
+    Ok(PrimitiveArray::new(buffer.into(), nulls.finish())
+        .with_data_type(values.data_type().clone()))
+}
+
+/// Flattens the elements of the lists of `list` and keeps those for which the
+/// corresponding value of `mask` is true
+///
+/// The flattened elements are those referenced by the offsets of `list`, in order,
+/// including any elements underlying null lists. A null in `mask` drops the element.
+///
+/// # Errors
+/// This function errors if `mask` does not have the same length as the flattened
+/// elements
+///
+/// # Example
+/// ```
+/// # use arrow_array::{BooleanArray, Int32Array, ListArray};
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::take::take_exploded;
+/// let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), Some(2)]),
+///     Some(vec![]),
+///     Some(vec![Some(3)]),
+/// ]);
+/// let mask = BooleanArray::from(vec![false, true, true]);
+/// let taken = take_exploded(&list, &mask).unwrap();
+/// assert_eq!(taken.as_ref(), &Int32Array::from(vec![2, 3]));
+/// ```
+pub fn take_exploded<O: OffsetSizeTrait>(
+    list: &GenericListArray<O>,
+    mask: &BooleanArray,
+) -> Result<ArrayRef, ArrowError> {
+    let offsets = list.offsets();
+    let start = offsets[0].as_usize();
+    let len = offsets[list.len()].as_usize() - start;
+    if mask.len() != len {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "take_exploded requires a mask of the same length as the flattened list elements, got {} and {len}",
+            mask.len()
+        )));
+    }
+    let values = list.values().slice(start, len);
+    crate::filter::filter(values.as_ref(), mask)
+}
+
+/// Takes elements of `dict` at `indices` as per [`take`], additionally removing the
+/// dictionary values not referenced by the taken keys
+///
+/// The retained dictionary values keep their relative order. This is useful after
+/// selecting a small number of rows from a large dictionary, to avoid retaining and
+/// later writing dictionary values that are no longer used.
+///
+/// # Errors
+/// This function errors under the same conditions as [`take`]
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, DictionaryArray, UInt32Array};
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::take::take_dict_gc;
+/// let dict: DictionaryArray<Int32Type> = vec!["a", "b", "c", "d"].into_iter().collect();
+/// let indices = UInt32Array::from(vec![Some(3), None, Some(1), Some(3)]);
+/// let taken = take_dict_gc(&dict, &indices).unwrap();
+/// assert_eq!(taken.values().len(), 2);
+/// assert_eq!(taken.keys(), &vec![Some(1), None, Some(0), Some(1)].into());
+/// ```
+pub fn take_dict_gc<K: ArrowDictionaryKeyType>(
+    dict: &DictionaryArray<K>,
+    indices: &dyn Array,
+) -> Result<DictionaryArray<K>, ArrowError> {
+    let taken = take(dict, indices, None)?;
+    compact_dictionary(taken.as_dictionary::<K>())
+}
+
+/// Takes the elements of `values` within `range`, equivalent to [`take`] with the
+/// indices of `range`
+///
+/// This does not copy any data, and returns a slice of `values` as per [`Array::slice`]
+///
+/// # Errors
+/// This function errors if `range` is decreasing or ends past the end of `values`
+///
+/// # Example
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_select::take::take_range;
+/// let values = Int32Array::from(vec![1, 2, 3, 4]);
+/// let taken = take_range(&values, 1..3).unwrap();
+/// assert_eq!(taken.as_ref(), &Int32Array::from(vec![2, 3]));
+/// ```
+pub fn take_range(values: &dyn Array, range: Range<usize>) -> Result<ArrayRef, ArrowError> {
+    if range.start > range.end || range.end > values.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "take_range requires a range within the {} values, got {}..{}",
+            values.len(),
+            range.start,
+            range.end
+        )));
+    }
+    Ok(values.slice(range.start, range.len()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use arrow_array::builder::*;
-    use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano};
+    use arrow_buffer::{i256, IntervalDayTime, IntervalMonthDayNano};
     use arrow_data::ArrayData;
-    use arrow_schema::{Field, Fields, TimeUnit, UnionFields};
+    use arrow_schema::{Fields, TimeUnit, UnionFields};
+    use rand::prelude::*;
 
     fn test_take_decimal_arrays(
         data: Vec<Option<i128>>,
@@ -1111,6 +1728,40 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn test_take_decimal256() {
+        let values = vec![
+            Some(i256::MAX),
+            None,
+            Some(i256::from_parts(u128::MAX, -1)),
+            Some(i256::MIN),
+            Some(i256::from_i128(-42)),
+            Some(i256::from_parts(1, i128::MAX)),
+        ];
+        let array = Decimal256Array::from(values.clone())
+            .with_precision_and_scale(76, 10)
+            .unwrap();
+
+        let index = UInt32Array::from(vec![Some(5), None, Some(0), Some(1), Some(3), Some(5)]);
+        let taken = take(&array, &index, None).unwrap();
+        assert_eq!(taken.data_type(), &DataType::Decimal256(76, 10));
+        let expected = vec![values[5], None, values[0], None, values[3], values[5]];
+        let expected = Decimal256Array::from(expected)
+            .with_precision_and_scale(76, 10)
+            .unwrap();
+        assert_eq!(taken.as_primitive::<Decimal256Type>(), &expected);
+
+        // Sliced values
+        let sliced = array.slice(2, 4);
+        let index = UInt64Array::from(vec![Some(3), Some(0), None, Some(2)]);
+        let taken = take(&sliced, &index, None).unwrap();
+        let expected = vec![values[5], values[2], None, values[4]];
+        let expected = Decimal256Array::from(expected)
+            .with_precision_and_scale(76, 10)
+            .unwrap();
+        assert_eq!(taken.as_primitive::<Decimal256Type>(), &expected);
+    }
+
     #[test]
     fn test_take_primitive_non_null_indices() {
         let index = UInt32Array::from(vec![0, 5, 3, 1, 4, 2]);
@@ -1519,6 +2170,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_take_bool_null_indices_sliced_values() {
+        let values: BooleanArray = (0..300)
+            .map(|i| (i % 7 != 0).then_some(i % 3 == 0))
+            .collect();
+        let values = values.slice(5, 290);
+        let indices: UInt32Array = (0..200_u32)
+            .map(|i| (i % 5 != 0).then_some((i * 37) % 290))
+            .collect();
+
+        let taken = take(&values, &indices, None).unwrap();
+        let taken = taken.as_boolean();
+
+        let expected: BooleanArray = indices
+            .iter()
+            .map(|index| index.and_then(|index| values.iter().nth(index as usize).unwrap()))
+            .collect();
+        assert_eq!(taken, &expected);
+        assert_eq!(taken.null_count(), expected.null_count());
+    }
+
     fn _test_take_string<'a, K>()
     where
         K: Array + PartialEq + From<Vec<Option<&'a str>>> + 'static,
@@ -2177,6 +2849,325 @@ mod tests {
         assert_eq!(null_buf.as_slice(), &[0b11111111]);
     }
 
+    #[test]
+    fn test_take_run_decoded() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let logical = (0..200)
+            .map(|i| (i % 7 != 0).then_some(i / 10))
+            .collect::<Vec<_>>();
+        let mut builder = PrimitiveRunBuilder::<Int16Type, Int32Type>::new();
+        builder.extend(logical.iter().copied());
+        let run_array = builder.finish();
+
+        let indices = (0..500)
+            .map(|_| rng.random_bool(0.8).then(|| rng.random_range(0..200)))
+            .collect::<Vec<Option<u32>>>();
+        let taken = take_run_decoded(&run_array, &UInt32Array::from(indices.clone())).unwrap();
+        let expected = indices
+            .iter()
+            .map(|i| i.and_then(|i| logical[i as usize]))
+            .collect::<Int32Array>();
+        assert_eq!(taken.as_primitive::<Int32Type>(), &expected);
+
+        // Sliced run arrays
+        let sliced = run_array.slice(15, 100);
+        let indices = Int64Array::from(vec![Some(99), None, Some(0), Some(50), Some(0)]);
+        let taken = take_run_decoded(&sliced, &indices).unwrap();
+        let expected = Int32Array::from(vec![
+            logical[114],
+            None,
+            logical[15],
+            logical[65],
+            logical[15],
+        ]);
+        assert_eq!(taken.as_primitive::<Int32Type>(), &expected);
+
+        let err = take_run_decoded(&sliced, &Int64Array::from(vec![100])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Array index out of bounds, cannot get item at index 100 from 100 entries"
+        );
+
+        let err = take_run_decoded(&expected, &Int64Array::from(vec![0])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: take_run_decoded requires a run array, got Int32"
+        );
+    }
+
+    #[test]
+    fn test_slice_record_batch() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from_iter_values(0..10)) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from_iter(
+                    (0..10).map(|i| (i % 3 != 0).then(|| i.to_string())),
+                )) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        // In range
+        let sliced = slice_record_batch(&batch, 2, 5);
+        assert_eq!(sliced, batch.slice(2, 5));
+        let a = sliced.column(0).as_primitive::<Int32Type>();
+        assert_eq!(a.values(), &[2, 3, 4, 5, 6]);
+        assert_eq!(sliced.column(1).null_count(), 2);
+
+        // Over-length slices are clamped
+        let sliced = slice_record_batch(&batch, 7, 10);
+        assert_eq!(sliced, batch.slice(7, 3));
+        let sliced = slice_record_batch(&batch, 0, usize::MAX);
+        assert_eq!(sliced, batch);
+
+        // Offsets past the end return an empty batch
+        let sliced = slice_record_batch(&batch, 10, 1);
+        assert_eq!(sliced.num_rows(), 0);
+        assert_eq!(sliced.schema(), batch.schema());
+        let sliced = slice_record_batch(&batch, usize::MAX, usize::MAX);
+        assert_eq!(sliced.num_rows(), 0);
+    }
+
+    #[test]
+    fn test_take_strided() {
+        let values = Int64Array::from_iter((0..10).map(|i| (i % 4 != 0).then_some(i)));
+        let strings = StringArray::from_iter((0..10).map(|i| (i % 3 != 0).then(|| i.to_string())));
+
+        // Stride 1 is a contiguous slice
+        let taken = take_strided(&values, 2, 1, 5).unwrap();
+        assert_eq!(taken.as_ref(), &values.slice(2, 5));
+        let taken = take_strided(&strings, 9, 1, 1).unwrap();
+        assert_eq!(taken.as_ref(), &strings.slice(9, 1));
+
+        // Stride 2 gathers every other element
+        let taken = take_strided(&values, 1, 2, 5).unwrap();
+        let expected = Int64Array::from(vec![Some(1), Some(3), Some(5), Some(7), Some(9)]);
+        assert_eq!(taken.as_primitive::<Int64Type>(), &expected);
+        let taken = take_strided(&values, 0, 2, 5).unwrap();
+        let expected = Int64Array::from(vec![None, Some(2), None, Some(6), None]);
+        assert_eq!(taken.as_primitive::<Int64Type>(), &expected);
+
+        let taken = take_strided(&strings, 0, 3, 4).unwrap();
+        let expected = StringArray::from(vec![None::<&str>, None, None, None]);
+        assert_eq!(taken.as_string::<i32>(), &expected);
+        let taken = take_strided(&strings, 2, 5, 2).unwrap();
+        let expected = StringArray::from(vec!["2", "7"]);
+        assert_eq!(taken.as_string::<i32>(), &expected);
+
+        // Sliced inputs and data types are preserved
+        let decimals = Decimal128Array::from_iter_values(0..10)
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let taken = take_strided(&decimals.slice(3, 7), 1, 3, 2).unwrap();
+        let expected = Decimal128Array::from(vec![4, 7])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        assert_eq!(taken.as_primitive::<Decimal128Type>(), &expected);
+
+        let taken = take_strided(&values, 20, 3, 0).unwrap();
+        assert!(taken.is_empty());
+    }
+
+    #[test]
+    fn test_take_strided_invalid() {
+        let values = Int32Array::from(vec![1, 2, 3, 4, 5]);
+
+        let err = take_strided(&values, 0, 2, 4).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: take_strided of 4 elements with stride 2 from 0 is out of bounds for array of length 5"
+        );
+        assert!(take_strided(&values, 5, 1, 1).is_err());
+        assert!(take_strided(&values, 1, usize::MAX, 2).is_err());
+
+        let err = take_strided(&values, 0, 0, 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: take_strided stride must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn test_take_dict_gc() {
+        let values = StringArray::from_iter_values((0..100).map(|i| format!("v{i}")));
+        let keys = Int16Array::from_iter_values((0..300).map(|i| (i * 7 % 100) as i16));
+        let dict = DictionaryArray::new(keys, Arc::new(values));
+
+        // Rows 10 and 110 have key 70, and rows 3 and 203 have key 21
+        let indices = UInt32Array::from(vec![Some(110), Some(3), None, Some(10), Some(203)]);
+        let taken = take_dict_gc(&dict, &indices).unwrap();
+        assert_eq!(taken.values().len(), 2);
+        assert_eq!(
+            taken.values().as_string::<i32>(),
+            &StringArray::from(vec!["v21", "v70"])
+        );
+        assert_eq!(
+            taken.keys(),
+            &Int16Array::from(vec![Some(1), Some(0), None, Some(1), Some(0)])
+        );
+        taken.to_data().validate_full().unwrap();
+
+        // The result is logically equal to take
+        let expected = take(&dict, &indices, None).unwrap();
+        let strings = |d: &DictionaryArray<Int16Type>| {
+            let d = d.downcast_dict::<StringArray>().unwrap();
+            d.into_iter()
+                .map(|v| v.map(String::from))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(strings(&taken), strings(expected.as_dictionary()));
+
+        // Nothing is referenced
+        let indices = UInt32Array::from(vec![None, None]);
+        let taken = take_dict_gc(&dict, &indices).unwrap();
+        assert_eq!(taken.values().len(), 0);
+        assert_eq!(taken.null_count(), 2);
+    }
+
+    #[test]
+    fn test_take_exploded() {
+        let mut builder = ListBuilder::new(Int32Builder::new());
+        for row in [
+            Some(vec![Some(0), Some(1), Some(2)]),
+            Some(vec![]),
+            None,
+            Some(vec![Some(3), None]),
+            Some(vec![Some(4)]),
+            Some(vec![Some(5), Some(6), Some(7), Some(8)]),
+        ] {
+            builder.append_option(row);
+        }
+        let list = builder.finish();
+
+        let mask = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            Some(false),
+            Some(false),
+            Some(true),
+        ]);
+        let taken = take_exploded(&list, &mask).unwrap();
+        let expected = Int32Array::from(vec![Some(0), Some(2), Some(3), None, Some(8)]);
+        assert_eq!(taken.as_primitive::<Int32Type>(), &expected);
+
+        // Sliced lists only flatten their own elements
+        let sliced = list.slice(3, 2);
+        let mask = BooleanArray::from(vec![false, true, true]);
+        let taken = take_exploded(&sliced, &mask).unwrap();
+        let expected = Int32Array::from(vec![None, Some(4)]);
+        assert_eq!(taken.as_primitive::<Int32Type>(), &expected);
+
+        let err = take_exploded(&sliced, &BooleanArray::from(vec![true])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: take_exploded requires a mask of the same length as the flattened list elements, got 1 and 3"
+        );
+    }
+
+    #[test]
+    fn test_take_filtered() {
+        let indices = Int64Array::from(vec![
+            Some(4),
+            Some(0),
+            None,
+            Some(2),
+            Some(2),
+            None,
+            Some(1),
+        ]);
+        let keep = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            Some(true),
+            None,
+            Some(true),
+            Some(false),
+            Some(true),
+        ]);
+        let expected = |values: &dyn Array, indices: &dyn Array, keep: &BooleanArray| {
+            let filtered = crate::filter::filter(indices, keep).unwrap();
+            take(values, &filtered, None).unwrap()
+        };
+
+        let values = Int32Array::from(vec![Some(10), None, Some(30), Some(40), Some(50)]);
+        let taken = take_filtered(&values, &indices, &keep).unwrap();
+        assert_eq!(
+            taken.as_ref(),
+            &Int32Array::from(vec![Some(50), None, Some(30), None])
+        );
+        assert_eq!(&taken, &expected(&values, &indices, &keep));
+
+        // Sliced arguments
+        let (values, indices, keep) = (values.slice(1, 4), indices.slice(1, 6), keep.slice(1, 6));
+        let indices = Int64Array::from_iter(indices.iter().map(|i| i.map(|i| i.min(3))));
+        let taken = take_filtered(&values, &indices, &keep).unwrap();
+        assert_eq!(&taken, &expected(&values, &indices, &keep));
+
+        let values = TimestampSecondArray::from(vec![1, 2, 3]).with_timezone("+01:00");
+        let indices = UInt8Array::from(vec![2, 1, 0]);
+        let keep = BooleanArray::from(vec![true, false, true]);
+        let taken = take_filtered(&values, &indices, &keep).unwrap();
+        assert_eq!(taken.data_type(), values.data_type());
+        assert_eq!(&taken, &expected(&values, &indices, &keep));
+
+        // Other types filter the indices first
+        let values = StringArray::from(vec![Some("a"), None, Some("c")]);
+        let taken = take_filtered(&values, &indices, &keep).unwrap();
+        assert_eq!(taken.as_ref(), &StringArray::from(vec!["c", "a"]));
+
+        let err = take_filtered(&values, &indices, &keep.slice(0, 2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: take_filtered requires a keep mask of the same length as the indices, got 2 and 3"
+        );
+    }
+
+    #[test]
+    fn test_take_run_output() {
+        let values = Int32Array::from(vec![Some(10), None, Some(30)]);
+        let indices = UInt32Array::from(vec![
+            Some(0),
+            Some(0),
+            Some(1),
+            Some(1),
+            Some(1),
+            None,
+            None,
+            Some(2),
+            Some(0),
+        ]);
+        let taken = take_run_output::<Int16Type>(&values, &indices).unwrap();
+        assert_eq!(taken.len(), 9);
+        assert_eq!(taken.run_ends().values(), &[2, 5, 7, 8, 9]);
+        let expected = Int32Array::from(vec![Some(10), None, None, Some(30), Some(10)]);
+        assert_eq!(taken.values().as_primitive::<Int32Type>(), &expected);
+
+        // The logical values match take
+        let decoded = take_run_decoded(&taken, &UInt32Array::from_iter_values(0..9)).unwrap();
+        assert_eq!(&decoded, &take(&values, &indices, None).unwrap());
+
+        let taken =
+            take_run_output::<Int32Type>(&values, &UInt32Array::from(Vec::<u32>::new())).unwrap();
+        assert_eq!(taken.len(), 0);
+        assert_eq!(taken.values().len(), 0);
+
+        let indices = UInt32Array::from(vec![0; 40000]);
+        let err = take_run_output::<Int16Type>(&values, &indices).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: take_run_output of 40000 indices overflows run end type Int16"
+        );
+    }
+
     #[test]
     fn test_take_runs() {
         let logical_array: Vec<i32> = vec![1_i32, 1, 2, 2, 1, 1, 1, 2, 2, 1, 1, 2, 2];
@@ -2308,6 +3299,50 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn test_take_union_sparse_mixed_type_ids() {
+        let ints = Int32Array::from(vec![Some(1), None, Some(3), Some(4), Some(5)]);
+        let strings = StringArray::from(vec![Some("a"), Some("b"), None, Some("d"), Some("e")]);
+        let type_ids = [2_i8, 5, 5, 2, 5].into_iter().collect::<ScalarBuffer<i8>>();
+
+        let union_fields = UnionFields::new(
+            vec![2, 5],
+            vec![
+                Field::new("i", DataType::Int32, true),
+                Field::new("s", DataType::Utf8, true),
+            ],
+        );
+        let children = vec![Arc::new(ints) as ArrayRef, Arc::new(strings)];
+        let array = UnionArray::try_new(union_fields, type_ids, None, children).unwrap();
+
+        let index = UInt32Array::from(vec![Some(4), Some(0), None, Some(1), Some(3), None]);
+        // Null slots may contain out-of-bounds values
+        let (_, values, nulls) = index.into_parts();
+        let mut values = values.to_vec();
+        values[5] = 100;
+        let index = UInt32Array::new(values.into(), nulls);
+
+        let actual = take(&array, &index, None).unwrap();
+        let actual = actual.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(actual.len(), 6);
+        assert_eq!(actual.type_ids(), &[5, 2, 2, 5, 2, 2]);
+        assert!(actual.offsets().is_none());
+
+        let ints = actual.child(2).as_primitive::<Int32Type>();
+        let expected = Int32Array::from(vec![Some(5), Some(1), None, None, Some(4), None]);
+        assert_eq!(ints, &expected);
+
+        let strings = actual.child(5).as_string::<i32>();
+        let expected =
+            StringArray::from(vec![Some("e"), Some("a"), None, Some("b"), Some("d"), None]);
+        assert_eq!(strings, &expected);
+
+        // Rows with a null index are null
+        let logical_nulls = actual.logical_nulls().unwrap();
+        let valid = logical_nulls.iter().collect::<Vec<_>>();
+        assert_eq!(valid, vec![true, true, false, true, true, false]);
+    }
+
     #[test]
     fn test_take_union_dense() {
         let type_ids = vec![0, 1, 1, 0, 0, 1, 0];
@@ -2411,4 +3446,140 @@ mod tests {
         let array = take(&array, &indicies, None).unwrap();
         assert_eq!(array.len(), 3);
     }
+
+    #[test]
+    fn test_take_reshape() {
+        let values = Int32Array::from(vec![Some(0), Some(1), None, Some(3), Some(4)]);
+        let indices = UInt32Array::from(vec![4, 0, 2, 1, 1, 3]);
+        let reshaped = take_reshape(&values, &indices, 3).unwrap();
+
+        assert_eq!(
+            reshaped.data_type(),
+            &DataType::FixedSizeList(Arc::new(Field::new_list_field(DataType::Int32, true)), 3)
+        );
+        assert_eq!(reshaped.len(), 2);
+        assert_eq!(reshaped.null_count(), 0);
+        assert_eq!(
+            reshaped.value(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![Some(4), Some(0), None])
+        );
+        assert_eq!(
+            reshaped.value(1).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![1, 1, 3])
+        );
+
+        let empty = take_reshape(&values, &UInt32Array::from(Vec::<u32>::new()), 3).unwrap();
+        assert_eq!(empty.len(), 0);
+    }
+
+    #[test]
+    fn test_take_reshape_invalid_group_size() {
+        let values = Int32Array::from(vec![0, 1, 2]);
+        let indices = UInt32Array::from(vec![0, 1, 2, 1]);
+
+        let err = take_reshape(&values, &indices, 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: take_reshape indices length 4 is not a multiple of group size 3"
+        );
+
+        let err = take_reshape(&values, &indices, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: take_reshape group size must be between 1 and 2147483647, got 0"
+        );
+    }
+
+    #[test]
+    fn test_take_prepared() {
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![
+                Some(1),
+                None,
+                Some(3),
+                Some(4),
+                Some(5),
+            ])),
+            Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e"])),
+            Arc::new(BooleanArray::from(vec![true, false, true, false, false])),
+        ];
+
+        for indices in [
+            Arc::new(Int8Array::from(vec![
+                Some(4),
+                None,
+                Some(0),
+                Some(4),
+                Some(1),
+            ])) as ArrayRef,
+            Arc::new(UInt16Array::from(vec![3, 2, 1])),
+            Arc::new(Int64Array::from(vec![None, Some(2)])),
+            Arc::new(UInt64Array::from(Vec::<u64>::new())),
+        ] {
+            let prepared = TakeIndices::try_new(&indices).unwrap();
+            assert_eq!(prepared.len(), indices.len());
+            for column in &columns {
+                let expected = take(column, &indices, None).unwrap();
+                let actual = take_prepared(column, &prepared).unwrap();
+                assert_eq!(actual.as_ref(), expected.as_ref());
+            }
+        }
+    }
+
+    #[test]
+    fn test_take_prepared_bounds() {
+        let indices =
+            TakeIndices::try_new(&Int32Array::from(vec![Some(1), None, Some(3)])).unwrap();
+        assert_eq!(indices.max_index(), Some(3));
+
+        let values = Int32Array::from(vec![1, 2, 3]);
+        let err = take_prepared(&values, &indices).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 3 from 3 entries"
+        );
+
+        let indices = TakeIndices::try_new(&Int32Array::from(vec![None, None])).unwrap();
+        assert_eq!(indices.max_index(), None);
+        let taken = take_prepared(&values, &indices).unwrap();
+        assert_eq!(taken.null_count(), 2);
+
+        let err = TakeIndices::try_new(&Int32Array::from(vec![0, -1])).unwrap_err();
+        assert_eq!(err.to_string(), "Compute error: Cast to usize failed");
+
+        let err = TakeIndices::try_new(&StringArray::from(vec!["a"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Take only supported for integers, got Utf8"
+        );
+    }
+
+    #[test]
+    fn test_take_range() {
+        let values = StringArray::from(vec![Some("a"), None, Some("c"), Some("d")]);
+        let taken = take_range(&values, 1..4).unwrap();
+        let expected = take(&values, &UInt32Array::from(vec![1, 2, 3]), None).unwrap();
+        assert_eq!(&taken, &expected);
+        // The values are not copied
+        assert_eq!(
+            taken.as_string::<i32>().values().as_ptr(),
+            values.values().as_ptr()
+        );
+
+        let taken = take_range(&values, 4..4).unwrap();
+        assert_eq!(taken.len(), 0);
+        assert_eq!(taken.data_type(), &DataType::Utf8);
+
+        let err = take_range(&values, 2..5).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: take_range requires a range within the 4 values, got 2..5"
+        );
+        #[allow(clippy::reversed_empty_ranges)]
+        let err = take_range(&values, 3..1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: take_range requires a range within the 4 values, got 3..1"
+        );
+    }
 }
@@ -17,16 +17,35 @@
 
 //! [`zip`]: Combine values from two arrays based on boolean mask
 
-use crate::filter::SlicesIterator;
+use arrow_array::cast::AsArray;
 use arrow_array::*;
+use arrow_buffer::bit_iterator::BitSliceIterator;
+use arrow_buffer::{BooleanBuffer, Buffer, MutableBuffer, NullBuffer, OffsetBuffer};
 use arrow_data::transform::MutableArrayData;
-use arrow_schema::ArrowError;
+use arrow_data::{layout, ArrayData};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, UnionFields};
+use std::sync::Arc;
 
 /// Zip two arrays by some boolean mask.
 ///
 /// - Where `mask` is `true`, values of `truthy` are taken
 /// - Where `mask` is `false` or `NULL`, values of `falsy` are taken
 ///
+/// Nested types are zipped recursively to arbitrary depth. An error is returned if
+/// the children of `truthy` and `falsy` do not have the same layout, or contain a
+/// data type that cannot be zipped, such as [`DataType::RunEndEncoded`].
+///
+/// Nested types that differ only in the nullability or metadata of their fields, or
+/// in the names of list items, are unified: the output fields are nullable if either
+/// input field is, and have the metadata of both. Conflicting metadata values, or
+/// differently named struct fields, cannot be unified and return an error.
+///
+/// The top-level output array has no offset, and its offsets, if any, start at zero,
+/// so writers that truncate sliced buffers, such as the Arrow IPC writer, can write its
+/// buffers as is. Lists that share their child values, and whose selected lists are
+/// contiguous in them, are zipped without copying the child values, in which case the
+/// child values of the output are a slice of the shared values and may have an offset.
+///
 /// # Example: `zip` two arrays
 /// ```
 /// # use std::sync::Arc;
@@ -86,89 +105,1106 @@ pub fn zip(
     truthy: &dyn Datum,
     falsy: &dyn Datum,
 ) -> Result<ArrayRef, ArrowError> {
+    if let Some((t, f)) = same_type_arrays(mask.values(), truthy, falsy) {
+        if let (Some(t), Some(f)) = (t.as_boolean_opt(), f.as_boolean_opt()) {
+            if t.values().ptr_eq(mask.values()) {
+                return Ok(Arc::new(zip_mask_or(mask, t, f)));
+            }
+        }
+        if t.data_type().is_primitive() {
+            if let Some(data) = zip_sparse_primitive(mask.values(), t, f) {
+                return Ok(make_array(data));
+            }
+            // Copying a slice costs about as much as blending tens of values
+            if set_slice_count(mask.values()) * 64 >= mask.len() {
+                return Ok(zip_blend_primitive(mask.values(), t, f));
+            }
+        }
+        let shared = match t.data_type() {
+            DataType::List(_) => zip_shared_list(mask.values(), t.as_list::<i32>(), f.as_list()),
+            DataType::LargeList(_) => {
+                zip_shared_list(mask.values(), t.as_list::<i64>(), f.as_list())
+            }
+            _ => None,
+        };
+        if let Some(shared) = shared {
+            return Ok(shared);
+        }
+    }
+    zip_datums(mask.values().set_slices(), mask.len(), truthy, falsy).map(make_array)
+}
+
+/// Returns `truthy` and `falsy` if they are arrays of the same data type and of
+/// the same length as `mask`
+fn same_type_arrays<'a>(
+    mask: &BooleanBuffer,
+    truthy: &'a dyn Datum,
+    falsy: &'a dyn Datum,
+) -> Option<(&'a dyn Array, &'a dyn Array)> {
     let (truthy, truthy_is_scalar) = truthy.get();
     let (falsy, falsy_is_scalar) = falsy.get();
+    let valid = !truthy_is_scalar
+        && !falsy_is_scalar
+        && truthy.data_type() == falsy.data_type()
+        && truthy.len() == mask.len()
+        && falsy.len() == mask.len();
+    valid.then_some((truthy, falsy))
+}
 
-    if truthy.data_type() != falsy.data_type() {
-        return Err(ArrowError::InvalidArgumentError(
-            "arguments need to have the same data type".into(),
-        ));
+/// Zips `truthy` and `falsy` where `truthy` has the same values as `mask`, which
+/// selects `true` where `mask` is set, and is therefore the bitwise or of `mask`
+/// and `falsy`
+fn zip_mask_or(mask: &BooleanArray, truthy: &BooleanArray, falsy: &BooleanArray) -> BooleanArray {
+    let values = mask.values() | falsy.values();
+    let nulls = select_nulls(mask.values(), truthy.nulls(), falsy.nulls());
+    BooleanArray::new(values, nulls)
+}
+
+/// Returns the number of runs of set bits in `mask`
+fn set_slice_count(mask: &BooleanBuffer) -> usize {
+    let mut carry = 0;
+    let starts = mask.bit_chunks().iter_padded().map(|c| {
+        let starts = c & !((c << 1) | carry);
+        carry = c >> 63;
+        starts.count_ones() as usize
+    });
+    starts.sum()
+}
+
+/// Zips two primitive arrays of the same type by selecting each value from `truthy`
+/// or `falsy` according to the corresponding bit of `mask`
+///
+/// This processes the mask 64 bits at a time without branching on the bits, so that
+/// the selection can be vectorized, and is faster than copying slices of `truthy`
+/// and `falsy` unless the runs of `mask` are long
+fn zip_blend_primitive(mask: &BooleanBuffer, truthy: &dyn Array, falsy: &dyn Array) -> ArrayRef {
+    downcast_primitive_array! {
+        truthy => Arc::new(blend_primitive(mask, truthy, falsy.as_primitive())),
+        t => unreachable!("primitive type {t}")
     }
+}
 
-    if truthy_is_scalar && truthy.len() != 1 {
-        return Err(ArrowError::InvalidArgumentError(
-            "scalar arrays must have 1 element".into(),
-        ));
+fn blend_primitive<T: ArrowPrimitiveType>(
+    mask: &BooleanBuffer,
+    truthy: &PrimitiveArray<T>,
+    falsy: &PrimitiveArray<T>,
+) -> PrimitiveArray<T> {
+    let mut values = Vec::with_capacity(mask.len());
+    let mut blend = |bits: u64, t: &[T::Native], f: &[T::Native]| {
+        let selected = t.iter().zip(f).enumerate();
+        values.extend(selected.map(|(i, (t, f))| if (bits >> i) & 1 == 1 { *t } else { *f }))
+    };
+
+    let chunks = mask.bit_chunks();
+    let mut chunk_t = truthy.values().chunks_exact(64);
+    let mut chunk_f = falsy.values().chunks_exact(64);
+    for (bits, (t, f)) in chunks.iter().zip(chunk_t.by_ref().zip(chunk_f.by_ref())) {
+        blend(bits, t, f);
     }
-    if !truthy_is_scalar && truthy.len() != mask.len() {
-        return Err(ArrowError::InvalidArgumentError(
-            "all arrays should have the same length".into(),
-        ));
+    blend(
+        chunks.remainder_bits(),
+        chunk_t.remainder(),
+        chunk_f.remainder(),
+    );
+
+    let nulls = select_nulls(mask, truthy.nulls(), falsy.nulls());
+    PrimitiveArray::new(values.into(), nulls).with_data_type(truthy.data_type().clone())
+}
+
+/// Returns the nulls of `truthy` where `mask` is set, and the nulls of `falsy`
+/// elsewhere
+fn select_nulls(
+    mask: &BooleanBuffer,
+    truthy: Option<&NullBuffer>,
+    falsy: Option<&NullBuffer>,
+) -> Option<NullBuffer> {
+    if truthy.is_none() && falsy.is_none() {
+        return None;
     }
-    if falsy_is_scalar && falsy.len() != 1 {
-        return Err(ArrowError::InvalidArgumentError(
-            "scalar arrays must have 1 element".into(),
-        ));
+    let valid = |n: Option<&NullBuffer>| match n {
+        Some(n) => n.inner().clone(),
+        None => BooleanBuffer::new_set(mask.len()),
+    };
+    let t = mask & &valid(truthy);
+    let f = &!mask & &valid(falsy);
+    Some(NullBuffer::new(&t | &f)).filter(|n| n.null_count() > 0)
+}
+
+/// Zips two list arrays that share the same child values, by selecting their offsets,
+/// returning `None` if they do not share their values or the selected lists are not
+/// contiguous in the values
+///
+/// The output values are a slice of the shared values, and no values are copied.
+/// This is the case, for example, when zipping lists that differ only in their nulls.
+fn zip_shared_list<O: OffsetSizeTrait>(
+    mask: &BooleanBuffer,
+    truthy: &GenericListArray<O>,
+    falsy: &GenericListArray<O>,
+) -> Option<ArrayRef> {
+    if mask.is_empty() || !truthy.values().to_data().ptr_eq(&falsy.values().to_data()) {
+        return None;
     }
-    if !falsy_is_scalar && falsy.len() != mask.len() {
-        return Err(ArrowError::InvalidArgumentError(
-            "all arrays should have the same length".into(),
-        ));
+
+    let (t, f) = (truthy.value_offsets(), falsy.value_offsets());
+    let start = match mask.value(0) {
+        true => t[0],
+        false => f[0],
+    };
+    let mut end = start;
+    let mut offsets = Vec::with_capacity(mask.len() + 1);
+    offsets.push(O::usize_as(0));
+    for (i, selected) in mask.iter().enumerate() {
+        let o = if selected { t } else { f };
+        if o[i] != end {
+            return None;
+        }
+        end = o[i + 1];
+        offsets.push(end - start);
     }
 
-    let falsy = falsy.to_data();
+    let (start, end) = (start.as_usize(), end.as_usize());
+    let field = match truthy.data_type() {
+        DataType::List(f) | DataType::LargeList(f) => f.clone(),
+        d => unreachable!("list type {d}"),
+    };
+    let list = GenericListArray::new(
+        field,
+        OffsetBuffer::new(offsets.into()),
+        truthy.values().slice(start, end - start),
+        select_nulls(mask, truthy.nulls(), falsy.nulls()),
+    );
+    Some(Arc::new(list))
+}
+
+/// Zips two primitive arrays that are mostly null by selecting their validity bits
+/// and copying only the values at valid positions, returning `None` if either
+/// argument is not sparse
+///
+/// Positions that are null in the output are left zeroed
+fn zip_sparse_primitive(
+    mask: &BooleanBuffer,
+    truthy: &dyn Array,
+    falsy: &dyn Array,
+) -> Option<ArrayData> {
+    let data_type = truthy.data_type();
+    let width = data_type.primitive_width()?;
+
+    // The validity bits must be combined anyway, and copying values is only
+    // avoided if most of them are null
+    let is_sparse = |a: &dyn Array| a.null_count() * 5 >= a.len() * 4;
+    if mask.is_empty() || !is_sparse(truthy) || !is_sparse(falsy) {
+        return None;
+    }
     let truthy = truthy.to_data();
+    let falsy = falsy.to_data();
+    let truthy_valid = mask & truthy.nulls()?.inner();
+    let falsy_valid = &!mask & falsy.nulls()?.inner();
+
+    let mut values = MutableBuffer::from_len_zeroed(mask.len() * width);
+    for (data, valid) in [(&truthy, &truthy_valid), (&falsy, &falsy_valid)] {
+        let src = &data.buffers()[0].as_slice()[data.offset() * width..];
+        for (start, end) in valid.set_slices() {
+            let range = start * width..end * width;
+            values[range.clone()].copy_from_slice(&src[range]);
+        }
+    }
+
+    let nulls = NullBuffer::new(&truthy_valid | &falsy_valid);
+    let builder = ArrayData::builder(data_type.clone())
+        .len(mask.len())
+        .add_buffer(values.into())
+        .nulls(Some(nulls));
+    // SAFETY: the values buffer has `mask.len()` values of the primitive type
+    Some(unsafe { builder.build_unchecked() })
+}
+
+/// Zip two arrays by some boolean mask as per [`zip`], additionally returning a [`Field`]
+/// named `name` describing the result
+///
+/// The field has the data type of the result, and is nullable if either `truthy` or
+/// `falsy` contains nulls. A null in `mask` selects `falsy`, and so does not by itself
+/// make the result nullable.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, BooleanArray, Int32Array};
+/// # use arrow_schema::DataType;
+/// # use arrow_select::zip::zip_as_field;
+/// let mask = BooleanArray::from(vec![Some(true), None, Some(false)]);
+/// let truthy = Int32Array::from(vec![1, 2, 3]);
+/// let falsy = Int32Array::new_scalar(0);
+/// let (field, result) = zip_as_field("a", &mask, &truthy, &falsy).unwrap();
+/// assert_eq!(field.name(), "a");
+/// assert_eq!(field.data_type(), &DataType::Int32);
+/// assert!(!field.is_nullable());
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![1, 0, 0]));
+/// ```
+pub fn zip_as_field(
+    name: impl Into<String>,
+    mask: &BooleanArray,
+    truthy: &dyn Datum,
+    falsy: &dyn Datum,
+) -> Result<(FieldRef, ArrayRef), ArrowError> {
+    let result = zip(mask, truthy, falsy)?;
+    let nullable = [truthy, falsy]
+        .iter()
+        .any(|d| d.get().0.logical_null_count() > 0);
+    let field = Field::new(name, result.data_type().clone(), nullable);
+    Ok((Arc::new(field), result))
+}
+
+/// Zip `truthy` by some boolean mask as per [`zip`], filling the positions where the
+/// mask is not true with `falsy`, or with nulls of the type of `truthy` if `falsy` is
+/// `None`
+///
+/// This avoids constructing a null [`Scalar`] to pass as `falsy` to [`zip`].
+///
+/// # Example
+/// ```
+/// # use arrow_array::{BooleanArray, Int32Array};
+/// # use arrow_select::zip::zip_or_null;
+/// let mask = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+/// let truthy = Int32Array::from(vec![1, 2, 3, 4]);
+/// let result = zip_or_null(&mask, &truthy, None).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![Some(1), None, None, Some(4)]));
+///
+/// let falsy = Int32Array::new_scalar(0);
+/// let result = zip_or_null(&mask, &truthy, Some(&falsy)).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![1, 0, 0, 4]));
+/// ```
+pub fn zip_or_null(
+    mask: &BooleanArray,
+    truthy: &dyn Datum,
+    falsy: Option<&dyn Datum>,
+) -> Result<ArrayRef, ArrowError> {
+    match falsy {
+        Some(falsy) => zip(mask, truthy, falsy),
+        None => {
+            let null = Scalar::new(new_null_array(truthy.get().0.data_type(), 1));
+            zip(mask, truthy, &null)
+        }
+    }
+}
+
+/// Zip two arrays by a mask of `len` bits, bit-packed into `mask_bits` as per
+/// [`BooleanBuffer`], without constructing a [`BooleanArray`]
+///
+/// The mask has no nulls: where a bit is set, values of `truthy` are taken,
+/// otherwise values of `falsy` are taken. See [`zip`] for details.
+///
+/// # Errors
+/// This function errors if `mask_bits` contains fewer than `len` bits, or
+/// under the same conditions as [`zip`]
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, Int32Array};
+/// # use arrow_buffer::Buffer;
+/// # use arrow_select::zip::zip_raw_mask;
+/// let truthy = Int32Array::from(vec![1, 2, 3, 4]);
+/// let falsy = Int32Array::from(vec![5, 6, 7, 8]);
+/// // mask: [true, false, false, true]
+/// let mask = Buffer::from([0b1001]);
+/// let result = zip_raw_mask(&mask, 4, &truthy, &falsy).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![1, 6, 7, 4]));
+/// ```
+pub fn zip_raw_mask(
+    mask_bits: &Buffer,
+    len: usize,
+    truthy: &dyn Datum,
+    falsy: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    if mask_bits.len().saturating_mul(8) < len {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "mask of {} bytes is too short for length {len}",
+            mask_bits.len()
+        )));
+    }
+    let mask = BitSliceIterator::new(mask_bits.as_slice(), 0, len);
+    zip_datums(mask, len, truthy, falsy).map(make_array)
+}
+
+/// Zip two arrays by some boolean mask as per [`zip`], exporting the result via the
+/// [C Data Interface] into `out` and `schema` without creating an intermediate [`ArrayRef`]
+///
+/// Any existing contents of `out` and `schema` are released and overwritten. They are
+/// left unchanged if an error is returned.
+///
+/// [C Data Interface]: https://arrow.apache.org/docs/format/CDataInterface.html
+///
+/// # Example
+/// ```
+/// # use arrow_array::{make_array, BooleanArray, Int32Array};
+/// # use arrow_array::ffi::{from_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+/// # use arrow_select::zip::zip_to_ffi;
+/// let mask = BooleanArray::from(vec![true, false, true]);
+/// let truthy = Int32Array::from(vec![1, 2, 3]);
+/// let falsy = Int32Array::new_scalar(0);
+///
+/// let mut out = FFI_ArrowArray::empty();
+/// let mut schema = FFI_ArrowSchema::empty();
+/// zip_to_ffi(&mask, &truthy, &falsy, &mut out, &mut schema).unwrap();
+///
+/// let data = unsafe { from_ffi(out, &schema) }.unwrap();
+/// assert_eq!(make_array(data).as_ref(), &Int32Array::from(vec![1, 0, 3]));
+/// ```
+#[cfg(feature = "ffi")]
+pub fn zip_to_ffi(
+    mask: &BooleanArray,
+    truthy: &dyn Datum,
+    falsy: &dyn Datum,
+    out: &mut arrow_data::ffi::FFI_ArrowArray,
+    schema: &mut arrow_schema::ffi::FFI_ArrowSchema,
+) -> Result<(), ArrowError> {
+    let data = zip_datums(mask.values().set_slices(), mask.len(), truthy, falsy)?;
+    *schema = arrow_schema::ffi::FFI_ArrowSchema::try_from(data.data_type())?;
+    *out = arrow_data::ffi::FFI_ArrowArray::new(&data);
+    Ok(())
+}
+
+/// Zip two [`ArrayData`] by some boolean mask as per [`zip`], returning [`ArrayData`]
+///
+/// This allows composing operations on [`ArrayData`] without converting the result
+/// to an [`ArrayRef`]. Both `truthy` and `falsy` must have the same length as `mask`,
+/// to zip with a scalar use [`zip`] with a [`Scalar`].
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, BooleanArray, Int32Array};
+/// # use arrow_select::zip::zip_data;
+/// let mask = BooleanArray::from(vec![true, false, true]);
+/// let truthy = Int32Array::from(vec![1, 2, 3]).into_data();
+/// let falsy = Int32Array::from(vec![4, 5, 6]).into_data();
+/// let result = zip_data(&mask, &truthy, &falsy).unwrap();
+/// assert_eq!(Int32Array::from(result), Int32Array::from(vec![1, 5, 3]));
+/// ```
+pub fn zip_data(
+    mask: &BooleanArray,
+    truthy: &ArrayData,
+    falsy: &ArrayData,
+) -> Result<ArrayData, ArrowError> {
+    zip_impl(
+        mask.values().set_slices(),
+        mask.len(),
+        (truthy, false),
+        (falsy, false),
+    )
+}
+
+/// Zips `truthy` and `falsy` by a mask of length `mask_len`, where `mask`
+/// yields the ranges of the mask that are set
+fn zip_datums(
+    mask: BitSliceIterator<'_>,
+    mask_len: usize,
+    truthy: &dyn Datum,
+    falsy: &dyn Datum,
+) -> Result<ArrayData, ArrowError> {
+    let (truthy, truthy_is_scalar) = truthy.get();
+    let (falsy, falsy_is_scalar) = falsy.get();
+    zip_impl(
+        mask,
+        mask_len,
+        (&truthy.to_data(), truthy_is_scalar),
+        (&falsy.to_data(), falsy_is_scalar),
+    )
+}
+
+/// Zips `truthy` and `falsy`, each paired with whether it is a scalar, as per
+/// [`zip_datums`]
+fn zip_impl(
+    mask: BitSliceIterator<'_>,
+    mask_len: usize,
+    (truthy, truthy_is_scalar): (&ArrayData, bool),
+    (falsy, falsy_is_scalar): (&ArrayData, bool),
+) -> Result<ArrayData, ArrowError> {
+    let data_type = match truthy.data_type() == falsy.data_type() {
+        true => None,
+        false => match unify_data_types(truthy.data_type(), falsy.data_type()) {
+            Some(data_type) => Some(data_type),
+            None => return Err(mismatched_types(truthy.data_type(), falsy.data_type())),
+        },
+    };
+
+    check_lengths(
+        mask_len,
+        (truthy.len(), truthy_is_scalar),
+        (falsy.len(), falsy_is_scalar),
+    )?;
+
+    let mut falsy = falsy.clone();
+    let mut truthy = truthy.clone();
+    if let Some(data_type) = &data_type {
+        falsy = with_data_type(falsy, data_type);
+        truthy = with_data_type(truthy, data_type);
+    }
+    check_layouts(&truthy, &falsy)?;
 
-    let mut mutable = MutableArrayData::new(vec![&truthy, &falsy], false, truthy.len());
+    let mut mutable = MutableArrayData::new(vec![&truthy, &falsy], false, mask_len);
 
-    // the SlicesIterator slices only the true values. So the gaps left by this iterator we need to
+    // the mask slices only the true values. So the gaps left by this iterator we need to
     // fill with falsy values
 
     // keep track of how much is filled
     let mut filled = 0;
 
-    SlicesIterator::new(mask).for_each(|(start, end)| {
+    mask.for_each(|(start, end)| {
         // the gap needs to be filled with falsy values
         if start > filled {
             if falsy_is_scalar {
-                for _ in filled..start {
-                    // Copy the first item from the 'falsy' array into the output buffer.
-                    mutable.extend(1, 0, 1);
-                }
+                // Copy the first item from the 'falsy' array into the output buffer.
+                mutable.extend_constant(1, 0, start - filled);
             } else {
                 mutable.extend(1, filled, start);
             }
         }
         // fill with truthy values
         if truthy_is_scalar {
-            for _ in start..end {
-                // Copy the first item from the 'truthy' array into the output buffer.
-                mutable.extend(0, 0, 1);
-            }
+            // Copy the first item from the 'truthy' array into the output buffer.
+            mutable.extend_constant(0, 0, end - start);
         } else {
             mutable.extend(0, start, end);
         }
         filled = end;
     });
     // the remaining part is falsy
-    if filled < mask.len() {
+    if filled < mask_len {
         if falsy_is_scalar {
-            for _ in filled..mask.len() {
-                // Copy the first item from the 'falsy' array into the output buffer.
-                mutable.extend(1, 0, 1);
-            }
+            // Copy the first item from the 'falsy' array into the output buffer.
+            mutable.extend_constant(1, 0, mask_len - filled);
         } else {
-            mutable.extend(1, filled, mask.len());
+            mutable.extend(1, filled, mask_len);
+        }
+    }
+
+    Ok(mutable.freeze())
+}
+
+/// Checks that `truthy` and `falsy`, each given as its length and whether it is a
+/// scalar, are scalars of 1 element or arrays of `mask_len` elements
+fn check_lengths(
+    mask_len: usize,
+    truthy: (usize, bool),
+    falsy: (usize, bool),
+) -> Result<(), ArrowError> {
+    let inputs = [("truthy", truthy), ("falsy", falsy)];
+    for (name, (len, is_scalar)) in inputs {
+        if is_scalar && len != 1 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "scalar arrays must have 1 element, got {name} scalar of length {len}"
+            )));
+        }
+    }
+    if inputs
+        .iter()
+        .any(|(_, (len, is_scalar))| !is_scalar && *len != mask_len)
+    {
+        let [truthy, falsy] = inputs.map(|(name, (len, is_scalar))| match is_scalar {
+            true => format!("{name} scalar"),
+            false => format!("{name} of length {len}"),
+        });
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "all arrays should have the same length, got mask of length {mask_len}, {truthy} and {falsy}"
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the error for branches of data types `a` and `b` that cannot be zipped,
+/// naming the units of temporal types that only differ by unit
+fn mismatched_types(a: &DataType, b: &DataType) -> ArrowError {
+    use DataType::*;
+    let units = match (a, b) {
+        (Duration(_), Duration(_))
+        | (Time32(_), Time32(_))
+        | (Time64(_), Time64(_))
+        | (Interval(_), Interval(_)) => true,
+        (Timestamp(a_unit, _), Timestamp(b_unit, _)) => a_unit != b_unit,
+        _ => false,
+    };
+    let message = match units {
+        true => {
+            format!("arguments need to have the same data type, got {a} and {b} of different units")
         }
+        false => "arguments need to have the same data type".to_string(),
+    };
+    ArrowError::InvalidArgumentError(message)
+}
+
+/// Returns the data type both `a` and `b` can be represented as, if they differ only
+/// in the nullability or metadata of nested fields, or in the names of list items
+fn unify_data_types(a: &DataType, b: &DataType) -> Option<DataType> {
+    use DataType::*;
+    if a == b {
+        return Some(a.clone());
+    }
+    Some(match (a, b) {
+        (List(a), List(b)) => List(unify_fields(a, b, true)?),
+        (LargeList(a), LargeList(b)) => LargeList(unify_fields(a, b, true)?),
+        (FixedSizeList(a, a_len), FixedSizeList(b, b_len)) if a_len == b_len => {
+            FixedSizeList(unify_fields(a, b, true)?, *a_len)
+        }
+        (Map(a, a_sorted), Map(b, b_sorted)) if a_sorted == b_sorted => {
+            Map(unify_fields(a, b, true)?, *a_sorted)
+        }
+        (Struct(a), Struct(b)) if a.len() == b.len() => {
+            let fields = a.iter().zip(b.iter());
+            Struct(
+                fields
+                    .map(|(a, b)| unify_fields(a, b, false))
+                    .collect::<Option<_>>()?,
+            )
+        }
+        (Dictionary(a_key, a_value), Dictionary(b_key, b_value)) if a_key == b_key => {
+            Dictionary(a_key.clone(), Box::new(unify_data_types(a_value, b_value)?))
+        }
+        _ => return None,
+    })
+}
+
+/// Unifies the fields `a` and `b` as per [`unify_data_types`], using the name of `a`
+/// if `any_name` is true, and otherwise requiring the names to match
+fn unify_fields(a: &FieldRef, b: &FieldRef, any_name: bool) -> Option<FieldRef> {
+    if a == b {
+        return Some(Arc::clone(a));
+    }
+    if !any_name && a.name() != b.name() {
+        return None;
+    }
+    let data_type = unify_data_types(a.data_type(), b.data_type())?;
+    let mut metadata = a.metadata().clone();
+    for (key, value) in b.metadata() {
+        match metadata.get(key) {
+            Some(existing) if existing != value => return None,
+            Some(_) => {}
+            None => {
+                metadata.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    let field = a.as_ref().clone().with_data_type(data_type);
+    let field = field.with_nullable(a.is_nullable() || b.is_nullable());
+    Some(Arc::new(field.with_metadata(metadata)))
+}
+
+/// Returns `data` with the type `data_type` returned by [`unify_data_types`], which
+/// only differs from that of `data` in field metadata, and so has the same layout
+fn with_data_type(data: ArrayData, data_type: &DataType) -> ArrayData {
+    if data.data_type() == data_type {
+        return data;
+    }
+    let child_types: Vec<&DataType> = match data_type {
+        DataType::List(f)
+        | DataType::LargeList(f)
+        | DataType::FixedSizeList(f, _)
+        | DataType::Map(f, _) => vec![f.data_type()],
+        DataType::Struct(fields) => fields.iter().map(|f| f.data_type()).collect(),
+        DataType::Dictionary(_, value) => vec![value.as_ref()],
+        _ => vec![],
+    };
+    let children = data
+        .child_data()
+        .iter()
+        .zip(child_types)
+        .map(|(child, data_type)| with_data_type(child.clone(), data_type))
+        .collect();
+    let builder = data
+        .into_builder()
+        .data_type(data_type.clone())
+        .child_data(children);
+    // Safety: the layout of `data` is unchanged
+    unsafe { builder.build_unchecked() }
+}
+
+/// Recursively checks that `truthy` and `falsy` have the same layout, and that the
+/// layout can be zipped by [`MutableArrayData`].
+///
+/// [`ArrayData`] with matching data types may still have
+/// incompatible children if it was constructed without validation, which would
+/// otherwise result in [`MutableArrayData`] panicking or producing invalid data.
+fn check_layouts(truthy: &ArrayData, falsy: &ArrayData) -> Result<(), ArrowError> {
+    let data_type = truthy.data_type();
+    if data_type != falsy.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "arguments need to have the same layout, found children of type {data_type} and {}",
+            falsy.data_type()
+        )));
+    }
+    if matches!(
+        data_type,
+        DataType::ListView(_) | DataType::LargeListView(_) | DataType::RunEndEncoded(_, _)
+    ) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "zip is not supported for data type {data_type}"
+        )));
+    }
+
+    let layout = layout(data_type);
+    for data in [truthy, falsy] {
+        let buffers = data.buffers().len();
+        let valid = match layout.variadic {
+            true => buffers >= layout.buffers.len(),
+            false => buffers == layout.buffers.len(),
+        };
+        if !valid {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "arguments need to have the same layout, found {buffers} buffers for data type {data_type} expected {}",
+                layout.buffers.len()
+            )));
+        }
+    }
+    if truthy.child_data().len() != falsy.child_data().len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "arguments need to have the same layout, found {} and {} children for data type {data_type}",
+            truthy.child_data().len(),
+            falsy.child_data().len()
+        )));
+    }
+
+    truthy
+        .child_data()
+        .iter()
+        .zip(falsy.child_data())
+        .try_for_each(|(t, f)| check_layouts(t, f))
+}
+
+/// Zip two arrays by some boolean mask, which may be a [`Scalar`].
+///
+/// This behaves like [`zip`] when `mask` is an array. When `mask` is a scalar, the
+/// whole of `truthy` is selected if it is `true`, and the whole of `falsy` otherwise,
+/// allowing callers to avoid branching on whether the mask is a scalar.
+///
+/// If the selected value is an array, it is returned without copying. If it is a
+/// scalar, it is broadcast to the length of the other argument, or returned as a
+/// single element array if both `truthy` and `falsy` are scalars.
+///
+/// # Example: `zip` with a scalar mask
+/// ```
+/// # use arrow_array::{Array, BooleanArray, Int32Array, Scalar};
+/// # use arrow_select::zip::zip_datum_mask;
+/// let truthy = Int32Array::from(vec![1, 2, 3]);
+/// let falsy = Int32Array::new_scalar(42);
+///
+/// let mask = Scalar::new(BooleanArray::from(vec![true]));
+/// let result = zip_datum_mask(&mask, &truthy, &falsy).unwrap();
+/// assert_eq!(result.as_ref(), &truthy as &dyn Array);
+///
+/// let mask = Scalar::new(BooleanArray::from(vec![false]));
+/// let result = zip_datum_mask(&mask, &truthy, &falsy).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![42, 42, 42]) as &dyn Array);
+/// ```
+pub fn zip_datum_mask(
+    mask: &dyn Datum,
+    truthy: &dyn Datum,
+    falsy: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    let (mask, mask_is_scalar) = mask.get();
+    let mask = mask.as_boolean_opt().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "mask needs to be of type Boolean, got {}",
+            mask.data_type()
+        ))
+    })?;
+    if !mask_is_scalar {
+        return zip(mask, truthy, falsy);
+    }
+    if mask.len() != 1 {
+        return Err(ArrowError::InvalidArgumentError(
+            "scalar arrays must have 1 element".into(),
+        ));
+    }
+
+    let (truthy_array, truthy_is_scalar) = truthy.get();
+    let (falsy_array, falsy_is_scalar) = falsy.get();
+    if truthy_array.data_type() != falsy_array.data_type() {
+        return Err(mismatched_types(
+            truthy_array.data_type(),
+            falsy_array.data_type(),
+        ));
+    }
+
+    if (truthy_is_scalar && truthy_array.len() != 1) || (falsy_is_scalar && falsy_array.len() != 1)
+    {
+        return Err(ArrowError::InvalidArgumentError(
+            "scalar arrays must have 1 element".into(),
+        ));
+    }
+    if !truthy_is_scalar && !falsy_is_scalar && truthy_array.len() != falsy_array.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "all arrays should have the same length".into(),
+        ));
+    }
+
+    // A NULL mask selects `falsy`, consistent with `zip`
+    let select_truthy = mask.is_valid(0) && mask.value(0);
+    let (selected, selected_is_scalar) = match select_truthy {
+        true => (truthy_array, truthy_is_scalar),
+        false => (falsy_array, falsy_is_scalar),
+    };
+
+    if !selected_is_scalar || (truthy_is_scalar && falsy_is_scalar) {
+        return Ok(selected.slice(0, selected.len()));
+    }
+
+    // Broadcast the selected scalar to the length of the other argument
+    let len = match select_truthy {
+        true => falsy_array.len(),
+        false => truthy_array.len(),
+    };
+    let mask = match select_truthy {
+        true => BooleanBuffer::new_set(len),
+        false => BooleanBuffer::new_unset(len),
+    };
+    zip(&BooleanArray::new(mask, None), truthy, falsy)
+}
+
+/// Zip two [`RecordBatch`]es with the same schema by some boolean mask.
+///
+/// This applies [`zip`] to each pair of columns, selecting entire rows from
+/// `truthy` where `mask` is `true`, and from `falsy` where `mask` is `false` or `NULL`.
+///
+/// # Errors
+/// This function errors whenever:
+/// * `truthy` and `falsy` do not have the same schema
+/// * `truthy` or `falsy` do not have the same number of rows as `mask`
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{BooleanArray, Int32Array, RecordBatch, StringArray};
+/// # use arrow_select::zip::zip_record_batches;
+/// let truthy = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as _),
+///     ("b", Arc::new(StringArray::from(vec!["x", "y", "z"])) as _),
+/// ])
+/// .unwrap();
+/// let falsy = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![10, 20, 30])) as _),
+///     ("b", Arc::new(StringArray::from(vec!["u", "v", "w"])) as _),
+/// ])
+/// .unwrap();
+///
+/// let mask = BooleanArray::from(vec![Some(true), Some(false), None]);
+/// let result = zip_record_batches(&mask, &truthy, &falsy).unwrap();
+///
+/// let expected = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![1, 20, 30])) as _),
+///     ("b", Arc::new(StringArray::from(vec!["x", "v", "w"])) as _),
+/// ])
+/// .unwrap();
+/// assert_eq!(result, expected);
+/// ```
+pub fn zip_record_batches(
+    mask: &BooleanArray,
+    truthy: &RecordBatch,
+    falsy: &RecordBatch,
+) -> Result<RecordBatch, ArrowError> {
+    if truthy.schema_ref() != falsy.schema_ref() {
+        return Err(ArrowError::InvalidArgumentError(
+            "record batches need to have the same schema".into(),
+        ));
+    }
+    if truthy.num_rows() != mask.len() || falsy.num_rows() != mask.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "all record batches should have the same number of rows as the mask".into(),
+        ));
+    }
+
+    let columns = truthy
+        .columns()
+        .iter()
+        .zip(falsy.columns())
+        .map(|(truthy, falsy)| zip(mask, truthy, falsy))
+        .collect::<Result<Vec<_>, _>>()?;
+    let options = RecordBatchOptions::default().with_row_count(Some(mask.len()));
+    RecordBatch::try_new_with_options(truthy.schema(), columns, &options)
+}
+
+/// Zip two arrays of possibly different types by some boolean mask into a sparse
+/// [`UnionArray`], preserving the type of each branch rather than coercing them.
+///
+/// The result has two variants, type id `0` named `"truthy"` and type id `1` named
+/// `"falsy"`, whose children are `truthy` and `falsy` respectively, without copying.
+///
+/// - Where `mask` is `true`, rows select the `truthy` variant
+/// - Where `mask` is `false` or `NULL`, rows select the `falsy` variant
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, BooleanArray, Int32Array, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::zip::zip_union;
+/// let mask = BooleanArray::from(vec![Some(true), Some(false), None]);
+/// let truthy = Int32Array::from(vec![1, 2, 3]);
+/// let falsy = StringArray::from(vec!["a", "b", "c"]);
+/// let result = zip_union(&mask, &truthy, &falsy).unwrap();
+///
+/// assert_eq!(result.type_ids(), &[0, 1, 1]);
+/// assert_eq!(result.value(0).as_primitive::<Int32Type>().value(0), 1);
+/// assert_eq!(result.value(1).as_string::<i32>().value(0), "b");
+/// ```
+pub fn zip_union(
+    mask: &BooleanArray,
+    truthy: &dyn Array,
+    falsy: &dyn Array,
+) -> Result<UnionArray, ArrowError> {
+    check_lengths(mask.len(), (truthy.len(), false), (falsy.len(), false))?;
+
+    let fields = UnionFields::new(
+        [0, 1],
+        [
+            Field::new("truthy", truthy.data_type().clone(), true),
+            Field::new("falsy", falsy.data_type().clone(), true),
+        ],
+    );
+
+    let type_ids = match mask.nulls() {
+        Some(nulls) => (0..mask.len())
+            .map(|i| (!(nulls.is_valid(i) && mask.values().value(i))) as i8)
+            .collect(),
+        None => mask.values().iter().map(|v| (!v) as i8).collect(),
+    };
+
+    let children = vec![truthy.slice(0, truthy.len()), falsy.slice(0, falsy.len())];
+    UnionArray::try_new(fields, type_ids, None, children)
+}
+
+/// Zip two chunked arrays by a single boolean mask spanning all their chunks, without
+/// concatenating the chunks.
+///
+/// The output is split wherever a chunk of `truthy` or `falsy` ends, such that each
+/// output chunk lies within a single chunk of both branches. Concatenating the output
+/// is equivalent to calling [`zip`] on the concatenation of each branch.
+///
+/// # Errors
+/// This function errors if the total length of the chunks of either branch differs
+/// from the length of `mask`, or the chunks cannot be zipped
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, BooleanArray, Int32Array};
+/// # use arrow_select::zip::zip_mask_over_chunks;
+/// let mask = BooleanArray::from(vec![true, false, true, false]);
+/// let truthy = [Int32Array::from(vec![1, 2, 3]), Int32Array::from(vec![4])];
+/// let falsy = [Int32Array::from(vec![10]), Int32Array::from(vec![20, 30, 40])];
+/// let truthy: Vec<&dyn Array> = truthy.iter().map(|a| a as _).collect();
+/// let falsy: Vec<&dyn Array> = falsy.iter().map(|a| a as _).collect();
+///
+/// let result = zip_mask_over_chunks(&mask, &truthy, &falsy).unwrap();
+/// assert_eq!(result.len(), 3);
+/// assert_eq!(result[0].as_ref(), &Int32Array::from(vec![1]));
+/// assert_eq!(result[1].as_ref(), &Int32Array::from(vec![20, 3]));
+/// assert_eq!(result[2].as_ref(), &Int32Array::from(vec![40]));
+/// ```
+pub fn zip_mask_over_chunks(
+    mask: &BooleanArray,
+    truthy: &[&dyn Array],
+    falsy: &[&dyn Array],
+) -> Result<Vec<ArrayRef>, ArrowError> {
+    let truthy_len: usize = truthy.iter().map(|a| a.len()).sum();
+    let falsy_len: usize = falsy.iter().map(|a| a.len()).sum();
+    if truthy_len != mask.len() || falsy_len != mask.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "total length of chunks should be the same as the mask, got {truthy_len} and {falsy_len} for mask of length {}",
+            mask.len()
+        )));
+    }
+
+    let mut truthy = truthy.iter().filter(|a| !a.is_empty());
+    let mut falsy = falsy.iter().filter(|a| !a.is_empty());
+    // The current chunk of each branch, and the offset into it
+    let mut t = (truthy.next(), 0);
+    let mut f = (falsy.next(), 0);
+
+    let mut output = vec![];
+    let mut offset = 0;
+    while let ((Some(t_chunk), t_offset), (Some(f_chunk), f_offset)) = (t, f) {
+        let len = (t_chunk.len() - t_offset).min(f_chunk.len() - f_offset);
+        output.push(zip(
+            &mask.slice(offset, len),
+            &t_chunk.slice(t_offset, len),
+            &f_chunk.slice(f_offset, len),
+        )?);
+        offset += len;
+
+        t = match t_offset + len == t_chunk.len() {
+            true => (truthy.next(), 0),
+            false => (Some(t_chunk), t_offset + len),
+        };
+        f = match f_offset + len == f_chunk.len() {
+            true => (falsy.next(), 0),
+            false => (Some(f_chunk), f_offset + len),
+        };
     }
+    Ok(output)
+}
+
+/// Zip two arrays by an integer mask as per [`zip`], taking values of `truthy` where
+/// `pred` returns `true` for the value of `mask`, and values of `falsy` otherwise
+///
+/// This avoids first materializing a [`BooleanArray`] from `mask`, for example by a
+/// comparison kernel. Where `mask` is `NULL`, values of `falsy` are taken, and `pred`
+/// is not called.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, Int32Array, StringArray};
+/// # use arrow_select::zip::zip_int_mask;
+/// let mask = Int32Array::from(vec![Some(5), Some(1), None, Some(3)]);
+/// let truthy = StringArray::from(vec!["a", "b", "c", "d"]);
+/// let falsy = StringArray::new_scalar("-");
+/// let result = zip_int_mask(&mask, |v| v >= 3, &truthy, &falsy).unwrap();
+/// assert_eq!(result.as_ref(), &StringArray::from(vec!["a", "-", "-", "d"]));
+/// ```
+pub fn zip_int_mask<F>(
+    mask: &Int32Array,
+    pred: F,
+    truthy: &dyn Datum,
+    falsy: &dyn Datum,
+) -> Result<ArrayRef, ArrowError>
+where
+    F: Fn(i32) -> bool,
+{
+    let values = mask.values();
+    let bits = BooleanBuffer::collect_bool(mask.len(), |i| mask.is_valid(i) && pred(values[i]));
+    zip(&BooleanArray::new(bits, None), truthy, falsy)
+}
+
+/// Zips `truthy` and `falsy` as per [`zip`], additionally returning a [`BooleanBuffer`]
+/// that is set where the output differs from `falsy`
+///
+/// The output can only differ from `falsy` where values of `truthy` are taken, and
+/// differs where these values are not equal to the corresponding value of `falsy`.
+/// A null is equal to a null and not equal to any value, and floats are compared by
+/// their bits, so that a `NaN` is equal to the same `NaN`.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, BooleanArray, Int32Array};
+/// # use arrow_select::zip::zip_diff;
+/// let mask = BooleanArray::from(vec![true, true, false, true]);
+/// let truthy = Int32Array::from(vec![Some(1), Some(5), Some(3), None]);
+/// let falsy = Int32Array::from(vec![Some(1), Some(2), Some(3), Some(4)]);
+/// let (result, changed) = zip_diff(&mask, &truthy, &falsy).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![Some(1), Some(5), Some(3), None]));
+/// assert_eq!(changed.iter().collect::<Vec<_>>(), vec![false, true, false, true]);
+/// ```
+pub fn zip_diff(
+    mask: &BooleanArray,
+    truthy: &dyn Datum,
+    falsy: &dyn Datum,
+) -> Result<(ArrayRef, BooleanBuffer), ArrowError> {
+    let output = zip(mask, truthy, falsy)?;
+    let (falsy, falsy_is_scalar) = falsy.get();
+    let falsy = make_array(with_data_type(falsy.to_data(), output.data_type()));
+    let index = |i: usize| if falsy_is_scalar { 0 } else { i };
+    let mask = mask.values();
 
-    let data = mutable.freeze();
-    Ok(make_array(data))
+    let values = output.as_ref();
+    let changed = downcast_primitive_array! {
+        values => value_changes(mask, values, falsy.as_primitive(), index, |a, b| a.is_eq(b)),
+        DataType::Boolean => value_changes(mask, values.as_boolean(), falsy.as_boolean(), index, |a, b| a == b),
+        DataType::Utf8 => value_changes(mask, values.as_string::<i32>(), falsy.as_string(), index, |a, b| a == b),
+        DataType::LargeUtf8 => value_changes(mask, values.as_string::<i64>(), falsy.as_string(), index, |a, b| a == b),
+        DataType::Utf8View => value_changes(mask, values.as_string_view(), falsy.as_string_view(), index, |a, b| a == b),
+        DataType::Binary => value_changes(mask, values.as_binary::<i32>(), falsy.as_binary(), index, |a, b| a == b),
+        DataType::LargeBinary => value_changes(mask, values.as_binary::<i64>(), falsy.as_binary(), index, |a, b| a == b),
+        DataType::BinaryView => value_changes(mask, values.as_binary_view(), falsy.as_binary_view(), index, |a, b| a == b),
+        DataType::FixedSizeBinary(_) => value_changes(mask, values.as_fixed_size_binary(), falsy.as_fixed_size_binary(), index, |a, b| a == b),
+        _ => BooleanBuffer::collect_bool(mask.len(), |i| {
+            mask.value(i) && values.slice(i, 1).to_data() != falsy.slice(index(i), 1).to_data()
+        })
+    };
+    Ok((output, changed))
+}
+
+/// Returns a [`BooleanBuffer`] that is set where `mask` is set and the value of
+/// `output` differs from the value of `falsy` at `index`, as per `eq`
+fn value_changes<A: ArrayAccessor>(
+    mask: &BooleanBuffer,
+    output: A,
+    falsy: A,
+    index: impl Fn(usize) -> usize,
+    eq: impl Fn(A::Item, A::Item) -> bool,
+) -> BooleanBuffer {
+    BooleanBuffer::collect_bool(mask.len(), |i| {
+        let j = index(i);
+        mask.value(i)
+            && match (output.is_valid(i), falsy.is_valid(j)) {
+                (true, true) => !eq(output.value(i), falsy.value(j)),
+                (a, b) => a != b,
+            }
+    })
+}
+
+/// Applies `f` to the values of `values` where `mask` is `true`, leaving the other
+/// values unchanged, i.e. `if mask then f(values) else values`
+///
+/// This is equivalent to calling [`zip`] on `mask`, `values` with `f` applied to all
+/// of its values, and `values`, but builds the result in a single pass without
+/// applying `f` to unselected values. Where `mask` is `false` or `NULL`, values are
+/// left unchanged. The nulls of `values` are preserved, and `f` may be applied to
+/// the values of null slots.
+///
+/// # Errors
+/// This function errors if `mask` and `values` have different lengths
+///
+/// # Example
+/// ```
+/// # use arrow_array::{BooleanArray, Int32Array};
+/// # use arrow_select::zip::zip_map;
+/// let mask = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+/// let values = Int32Array::from(vec![-1, -2, -3, 4]);
+/// let result = zip_map(&mask, &values, |v| v.wrapping_abs()).unwrap();
+/// assert_eq!(result, Int32Array::from(vec![1, -2, -3, 4]));
+/// ```
+pub fn zip_map<T, F>(
+    mask: &BooleanArray,
+    values: &PrimitiveArray<T>,
+    f: F,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native) -> T::Native,
+{
+    if mask.len() != values.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "zip_map requires a mask of the same length as the values, got {} and {}",
+            mask.len(),
+            values.len()
+        )));
+    }
+
+    let selected = match mask.nulls() {
+        Some(nulls) if nulls.null_count() > 0 => mask.values() & nulls.inner(),
+        _ => mask.values().clone(),
+    };
+
+    let native = values.values();
+    let mut result = Vec::with_capacity(native.len());
+    let mut last = 0;
+    for (start, end) in selected.set_slices() {
+        result.extend_from_slice(&native[last..start]);
+        result.extend(native[start..end].iter().map(|v| f(*v)));
+        last = end;
+    }
+    result.extend_from_slice(&native[last..]);
+
+    Ok(PrimitiveArray::new(result.into(), values.nulls().cloned())
+        .with_data_type(values.data_type().clone()))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use arrow_array::types::{
+        Date32Type, Date64Type, DurationMillisecondType, Int32Type, Int64Type, Time32SecondType,
+        Time64NanosecondType, TimestampNanosecondType,
+    };
+    use arrow_buffer::OffsetBuffer;
+    use arrow_buffer::{bit_util, ArrowNativeType, MutableBuffer};
+    use arrow_schema::{Fields, UnionMode};
+    use std::sync::Arc;
 
     #[test]
     fn test_zip_kernel_one() {
@@ -279,4 +1315,1136 @@ mod test {
         let expected = Int32Array::from(vec![None, None, Some(42), Some(42), None]);
         assert_eq!(actual, &expected);
     }
+
+    #[test]
+    fn test_zip_kernel_scalar_large_utf8() {
+        let a = LargeStringArray::from(vec![Some("a"), None, Some("ccc"), None, Some("ee")]);
+        let mask = BooleanArray::from(vec![true, false, false, true, false]);
+
+        let scalar = LargeStringArray::new_scalar("scalar");
+        let out = zip(&mask, &a, &scalar).unwrap();
+        out.to_data().validate_full().unwrap();
+        let out = out.as_string::<i64>();
+        let values = out.iter().collect::<Vec<_>>();
+        let expected = vec![
+            Some("a"),
+            Some("scalar"),
+            Some("scalar"),
+            None,
+            Some("scalar"),
+        ];
+        assert_eq!(values, expected);
+
+        // Empty string scalar
+        let empty = LargeStringArray::new_scalar("");
+        let out = zip(&mask, &empty, &a).unwrap();
+        out.to_data().validate_full().unwrap();
+        let out = out.as_string::<i64>();
+        let values = out.iter().collect::<Vec<_>>();
+        let expected = vec![Some(""), None, Some("ccc"), Some(""), Some("ee")];
+        assert_eq!(values, expected);
+
+        // Null scalar
+        let null = Scalar::new(LargeStringArray::new_null(1));
+        let out = zip(&mask, &a, &null).unwrap();
+        out.to_data().validate_full().unwrap();
+        let out = out.as_string::<i64>();
+        let values = out.iter().collect::<Vec<_>>();
+        let expected = vec![Some("a"), None, None, None, None];
+        assert_eq!(values, expected);
+
+        // Both scalars
+        let out = zip(&mask, &null, &scalar).unwrap();
+        out.to_data().validate_full().unwrap();
+        let out = out.as_string::<i64>();
+        let values = out.iter().collect::<Vec<_>>();
+        let expected = vec![None, Some("scalar"), Some("scalar"), None, Some("scalar")];
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn test_zip_unifies_nested_fields() {
+        let list = |nullable: bool, name: &str| {
+            let field = Arc::new(Field::new(name, DataType::Int32, nullable));
+            let values = Int32Array::from(vec![1, 2, 3, 4, 5, 6]);
+            let offsets = OffsetBuffer::from_lengths([2, 1, 3]);
+            ListArray::new(field, offsets, Arc::new(values), None)
+        };
+        let strukt = |list: ListArray, metadata: &[(&str, &str)]| {
+            let metadata = metadata
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            let field = Field::new("x", list.data_type().clone(), false).with_metadata(metadata);
+            StructArray::new(vec![field].into(), vec![Arc::new(list)], None)
+        };
+
+        let truthy = strukt(list(false, "item"), &[("a", "1")]);
+        let falsy = strukt(list(true, "element"), &[("b", "2")]);
+        let mask = BooleanArray::from(vec![true, false, true]);
+
+        let out = zip(&mask, &truthy, &falsy).unwrap();
+        out.to_data().validate_full().unwrap();
+        let expected_list = DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
+        let expected_field = Field::new("x", expected_list, false).with_metadata(
+            [("a", "1"), ("b", "2")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        );
+        assert_eq!(
+            out.data_type(),
+            &DataType::Struct(vec![expected_field].into())
+        );
+
+        let x = out.as_struct().column(0).as_list::<i32>();
+        let values = x.values().as_primitive::<Int32Type>();
+        assert_eq!(values.values(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(x.value_offsets(), &[0, 2, 3, 6]);
+
+        // Conflicting metadata cannot be unified
+        let falsy = strukt(list(true, "item"), &[("a", "2")]);
+        let err = zip(&mask, &truthy, &falsy).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arguments need to have the same data type"
+        );
+
+        // Neither can differently named struct fields
+        let falsy = StructArray::new(
+            vec![Field::new("y", truthy.column(0).data_type().clone(), false)].into(),
+            vec![truthy.column(0).clone()],
+            None,
+        );
+        let err = zip(&mask, &truthy, &falsy).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arguments need to have the same data type"
+        );
+    }
+
+    #[test]
+    fn test_zip_sparse_primitive() {
+        let sparse = |n: i64, seed: i64| {
+            Int64Array::from_iter((0..n).map(|i| ((i * 7 + seed) % 20 == 0).then_some(i + seed)))
+        };
+        let truthy = sparse(1000, 3);
+        let falsy = sparse(1000, 11);
+        let mask = BooleanArray::from_iter((0..1000).map(|i| Some(i % 3 == 0 || i % 7 == 1)));
+
+        let check = |mask: &BooleanArray, truthy: &dyn Array, falsy: &dyn Array| {
+            let sparse = zip_sparse_primitive(mask.values(), truthy, falsy).unwrap();
+            let generic =
+                zip_datums(mask.values().set_slices(), mask.len(), &truthy, &falsy).unwrap();
+            assert_eq!(sparse, generic);
+            sparse.validate_full().unwrap();
+            assert_eq!(&make_array(sparse), &zip(mask, &truthy, &falsy).unwrap());
+        };
+        check(&mask, &truthy, &falsy);
+
+        // Sliced arguments
+        check(
+            &mask.slice(5, 900),
+            &truthy.slice(13, 900),
+            &falsy.slice(1, 900),
+        );
+
+        // Data types are preserved
+        let timestamps = truthy.reinterpret_cast::<TimestampNanosecondType>();
+        let truthy = timestamps.clone().with_timezone("+01:00");
+        let falsy = timestamps.slice(1, 999).with_timezone("+01:00");
+        let sliced = mask.slice(1, 999);
+        check(&sliced, &truthy.slice(0, 999), &falsy);
+        assert_eq!(
+            zip(&sliced, &truthy.slice(0, 999), &falsy)
+                .unwrap()
+                .data_type(),
+            truthy.data_type()
+        );
+
+        // Dense arguments and scalars use the generic path
+        let dense = Int64Array::from_iter_values(0..1000);
+        let sparse = sparse(1000, 3);
+        assert!(zip_sparse_primitive(mask.values(), &dense, &sparse).is_none());
+        assert!(zip_sparse_primitive(mask.values(), &sparse, &dense).is_none());
+        let scalar = Int64Array::new_scalar(1);
+        assert!(same_type_arrays(mask.values(), &sparse, &scalar).is_none());
+    }
+
+    #[test]
+    fn test_zip_duration() {
+        let mask = BooleanArray::from(vec![true, false, true, false]);
+        let a = DurationMillisecondArray::from(vec![Some(1), Some(2), None, Some(4)]);
+        let b = DurationMillisecondArray::from(vec![Some(5), None, Some(7), Some(8)]);
+        let result = zip(&mask, &a, &b).unwrap();
+        assert_eq!(result.data_type(), a.data_type());
+        let expected = DurationMillisecondArray::from(vec![Some(1), None, None, Some(8)]);
+        assert_eq!(result.as_primitive::<DurationMillisecondType>(), &expected);
+
+        // Scalar branch
+        let scalar = Scalar::new(DurationMillisecondArray::from(vec![10]));
+        let result = zip(&mask, &scalar, &b).unwrap();
+        let expected = DurationMillisecondArray::from(vec![Some(10), None, Some(10), Some(8)]);
+        assert_eq!(result.as_primitive::<DurationMillisecondType>(), &expected);
+
+        // Mismatched units
+        let c = DurationSecondArray::from(vec![1, 2, 3, 4]);
+        let expected = "Invalid argument error: arguments need to have the same data type, \
+            got Duration(Millisecond) and Duration(Second) of different units";
+        let err = zip(&mask, &a, &c).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+        let scalar = Scalar::new(DurationSecondArray::from(vec![10]));
+        let err = zip(&mask, &a, &scalar).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn test_zip_mask_as_truthy() {
+        let mask = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+            Some(false),
+            None,
+            Some(false),
+        ]);
+        let falsy = BooleanArray::from(vec![
+            Some(false),
+            Some(true),
+            Some(true),
+            None,
+            None,
+            Some(false),
+            Some(false),
+        ]);
+        // A copy of the mask that does not share its buffers, zipped by the generic path
+        let copy = mask.iter().collect::<BooleanArray>();
+        assert!(!copy.values().ptr_eq(mask.values()));
+
+        let result = zip(&mask, &mask, &falsy).unwrap();
+        assert_eq!(&result, &zip(&mask, &copy, &falsy).unwrap());
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(true),
+            Some(true),
+            None,
+            Some(false),
+            Some(false),
+        ]);
+        assert_eq!(result.as_boolean(), &expected);
+
+        let (mask, falsy) = (mask.slice(1, 5), falsy.slice(2, 5));
+        let result = zip(&mask, &mask, &falsy).unwrap();
+        let copy = mask.iter().collect::<BooleanArray>();
+        assert_eq!(&result, &zip(&mask, &copy, &falsy).unwrap());
+    }
+
+    #[test]
+    fn test_zip_diff() {
+        let mask = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(false),
+            Some(true),
+            None,
+            Some(true),
+            Some(true),
+        ]);
+        let truthy = Float64Array::from(vec![
+            Some(1.0),
+            Some(2.5),
+            Some(3.0),
+            None,
+            Some(9.0),
+            Some(f64::NAN),
+            Some(-0.0),
+        ]);
+        let falsy = Float64Array::from(vec![
+            Some(1.0),
+            Some(2.0),
+            Some(4.0),
+            None,
+            Some(5.0),
+            Some(f64::NAN),
+            Some(0.0),
+        ]);
+        let (result, changed) = zip_diff(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(&result, &zip(&mask, &truthy, &falsy).unwrap());
+        // Sparser than the mask where the selected values are already in falsy
+        let expected = [false, true, false, false, false, false, true];
+        assert_eq!(changed.iter().collect::<Vec<_>>(), expected);
+        assert!(changed.count_set_bits() < mask.true_count());
+
+        let truthy = StringArray::from(vec![
+            Some("a"),
+            None,
+            Some("c"),
+            Some("d"),
+            Some("e"),
+            Some("f"),
+            None,
+        ]);
+        let falsy = StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            Some("x"),
+            Some("y"),
+            Some("z"),
+            Some("f"),
+            None,
+        ]);
+        let (result, changed) = zip_diff(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(&result, &zip(&mask, &truthy, &falsy).unwrap());
+        let expected = [false, true, false, true, false, false, false];
+        assert_eq!(changed.iter().collect::<Vec<_>>(), expected);
+
+        // Scalar falsy
+        let falsy = StringArray::new_scalar("f");
+        let (result, changed) = zip_diff(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(&result, &zip(&mask, &truthy, &falsy).unwrap());
+        let expected = [true, true, false, true, false, false, true];
+        assert_eq!(changed.iter().collect::<Vec<_>>(), expected);
+
+        let falsy = Int32Array::new_scalar(2);
+        let truthy = Int32Array::from(vec![1, 2, 3, 2, 4, 2, 5]);
+        let (_, changed) = zip_diff(&mask, &truthy, &falsy).unwrap();
+        let expected = [true, false, false, false, false, false, true];
+        assert_eq!(changed.iter().collect::<Vec<_>>(), expected);
+
+        let truthy = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            Some(true),
+            None,
+            Some(true),
+            Some(false),
+            Some(true),
+        ]);
+        let falsy = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false),
+            None,
+        ]);
+        let (result, changed) = zip_diff(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(&result, &zip(&mask, &truthy, &falsy).unwrap());
+        let expected = [false, true, false, true, false, false, true];
+        assert_eq!(changed.iter().collect::<Vec<_>>(), expected);
+
+        // Nested types compare the selected values as a whole
+        let truthy = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![Some(2), None]),
+            None,
+            None,
+            Some(vec![]),
+            Some(vec![]),
+            Some(vec![Some(7)]),
+        ]);
+        let falsy = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![Some(2)]),
+            Some(vec![]),
+            None,
+            None,
+            Some(vec![]),
+            Some(vec![Some(8)]),
+        ]);
+        let (result, changed) = zip_diff(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(&result, &zip(&mask, &truthy, &falsy).unwrap());
+        let expected = [false, true, false, false, false, false, true];
+        assert_eq!(changed.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_zip_blend_primitive() {
+        let truthy = Int32Array::from_iter((0..1000).map(|i| (i % 11 != 0).then_some(i)));
+        let falsy = Int32Array::from_iter_values((0..1000).map(|i| -i));
+        let mask = BooleanArray::from_iter((0..1000).map(|i| Some(i % 3 == 0 || i % 7 == 1)));
+        assert!(set_slice_count(mask.values()) * 64 >= mask.len());
+
+        let check = |mask: &BooleanArray, truthy: &dyn Array, falsy: &dyn Array| {
+            let blend = zip_blend_primitive(mask.values(), truthy, falsy);
+            let generic =
+                zip_datums(mask.values().set_slices(), mask.len(), &truthy, &falsy).unwrap();
+            assert_eq!(blend.to_data(), generic);
+            assert_eq!(&blend, &zip(mask, &truthy, &falsy).unwrap());
+        };
+        check(&mask, &truthy, &falsy);
+        check(&mask, &falsy, &truthy);
+        check(&mask, &falsy, &falsy);
+
+        // Sliced arguments, with a length that is not a multiple of 64
+        check(
+            &mask.slice(5, 900),
+            &truthy.slice(13, 900),
+            &falsy.slice(1, 900),
+        );
+        check(
+            &mask.slice(3, 10),
+            &truthy.slice(0, 10),
+            &falsy.slice(7, 10),
+        );
+
+        // Data types are preserved
+        let truthy = truthy.reinterpret_cast::<Date32Type>();
+        let falsy = falsy.reinterpret_cast::<Date32Type>();
+        let result = zip(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(result.data_type(), &DataType::Date32);
+
+        // Long runs use the generic path
+        let mask = BooleanArray::from_iter((0..1000).map(|i| Some(i % 400 < 100)));
+        assert_eq!(set_slice_count(mask.values()), 3);
+        assert_eq!(set_slice_count(mask.slice(150, 600).values()), 1);
+        check(&mask, &truthy, &falsy);
+    }
+
+    #[test]
+    fn test_zip_int_mask() {
+        let mask = Int32Array::from_iter((0..200).map(|i| (i % 13 != 0).then_some(i % 7 - 3)));
+        let truthy = Int64Array::from_iter((0..200).map(|i| (i % 5 != 0).then_some(i)));
+        let falsy = StringArray::from_iter_values((0..200).map(|i| format!("f{i}")));
+        let strings = StringArray::from_iter_values((0..200).map(|i| format!("t{i}")));
+        let scalar = StringArray::new_scalar("s");
+
+        for threshold in [-4, 0, 2, 4] {
+            // Equivalent to a mask computed by `gt_eq` with a scalar, which is null
+            // where `mask` is null
+            let expected_mask: BooleanArray =
+                mask.iter().map(|v| v.map(|v| v >= threshold)).collect();
+            let pred = |v| v >= threshold;
+
+            let result = zip_int_mask(&mask, pred, &strings, &falsy).unwrap();
+            assert_eq!(&result, &zip(&expected_mask, &strings, &falsy).unwrap());
+            let result = zip_int_mask(&mask, pred, &scalar, &falsy).unwrap();
+            assert_eq!(&result, &zip(&expected_mask, &scalar, &falsy).unwrap());
+
+            let falsy = Int64Array::new_scalar(-1);
+            let result = zip_int_mask(&mask, pred, &truthy, &falsy).unwrap();
+            assert_eq!(&result, &zip(&expected_mask, &truthy, &falsy).unwrap());
+        }
+
+        // Sliced masks
+        let sliced = mask.slice(7, 100);
+        let expected_mask: BooleanArray = sliced.iter().map(|v| v.map(|v| v > 0)).collect();
+        let truthy = truthy.slice(3, 100);
+        let falsy = Int64Array::new_scalar(0);
+        let result = zip_int_mask(&sliced, |v| v > 0, &truthy, &falsy).unwrap();
+        assert_eq!(&result, &zip(&expected_mask, &truthy, &falsy).unwrap());
+
+        // Null slots take `falsy`, even if their value satisfies `pred`
+        let mask = Int32Array::new(vec![5, 5].into(), Some(NullBuffer::from(vec![true, false])));
+        let truthy = Int64Array::from(vec![1, 2]);
+        let result = zip_int_mask(&mask, |v| v >= 0, &truthy, &falsy).unwrap();
+        assert_eq!(
+            result.as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![1, 0])
+        );
+    }
+
+    #[test]
+    fn test_zip_shared_list_values() {
+        let values: ArrayRef = Arc::new(Int32Array::from_iter_values(0..10));
+        let field = Arc::new(Field::new_list_field(DataType::Int32, false));
+        let list = |offsets: Vec<i32>, nulls: Option<NullBuffer>| {
+            let offsets = OffsetBuffer::new(offsets.into());
+            ListArray::new(field.clone(), offsets, values.clone(), nulls)
+        };
+        let truthy = list(
+            vec![0, 2, 5, 5, 9],
+            Some(vec![true, false, true, true].into()),
+        );
+        let falsy = list(
+            vec![0, 1, 5, 7, 10],
+            Some(vec![true, true, true, false].into()),
+        );
+
+        let check = |mask: &BooleanArray, truthy: &ListArray, falsy: &ListArray| {
+            let result = zip(mask, truthy, falsy).unwrap();
+            let generic =
+                zip_datums(mask.values().set_slices(), mask.len(), &truthy, &falsy).unwrap();
+            assert_eq!(&result, &make_array(generic));
+            result.to_data().validate_full().unwrap();
+            result
+        };
+
+        // The selected lists are contiguous in the shared values
+        let mask = BooleanArray::from(vec![true, true, false, false]);
+        let result = check(&mask, &truthy, &falsy);
+        let result = result.as_list::<i32>();
+        assert_eq!(result.value_offsets(), &[0, 2, 5, 7, 10]);
+        assert!(result.values().to_data().ptr_eq(&values.to_data()));
+        assert_eq!(result.nulls().unwrap().null_count(), 2);
+
+        // Sliced lists select a slice of the shared values
+        let mask = BooleanArray::from(vec![true, false, false]);
+        let result = check(&mask, &truthy.slice(1, 3), &falsy.slice(1, 3));
+        let result = result.as_list::<i32>();
+        assert_eq!(result.value_offsets(), &[0, 3, 5, 8]);
+        assert!(result
+            .values()
+            .to_data()
+            .ptr_eq(&values.slice(2, 8).to_data()));
+
+        // Lists that are not contiguous are copied
+        let mask = BooleanArray::from(vec![true, false, true, true]);
+        let result = check(&mask, &truthy, &falsy);
+        let result = result.as_list::<i32>();
+        assert_eq!(
+            result.values().as_primitive::<Int32Type>().values(),
+            &[0, 1, 1, 2, 3, 4, 5, 6, 7, 8]
+        );
+        assert!(!result.values().to_data().ptr_eq(&values.to_data()));
+
+        // Lists with different values are copied
+        let other = ListArray::new(
+            field.clone(),
+            falsy.offsets().clone(),
+            Arc::new(Int32Array::from_iter_values(10..20)),
+            None,
+        );
+        let mask = BooleanArray::from(vec![true, true, false, false]);
+        let result = check(&mask, &truthy, &other);
+        assert!(!result
+            .as_list::<i32>()
+            .values()
+            .to_data()
+            .ptr_eq(&values.to_data()));
+    }
+
+    #[test]
+    fn test_zip_length_mismatch() {
+        let mask = BooleanArray::from(vec![true, false, true]);
+        let a = Int32Array::from(vec![1, 2]);
+        let b = Int32Array::from(vec![3, 4, 5]);
+
+        let err = zip(&mask, &a, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all arrays should have the same length, got mask of length 3, truthy of length 2 and falsy of length 3"
+        );
+
+        // Branches of the same length that differ from the mask
+        let err = zip(&mask, &a, &a).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all arrays should have the same length, got mask of length 3, truthy of length 2 and falsy of length 2"
+        );
+
+        let err = zip(&mask, &Int32Array::new_scalar(1), &a).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all arrays should have the same length, got mask of length 3, truthy scalar and falsy of length 2"
+        );
+    }
+
+    #[test]
+    fn test_zip_data() {
+        let mask = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        let truthy = StringArray::from(vec![Some("a"), Some("b"), None, None]);
+        let falsy = StringArray::from(vec![Some("w"), Some("x"), Some("y"), Some("z")]);
+
+        let result = zip_data(&mask, &truthy.to_data(), &falsy.to_data()).unwrap();
+        result.validate_full().unwrap();
+        assert_eq!(result, zip(&mask, &truthy, &falsy).unwrap().to_data());
+
+        // Results can be zipped again
+        let mask = BooleanArray::from(vec![false, true, true, false]);
+        let result = zip_data(&mask, &result, &truthy.slice(0, 4).to_data()).unwrap();
+        assert_eq!(
+            StringArray::from(result),
+            StringArray::from(vec![Some("a"), Some("x"), Some("y"), None])
+        );
+
+        let err = zip_data(&mask, &truthy.slice(0, 1).to_data(), &falsy.to_data()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all arrays should have the same length, got mask of length 4, truthy of length 1 and falsy of length 4"
+        );
+    }
+
+    #[test]
+    fn test_zip_as_field() {
+        let mask = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        let valid = Int32Array::from(vec![1, 2, 3, 4]);
+        let nulls = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+
+        // Null masks do not introduce nulls
+        let (field, result) = zip_as_field("a", &mask, &valid, &valid).unwrap();
+        assert_eq!(field.as_ref(), &Field::new("a", DataType::Int32, false));
+        assert_eq!(&result, &zip(&mask, &valid, &valid).unwrap());
+        assert_eq!(result.null_count(), 0);
+
+        for (truthy, falsy) in [(&nulls, &valid), (&valid, &nulls)] {
+            let (field, result) = zip_as_field("b", &mask, truthy, falsy).unwrap();
+            assert_eq!(field.as_ref(), &Field::new("b", DataType::Int32, true));
+            assert_eq!(&result, &zip(&mask, truthy, falsy).unwrap());
+        }
+
+        let null = Scalar::new(Int32Array::new_null(1));
+        let (field, _) = zip_as_field("c", &mask, &valid, &null).unwrap();
+        assert!(field.is_nullable());
+        let (field, _) = zip_as_field("c", &mask, &Int32Array::new_scalar(1), &valid).unwrap();
+        assert!(!field.is_nullable());
+
+        // The field has the unified data type of the result
+        let item = |nullable| Arc::new(Field::new_list_field(DataType::Int32, nullable));
+        let offsets = OffsetBuffer::from_lengths([1, 1, 1, 1]);
+        let values: ArrayRef = Arc::new(valid.clone());
+        let a = ListArray::new(item(false), offsets.clone(), Arc::clone(&values), None);
+        let b = ListArray::new(item(true), offsets, values, None);
+        let (field, result) = zip_as_field("d", &mask, &a, &b).unwrap();
+        assert_eq!(field.data_type(), result.data_type());
+        assert!(!field.is_nullable());
+
+        let err = zip_as_field("e", &mask, &valid, &Int64Array::new_scalar(1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arguments need to have the same data type"
+        );
+    }
+
+    #[test]
+    fn test_zip_or_null() {
+        let mask = BooleanArray::from(vec![Some(true), Some(false), None, Some(true), Some(false)]);
+        let truthy = StringArray::from(vec![Some("a"), Some("b"), Some("c"), None, Some("e")]);
+
+        let null = Scalar::new(StringArray::new_null(1));
+        let expected = zip(&mask, &truthy, &null).unwrap();
+        let result = zip_or_null(&mask, &truthy, None).unwrap();
+        assert_eq!(&result, &expected);
+        assert_eq!(
+            result.as_string::<i32>(),
+            &StringArray::from(vec![Some("a"), None, None, None, None])
+        );
+
+        let falsy = StringArray::new_scalar("z");
+        let result = zip_or_null(&mask, &truthy, Some(&falsy)).unwrap();
+        assert_eq!(&result, &zip(&mask, &truthy, &falsy).unwrap());
+
+        // Scalar truthy values
+        let truthy = Int64Array::new_scalar(7);
+        let result = zip_or_null(&mask, &truthy, None).unwrap();
+        let expected = Int64Array::from(vec![Some(7), None, None, Some(7), None]);
+        assert_eq!(result.as_primitive::<Int64Type>(), &expected);
+
+        // The data type of truthy is preserved
+        let truthy = TimestampSecondArray::from(vec![1, 2, 3, 4, 5]).with_timezone("+01:00");
+        let result = zip_or_null(&mask, &truthy, None).unwrap();
+        assert_eq!(result.data_type(), truthy.data_type());
+    }
+
+    #[test]
+    fn test_zip_map() {
+        let values = Int32Array::from(vec![Some(1), Some(-2), None, Some(4), Some(-5), Some(6)]);
+        let mask = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+        ]);
+
+        // Only selected values are negated, null masks are not selected
+        let negated = zip_map(&mask, &values, |v| -v).unwrap();
+        let expected = Int32Array::from(vec![Some(-1), Some(2), None, Some(4), Some(-5), Some(-6)]);
+        assert_eq!(negated, expected);
+
+        // The result matches zipping `f` applied to all values
+        let all_negated = Int32Array::from_iter(values.iter().map(|v| v.map(|v| -v)));
+        let zipped = zip(&mask, &all_negated, &values).unwrap();
+        assert_eq!(zipped.as_primitive::<Int32Type>(), &negated);
+
+        // `f` is not applied to unselected values
+        let result = zip_map(&mask.slice(3, 2), &values.slice(3, 2), |_| unreachable!()).unwrap();
+        assert_eq!(result, values.slice(3, 2));
+
+        let timestamps = TimestampSecondArray::from(vec![10, 20]).with_timezone("+01:00");
+        let mask = BooleanArray::from(vec![false, true]);
+        let result = zip_map(&mask, &timestamps, |v| v + 1).unwrap();
+        let expected = TimestampSecondArray::from(vec![10, 21]).with_timezone("+01:00");
+        assert_eq!(result, expected);
+
+        let err = zip_map(&mask, &values, |v| v).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: zip_map requires a mask of the same length as the values, got 2 and 6"
+        );
+    }
+
+    #[test]
+    fn test_zip_raw_mask() {
+        let bools = (0..70).map(|i| i % 3 == 0 || (20..45).contains(&i));
+        let mask = BooleanArray::from_iter(bools.clone().map(Some));
+        let mut bits = MutableBuffer::new_null(70);
+        for (i, b) in bools.enumerate() {
+            if b {
+                bit_util::set_bit(bits.as_slice_mut(), i);
+            }
+        }
+        let bits = Buffer::from(bits);
+
+        let truthy = StringArray::from_iter((0..70).map(|i| (i % 5 != 0).then(|| i.to_string())));
+        let falsy = Scalar::new(StringArray::from(vec!["falsy"]));
+        let expected = zip(&mask, &truthy, &falsy).unwrap();
+        let actual = zip_raw_mask(&bits, 70, &truthy, &falsy).unwrap();
+        assert_eq!(actual.as_ref(), expected.as_ref());
+
+        let truthy = Scalar::new(Int32Array::from(vec![1]));
+        let falsy = Int32Array::from_iter_values(0..70);
+        let expected = zip(&mask, &truthy, &falsy).unwrap();
+        let actual = zip_raw_mask(&bits, 70, &truthy, &falsy).unwrap();
+        assert_eq!(actual.as_ref(), expected.as_ref());
+
+        // A mask shorter than the buffer
+        let expected = zip(&mask.slice(0, 10), &truthy, &falsy.slice(0, 10)).unwrap();
+        let actual = zip_raw_mask(&bits, 10, &truthy, &falsy.slice(0, 10)).unwrap();
+        assert_eq!(actual.as_ref(), expected.as_ref());
+
+        let err = zip_raw_mask(&bits, 73, &truthy, &falsy).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: mask of 9 bytes is too short for length 73"
+        );
+    }
+
+    #[test]
+    fn test_zip_record_batches() {
+        let truthy = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)])) as _,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    Some("b"),
+                    None,
+                    Some("d"),
+                ])) as _,
+            ),
+            (
+                "c",
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0, 4.0])) as _,
+            ),
+        ])
+        .unwrap();
+        let falsy = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![Some(10), Some(20), None, Some(40)])) as _,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from(vec![
+                    None,
+                    Some("f"),
+                    Some("g"),
+                    Some("h"),
+                ])) as _,
+            ),
+            (
+                "c",
+                Arc::new(Float64Array::from(vec![10.0, 20.0, 30.0, 40.0])) as _,
+            ),
+        ])
+        .unwrap();
+
+        let mask = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+        let out = zip_record_batches(&mask, &truthy, &falsy).unwrap();
+
+        assert_eq!(out.schema(), truthy.schema());
+        assert_eq!(out.num_rows(), 4);
+        for (i, column) in out.columns().iter().enumerate() {
+            let expected = zip(&mask, truthy.column(i), falsy.column(i)).unwrap();
+            assert_eq!(column, &expected);
+        }
+    }
+
+    #[test]
+    fn test_zip_record_batches_invalid() {
+        let truthy =
+            RecordBatch::try_from_iter([("a", Arc::new(Int32Array::from(vec![1, 2])) as _)])
+                .unwrap();
+        let falsy =
+            RecordBatch::try_from_iter([("b", Arc::new(Int32Array::from(vec![3, 4])) as _)])
+                .unwrap();
+        let mask = BooleanArray::from(vec![true, false]);
+        let err = zip_record_batches(&mask, &truthy, &falsy).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: record batches need to have the same schema"
+        );
+
+        let mask = BooleanArray::from(vec![true, false, true]);
+        let err = zip_record_batches(&mask, &truthy, &truthy).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all record batches should have the same number of rows as the mask"
+        );
+    }
+
+    #[test]
+    fn test_zip_datum_mask_scalar_true() {
+        let truthy = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let falsy = Int32Array::from(vec![Some(10), Some(20), None]);
+        let mask = Scalar::new(BooleanArray::from(vec![true]));
+
+        let out = zip_datum_mask(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(out.as_primitive::<Int32Type>(), &truthy);
+        // The truthy array is returned without copying
+        assert!(out.to_data().ptr_eq(&truthy.to_data()));
+
+        let sliced = truthy.slice(1, 2);
+        let out = zip_datum_mask(&mask, &sliced, &Int32Array::new_scalar(5)).unwrap();
+        assert!(out.to_data().ptr_eq(&sliced.to_data()));
+
+        // A scalar truthy value is broadcast to the length of falsy
+        let out = zip_datum_mask(&mask, &Int32Array::new_scalar(5), &falsy).unwrap();
+        assert_eq!(
+            out.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![5, 5, 5])
+        );
+
+        let out = zip_datum_mask(
+            &mask,
+            &Int32Array::new_scalar(5),
+            &Int32Array::new_scalar(6),
+        )
+        .unwrap();
+        assert_eq!(out.as_primitive::<Int32Type>(), &Int32Array::from(vec![5]));
+    }
+
+    #[test]
+    fn test_zip_datum_mask_scalar_false() {
+        let truthy = StringArray::from(vec![Some("a"), None, Some("c")]);
+        let falsy = StringArray::from(vec![Some("x"), Some("y"), None]);
+
+        for mask in [
+            Scalar::new(BooleanArray::from(vec![false])),
+            Scalar::new(BooleanArray::new_null(1)),
+        ] {
+            let out = zip_datum_mask(&mask, &truthy, &falsy).unwrap();
+            assert_eq!(out.as_string::<i32>(), &falsy);
+            // The falsy array is returned without copying
+            assert!(out.to_data().ptr_eq(&falsy.to_data()));
+
+            let out = zip_datum_mask(&mask, &truthy, &StringArray::new_scalar("z")).unwrap();
+            assert_eq!(
+                out.as_string::<i32>(),
+                &StringArray::from(vec!["z", "z", "z"])
+            );
+        }
+    }
+
+    #[test]
+    fn test_zip_datum_mask_array() {
+        let truthy = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let falsy = Int32Array::from(vec![Some(10), Some(20), None, Some(40)]);
+        let mask = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+
+        let out = zip_datum_mask(&mask, &truthy, &falsy).unwrap();
+        let expected = zip(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(&out, &expected);
+
+        let out = zip_datum_mask(&mask, &truthy, &Int32Array::new_scalar(7)).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(7), Some(7), Some(4)]);
+        assert_eq!(out.as_primitive::<Int32Type>(), &expected);
+    }
+
+    #[test]
+    fn test_zip_datum_mask_invalid() {
+        let truthy = Int32Array::from(vec![1, 2, 3]);
+        let falsy = Int32Array::from(vec![4, 5]);
+        let mask = Scalar::new(BooleanArray::from(vec![true]));
+
+        let err = zip_datum_mask(&mask, &truthy, &falsy).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all arrays should have the same length"
+        );
+
+        let err = zip_datum_mask(&mask, &truthy, &StringArray::new_scalar("a")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arguments need to have the same data type"
+        );
+
+        let mask = Int32Array::new_scalar(1);
+        let err = zip_datum_mask(&mask, &truthy, &truthy).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: mask needs to be of type Boolean, got Int32"
+        );
+    }
+
+    fn test_zip_temporal<T: ArrowPrimitiveType>() {
+        let v = |i: usize| Some(T::Native::usize_as(i));
+        let a = PrimitiveArray::<T>::from_iter([v(1), None, v(3), v(4)]);
+        let b = PrimitiveArray::<T>::from_iter([v(10), v(20), None, v(40)]);
+        let mask = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+
+        let out = zip(&mask, &a, &b).unwrap();
+        assert_eq!(out.data_type(), &T::DATA_TYPE);
+        let expected = PrimitiveArray::<T>::from_iter([v(1), v(20), None, v(4)]);
+        assert_eq!(out.as_primitive::<T>(), &expected);
+
+        let scalar = PrimitiveArray::<T>::new_scalar(T::Native::usize_as(7));
+        let out = zip(&mask, &a, &scalar).unwrap();
+        assert_eq!(out.data_type(), &T::DATA_TYPE);
+        let expected = PrimitiveArray::<T>::from_iter([v(1), v(7), v(7), v(4)]);
+        assert_eq!(out.as_primitive::<T>(), &expected);
+
+        let out = zip(&mask, &scalar, &b).unwrap();
+        assert_eq!(out.data_type(), &T::DATA_TYPE);
+        let expected = PrimitiveArray::<T>::from_iter([v(7), v(20), None, v(7)]);
+        assert_eq!(out.as_primitive::<T>(), &expected);
+    }
+
+    #[test]
+    fn test_zip_date() {
+        test_zip_temporal::<Date32Type>();
+        test_zip_temporal::<Date64Type>();
+    }
+
+    #[test]
+    fn test_zip_time() {
+        test_zip_temporal::<Time32SecondType>();
+        test_zip_temporal::<Time64NanosecondType>();
+    }
+
+    #[test]
+    fn test_zip_union() {
+        let mask = BooleanArray::from(vec![Some(true), Some(false), None, Some(true), Some(false)]);
+        let truthy = Int32Array::from(vec![Some(1), Some(2), Some(3), None, Some(5)]);
+        let falsy = StringArray::from(vec![Some("a"), None, Some("c"), Some("d"), Some("e")]);
+
+        let result = zip_union(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(result.len(), 5);
+        assert_eq!(result.type_ids(), &[0, 1, 1, 0, 1]);
+        assert!(result.offsets().is_none());
+
+        let DataType::Union(fields, UnionMode::Sparse) = result.data_type() else {
+            panic!("expected sparse union, got {}", result.data_type())
+        };
+        let fields = fields
+            .iter()
+            .map(|(id, f)| (id, f.name().as_str(), f.data_type()));
+        assert_eq!(
+            fields.collect::<Vec<_>>(),
+            vec![
+                (0, "truthy", &DataType::Int32),
+                (1, "falsy", &DataType::Utf8)
+            ]
+        );
+
+        // The branches are used as children without copying
+        assert!(result.child(0).to_data().ptr_eq(&truthy.to_data()));
+        assert!(result.child(1).to_data().ptr_eq(&falsy.to_data()));
+
+        let value = result.value(0);
+        assert_eq!(value.as_primitive::<Int32Type>().value(0), 1);
+        assert!(result.value(1).is_null(0));
+        assert_eq!(result.value(2).as_string::<i32>().value(0), "c");
+        assert!(result.value(3).is_null(0));
+        assert_eq!(result.value(4).as_string::<i32>().value(0), "e");
+
+        let logical_nulls = result.logical_nulls().unwrap();
+        let valid = logical_nulls.iter().collect::<Vec<_>>();
+        assert_eq!(valid, vec![true, false, true, false, true]);
+    }
+
+    #[test]
+    fn test_zip_union_invalid() {
+        let mask = BooleanArray::from(vec![true, false]);
+        let truthy = Int32Array::from(vec![1, 2]);
+        let falsy = StringArray::from(vec!["a"]);
+        let err = zip_union(&mask, &truthy, &falsy).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all arrays should have the same length, got mask of length 2, truthy of length 2 and falsy of length 1"
+        );
+    }
+
+    #[test]
+    fn test_zip_mask_over_chunks() {
+        let mask = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+        ]);
+        let truthy: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])),
+            Arc::new(StringArray::from(Vec::<&str>::new())),
+            Arc::new(StringArray::from(vec!["d", "e", "f", "g", "h"])),
+        ];
+        let falsy: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(vec!["A", "B", "C", "D", "E"])),
+            Arc::new(StringArray::from(vec![Some("F"), None])),
+            Arc::new(StringArray::from(vec!["H"])),
+        ];
+        let truthy: Vec<&dyn Array> = truthy.iter().map(|a| a.as_ref()).collect();
+        let falsy: Vec<&dyn Array> = falsy.iter().map(|a| a.as_ref()).collect();
+
+        let result = zip_mask_over_chunks(&mask, &truthy, &falsy).unwrap();
+        let lengths: Vec<_> = result.iter().map(|a| a.len()).collect();
+        assert_eq!(lengths, vec![3, 2, 2, 1]);
+
+        let result: Vec<&dyn Array> = result.iter().map(|a| a.as_ref()).collect();
+        let actual = crate::concat::concat(&result).unwrap();
+        let expected = zip(
+            &mask,
+            &crate::concat::concat(&truthy).unwrap(),
+            &crate::concat::concat(&falsy).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(actual.as_ref(), expected.as_ref());
+    }
+
+    #[test]
+    fn test_zip_mask_over_chunks_invalid() {
+        let mask = BooleanArray::from(vec![true, false, true]);
+        let a = Int32Array::from(vec![1, 2]);
+        let b = Int32Array::from(vec![3]);
+        let err = zip_mask_over_chunks(&mask, &[&a, &b], &[&a]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: total length of chunks should be the same as the mask, got 3 and 2 for mask of length 3"
+        );
+
+        let result = zip_mask_over_chunks(&BooleanArray::from(Vec::<bool>::new()), &[], &[]);
+        assert!(result.unwrap().is_empty());
+    }
+
+    /// Returns a `List<Struct<{l: LargeList<FixedSizeList<Int32>>}>>` array of length 2
+    fn nested_array(v: i32) -> ArrayRef {
+        let inner = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            [
+                Some(vec![Some(v), Some(v + 1)]),
+                None,
+                Some(vec![Some(v + 2), None]),
+            ],
+            2,
+        );
+        let inner_field = Arc::new(Field::new_list_field(inner.data_type().clone(), true));
+        let offsets = OffsetBuffer::from_lengths([2, 1]);
+        let large = LargeListArray::new(inner_field, offsets, Arc::new(inner), None);
+        let fields = Fields::from(vec![Field::new("l", large.data_type().clone(), true)]);
+        let strukt = StructArray::new(fields, vec![Arc::new(large)], None);
+        let struct_field = Arc::new(Field::new_list_field(strukt.data_type().clone(), true));
+        let offsets = OffsetBuffer::from_lengths([1, 1]);
+        Arc::new(ListArray::new(
+            struct_field,
+            offsets,
+            Arc::new(strukt),
+            None,
+        ))
+    }
+
+    #[test]
+    fn test_zip_deeply_nested() {
+        let truthy = nested_array(0);
+        let falsy = nested_array(100);
+        let mask = BooleanArray::from(vec![false, true]);
+
+        let result = zip(&mask, &truthy, &falsy).unwrap();
+        result.to_data().validate_full().unwrap();
+        assert_eq!(result.data_type(), truthy.data_type());
+        assert_eq!(result.slice(0, 1).as_ref(), falsy.slice(0, 1).as_ref());
+        assert_eq!(result.slice(1, 1).as_ref(), truthy.slice(1, 1).as_ref());
+    }
+
+    #[test]
+    fn test_zip_incompatible_layout() {
+        let truthy = nested_array(0);
+        let falsy = nested_array(100).to_data();
+
+        // Replace the innermost Int32 values with Int64 values, without validation
+        fn replace_leaf(data: &ArrayData) -> ArrayData {
+            let builder = data.clone().into_builder();
+            let builder = match data.child_data().first() {
+                Some(child) => builder.child_data(vec![replace_leaf(child)]),
+                None => {
+                    let values = Int64Array::from(vec![0; data.len()]);
+                    return values
+                        .into_data()
+                        .into_builder()
+                        .nulls(data.nulls().cloned())
+                        .build()
+                        .unwrap();
+                }
+            };
+            unsafe { builder.build_unchecked() }
+        }
+        let falsy = make_array(replace_leaf(&falsy));
+        assert_eq!(truthy.data_type(), falsy.data_type());
+
+        let mask = BooleanArray::from(vec![false, true]);
+        let err = zip(&mask, &truthy, &falsy).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arguments need to have the same layout, found children of type Int32 and Int64"
+        );
+    }
+
+    #[test]
+    fn test_zip_unsupported_nested_type() {
+        let run_ends = Int32Array::from(vec![2]);
+        let values = Int32Array::from(vec![1]);
+        let ree = RunArray::try_new(&run_ends, &values).unwrap();
+        let fields = Fields::from(vec![Field::new("r", ree.data_type().clone(), true)]);
+        let array = StructArray::new(fields, vec![Arc::new(ree)], None);
+
+        let mask = BooleanArray::from(vec![false, true]);
+        let err = zip(&mask, &array, &array).unwrap_err().to_string();
+        assert!(
+            err.starts_with(
+                "Not yet implemented: zip is not supported for data type RunEndEncoded"
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ffi")]
+    fn test_zip_to_ffi() {
+        use arrow_array::ffi::{from_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+
+        let mask = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+        let truthy = StringArray::from(vec![Some("a"), None, Some("c"), None]);
+        let falsy = StringArray::from(vec![Some("w"), Some("x"), Some("y"), Some("z")]);
+        let expected = zip(&mask, &truthy, &falsy).unwrap();
+
+        let mut out = FFI_ArrowArray::empty();
+        let mut schema = FFI_ArrowSchema::empty();
+        zip_to_ffi(&mask, &truthy, &falsy, &mut out, &mut schema).unwrap();
+        assert_eq!(out.len(), 4);
+        assert_eq!(schema.format(), "u");
+
+        let data = unsafe { from_ffi(out, &schema) }.unwrap();
+        assert_eq!(make_array(data).as_ref(), expected.as_ref());
+
+        // Errors leave the outputs untouched
+        let mut out = FFI_ArrowArray::empty();
+        let mut schema = FFI_ArrowSchema::empty();
+        let other = Int32Array::from(vec![1, 2, 3, 4]);
+        let err = zip_to_ffi(&mask, &truthy, &other, &mut out, &mut schema).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arguments need to have the same data type"
+        );
+        assert!(out.is_released());
+    }
 }
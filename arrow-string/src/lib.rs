@@ -32,3 +32,4 @@ pub mod like;
 mod predicate;
 pub mod regexp;
 pub mod substring;
+pub mod translate;
@@ -555,6 +555,106 @@ pub fn regexp_match(
     }
 }
 
+/// Extract the capture group `group` of the leftmost-first match of the regular
+/// expression `pattern` in each string of `array`
+///
+/// Modelled after the Spark [regexp_extract]. Group `0` is the substring matching the
+/// whole pattern, and group `n` the substring matching the n'th capturing parenthesized
+/// subexpression. The result is null where the string is null, the pattern does not
+/// match, or the group does not participate in the match.
+///
+/// `pattern` is compiled once and reused for every row.
+///
+/// # Errors
+/// This function errors if `pattern` does not compile, or does not have a group `group`
+///
+/// # Example
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_string::regexp::regexp_extract;
+/// let array = StringArray::from(vec![Some("100-200"), Some("foo"), None, Some("3-4")]);
+/// let result = regexp_extract(&array, r"(\d+)-(\d+)", 2).unwrap();
+/// assert_eq!(result, StringArray::from(vec![Some("200"), None, None, Some("4")]));
+/// ```
+///
+/// [regexp_extract]: https://spark.apache.org/docs/latest/api/sql/index.html#regexp_extract
+pub fn regexp_extract(
+    array: &StringArray,
+    pattern: &str,
+    group: usize,
+) -> Result<StringArray, ArrowError> {
+    let re = Regex::new(pattern).map_err(|e| {
+        ArrowError::ComputeError(format!("Regular expression did not compile: {e:?}"))
+    })?;
+    if group >= re.captures_len() {
+        return Err(ArrowError::ComputeError(format!(
+            "regexp_extract() regular expression has {} groups, but the specified group index is {group}",
+            re.captures_len() - 1
+        )));
+    }
+
+    let mut locations = re.capture_locations();
+    Ok(array
+        .iter()
+        .map(|value| {
+            let value = value?;
+            re.captures_read(&mut locations, value)?;
+            let (start, end) = locations.get(group)?;
+            Some(&value[start..end])
+        })
+        .collect())
+}
+
+/// Count the non-overlapping matches of the regular expression `pattern` in each
+/// string of `array`
+///
+/// Modelled after the Postgres [regexp_count]. The result is null where the string is
+/// null. The optional `flags` are as per [`regexp_is_match_scalar`], and `pattern` is
+/// compiled once and reused for every row.
+///
+/// # Errors
+/// This function errors if `pattern` does not compile
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int32Array, StringArray};
+/// # use arrow_string::regexp::regexp_count;
+/// let array = StringArray::from(vec![Some("abcabc"), Some("ABC"), None, Some("xyz")]);
+/// let result = regexp_count(&array, "abc", None).unwrap();
+/// assert_eq!(result, Int32Array::from(vec![Some(2), Some(0), None, Some(0)]));
+///
+/// let result = regexp_count(&array, "abc", Some("i")).unwrap();
+/// assert_eq!(result, Int32Array::from(vec![Some(2), Some(1), None, Some(0)]));
+/// ```
+///
+/// [regexp_count]: https://www.postgresql.org/docs/current/functions-matching.html#FUNCTIONS-POSIX-REGEXP
+pub fn regexp_count(
+    array: &StringArray,
+    pattern: &str,
+    flags: Option<&str>,
+) -> Result<Int32Array, ArrowError> {
+    let pattern = match flags {
+        Some(flags) => format!("(?{flags}){pattern}"),
+        None => pattern.to_string(),
+    };
+    let re = Regex::new(pattern.as_str()).map_err(|e| {
+        ArrowError::ComputeError(format!("Regular expression did not compile: {e:?}"))
+    })?;
+
+    array
+        .iter()
+        .map(|value| {
+            value
+                .map(|value| {
+                    i32::try_from(re.find_iter(value).count()).map_err(|_| {
+                        ArrowError::ComputeError("regexp_count() overflowed Int32".to_string())
+                    })
+                })
+                .transpose()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1068,4 +1168,81 @@ mod tests {
         regexp_is_match_scalar::<StringViewArray>,
         [true, true, false, false]
     );
+
+    #[test]
+    fn test_regexp_extract() {
+        let array = StringArray::from(vec![
+            Some("key=value"),
+            Some("key="),
+            Some("no match"),
+            None,
+            Some("a=1 b=2"),
+        ]);
+
+        let result = regexp_extract(&array, r"(\w+)=(\w*)", 0).unwrap();
+        let expected = StringArray::from(vec![
+            Some("key=value"),
+            Some("key="),
+            None,
+            None,
+            Some("a=1"),
+        ]);
+        assert_eq!(result, expected);
+
+        let result = regexp_extract(&array, r"(\w+)=(\w*)", 2).unwrap();
+        let expected = StringArray::from(vec![Some("value"), Some(""), None, None, Some("1")]);
+        assert_eq!(result, expected);
+
+        // Groups that do not participate in the match are null
+        let result = regexp_extract(&array, r"(\w+)=(?:(\d)|\w*)", 2).unwrap();
+        let expected = StringArray::from(vec![None, None, None, None, Some("1")]);
+        assert_eq!(result, expected);
+
+        let result = regexp_extract(&array.slice(3, 2), "(?i)(B)=", 1).unwrap();
+        assert_eq!(result, StringArray::from(vec![None, Some("b")]));
+
+        let err = regexp_extract(&array, r"(\w+)=", 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_extract() regular expression has 1 groups, but the specified group index is 2"
+        );
+        let err = regexp_extract(&array, "(", 0).unwrap_err();
+        assert!(err.to_string().contains("did not compile"), "{err}");
+    }
+
+    #[test]
+    fn test_regexp_count() {
+        let array = StringArray::from(vec![
+            Some("aaaa"),
+            Some("a1b22c333"),
+            Some(""),
+            None,
+            Some("AaA"),
+        ]);
+
+        // Matches do not overlap
+        let result = regexp_count(&array, "aa", None).unwrap();
+        assert_eq!(
+            result,
+            Int32Array::from(vec![Some(2), Some(0), Some(0), None, Some(0)])
+        );
+
+        let result = regexp_count(&array, r"\d+", None).unwrap();
+        assert_eq!(
+            result,
+            Int32Array::from(vec![Some(0), Some(3), Some(0), None, Some(0)])
+        );
+
+        let result = regexp_count(&array, "a", Some("i")).unwrap();
+        assert_eq!(
+            result,
+            Int32Array::from(vec![Some(4), Some(1), Some(0), None, Some(3)])
+        );
+
+        let result = regexp_count(&array.slice(3, 2), "A", None).unwrap();
+        assert_eq!(result, Int32Array::from(vec![None, Some(2)]));
+
+        let err = regexp_count(&array, "(", None).unwrap_err();
+        assert!(err.to_string().contains("did not compile"), "{err}");
+    }
 }
@@ -19,7 +19,8 @@
 //! Supported array types:
 //! [GenericStringArray], [GenericBinaryArray], [FixedSizeBinaryArray], [DictionaryArray]
 
-use arrow_array::builder::BufferBuilder;
+use arrow_array::builder::{BufferBuilder, StringBuilder};
+use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, Buffer, MutableBuffer};
@@ -226,6 +227,97 @@ pub fn substring_by_char<OffsetSize: OffsetSizeTrait>(
     Ok(GenericStringArray::<OffsetSize>::from(data))
 }
 
+/// Replaces `len` characters of each value of `array`, starting at the 1-based
+/// character position `pos`, with `replacement`. This can be used to implement SQL
+/// `OVERLAY(array PLACING replacement FROM pos FOR len)`
+///
+/// `replacement` must be [`DataType::Utf8`], and `pos` and `len` [`DataType::Int64`],
+/// each either an array of the same length as `array` or a scalar. Positions before
+/// the first character are clamped to the first character, and positions after the
+/// last character append `replacement`. A negative `len` is treated as zero, in which
+/// case `replacement` is inserted. The result is null where any argument is null.
+///
+/// # Errors
+/// This function errors if an argument does not have the expected data type, or is
+/// an array of a different length than `array`
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int64Array, StringArray};
+/// # use arrow_string::substring::overlay;
+/// let array = StringArray::from(vec![Some("Txxxxas"), None, Some("αβγ")]);
+/// let replacement = StringArray::new_scalar("hom");
+/// let pos = Int64Array::from(vec![2, 1, 2]);
+/// let len = Int64Array::new_scalar(4);
+/// let result = overlay(&array, &replacement, &pos, &len).unwrap();
+/// assert_eq!(result, StringArray::from(vec![Some("Thomas"), None, Some("αhom")]));
+/// ```
+pub fn overlay(
+    array: &StringArray,
+    replacement: &dyn Datum,
+    pos: &dyn Datum,
+    len: &dyn Datum,
+) -> Result<StringArray, ArrowError> {
+    let (replacement, replacement_is_scalar) =
+        overlay_arg(replacement, "replacement", DataType::Utf8, array.len())?;
+    let (pos, pos_is_scalar) = overlay_arg(pos, "pos", DataType::Int64, array.len())?;
+    let (len, len_is_scalar) = overlay_arg(len, "len", DataType::Int64, array.len())?;
+    let replacement = replacement.as_string::<i32>();
+    let pos = pos.as_primitive::<Int64Type>();
+    let len = len.as_primitive::<Int64Type>();
+
+    let index = |is_scalar: bool, i: usize| if is_scalar { 0 } else { i };
+    let mut builder = StringBuilder::with_capacity(array.len(), array.value_data().len());
+    for i in 0..array.len() {
+        let r = index(replacement_is_scalar, i);
+        let p = index(pos_is_scalar, i);
+        let l = index(len_is_scalar, i);
+        if array.is_null(i) || replacement.is_null(r) || pos.is_null(p) || len.is_null(l) {
+            builder.append_null();
+            continue;
+        }
+
+        let val = array.value(i);
+        let start = pos.value(p).saturating_sub(1).max(0);
+        let start = usize::try_from(start).unwrap_or(usize::MAX);
+        let length = usize::try_from(len.value(l).max(0)).unwrap_or(usize::MAX);
+        let (start_offset, end_offset) = get_start_end_offset(val, start, Some(length));
+        builder.append_value(
+            [
+                &val[..start_offset],
+                replacement.value(r),
+                &val[end_offset..],
+            ]
+            .concat(),
+        );
+    }
+    Ok(builder.finish())
+}
+
+/// Returns the array of an argument of [`overlay`] and whether it is a scalar,
+/// checking its data type and length
+fn overlay_arg<'a>(
+    datum: &'a dyn Datum,
+    name: &str,
+    data_type: DataType,
+    len: usize,
+) -> Result<(&'a dyn Array, bool), ArrowError> {
+    let (array, is_scalar) = datum.get();
+    if array.data_type() != &data_type {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "overlay requires {name} of type {data_type}, got {}",
+            array.data_type()
+        )));
+    }
+    if !is_scalar && array.len() != len {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "overlay requires {name} of the same length as the array or a scalar, got {} and {len}",
+            array.len()
+        )));
+    }
+    Ok((array, is_scalar))
+}
+
 /// * `val` - string
 /// * `start` - the start char index of the substring
 /// * `length` - the char length of the substring
@@ -976,4 +1068,84 @@ mod tests {
         let expected = BinaryArray::from(vec![Some(expected_bytes)]);
         assert_eq!(expected, *actual);
     }
+
+    #[test]
+    fn test_overlay() {
+        let array = StringArray::from(vec![
+            Some("abcdef"),
+            Some("abcdef"),
+            Some("abcdef"),
+            Some("abcdef"),
+            Some("abcdef"),
+            None,
+            Some("Γ ⊢x:T"),
+        ]);
+        let replacement = StringArray::from(vec![
+            Some("XY"),
+            Some("XY"),
+            Some("XY"),
+            Some("XY"),
+            None,
+            Some("XY"),
+            Some("🦀"),
+        ]);
+        let pos = Int64Array::from(vec![3, -5, 10, 2, 1, 1, 3]);
+        let len = Int64Array::from(vec![
+            Some(2),
+            Some(1),
+            Some(3),
+            Some(-1),
+            Some(1),
+            Some(1),
+            Some(2),
+        ]);
+        let result = overlay(&array, &replacement, &pos, &len).unwrap();
+        let expected = StringArray::from(vec![
+            Some("abXYef"),
+            // Positions are clamped to the first character
+            Some("XYbcdef"),
+            // Positions after the end append
+            Some("abcdefXY"),
+            // A negative length inserts
+            Some("aXYbcdef"),
+            None,
+            None,
+            // Positions count characters
+            Some("Γ 🦀:T"),
+        ]);
+        assert_eq!(result, expected);
+
+        // Scalar arguments, and a length past the end
+        let replacement = StringArray::new_scalar("Z");
+        let pos = Int64Array::new_scalar(6);
+        let len = Int64Array::new_scalar(i64::MAX);
+        let result = overlay(&array.slice(5, 2), &replacement, &pos, &len).unwrap();
+        assert_eq!(result, StringArray::from(vec![None, Some("Γ ⊢x:Z")]));
+
+        // Null scalars
+        let null = Scalar::new(Int64Array::new_null(1));
+        let result = overlay(&array, &replacement, &null, &len).unwrap();
+        assert_eq!(result.null_count(), array.len());
+    }
+
+    #[test]
+    fn test_overlay_invalid() {
+        let array = StringArray::from(vec!["abc", "def"]);
+        let replacement = StringArray::new_scalar("x");
+        let pos = Int64Array::new_scalar(1);
+
+        let len = Int32Array::new_scalar(1);
+        let err = overlay(&array, &replacement, &pos, &len).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: overlay requires len of type Int64, got Int32"
+        );
+
+        let pos = Int64Array::from(vec![1, 2, 3]);
+        let err = overlay(&array, &replacement, &pos, &pos).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: overlay requires pos of the same length as the array or a scalar, got 3 and 2"
+        );
+    }
 }
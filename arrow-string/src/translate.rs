@@ -0,0 +1,122 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernel to replace characters of string arrays
+
+use arrow_array::builder::StringBuilder;
+use arrow_array::*;
+use std::collections::HashMap;
+
+/// Replaces each character of each value of `array` that is in `from` with the
+/// character at the same position in `to`, or removes it if `to` has no character
+/// at that position. This can be used to implement SQL `TRANSLATE`
+///
+/// Characters are Unicode scalar values. If a character occurs more than once in
+/// `from`, its first occurrence is used. Nulls are preserved.
+///
+/// # Example
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_string::translate::translate;
+/// let array = StringArray::from(vec![Some("12345"), None, Some("αβγ")]);
+/// let result = translate(&array, "143β", "ax");
+/// assert_eq!(result, StringArray::from(vec![Some("a2x5"), None, Some("αγ")]));
+/// ```
+pub fn translate(array: &StringArray, from: &str, to: &str) -> StringArray {
+    let mut to = to.chars();
+    let mut replacements = HashMap::new();
+    for c in from.chars() {
+        let replacement = to.next();
+        replacements.entry(c).or_insert(replacement);
+    }
+
+    let capacity = array.value_data().len();
+    let mut builder = StringBuilder::with_capacity(array.len(), capacity);
+    let mut value = String::new();
+    for v in array {
+        match v {
+            Some(v) => {
+                value.clear();
+                value.extend(v.chars().filter_map(|c| match replacements.get(&c) {
+                    Some(replacement) => *replacement,
+                    None => Some(c),
+                }));
+                builder.append_value(&value);
+            }
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translate() {
+        let array = StringArray::from(vec![
+            Some("hello world"),
+            None,
+            Some(""),
+            Some("straße"),
+            Some("🦀 crab 🦀"),
+        ]);
+
+        // Characters are replaced, and deleted where `to` is shorter
+        let result = translate(&array, "lo ", "01");
+        let expected = StringArray::from(vec![
+            Some("he001w1r0d"),
+            None,
+            Some(""),
+            Some("straße"),
+            Some("🦀crab🦀"),
+        ]);
+        assert_eq!(result, expected);
+
+        // Multi-byte characters on either side
+        let result = translate(&array, "ß🦀o", "sπ");
+        let expected = StringArray::from(vec![
+            Some("hell wrld"),
+            None,
+            Some(""),
+            Some("strase"),
+            Some("π crab π"),
+        ]);
+        assert_eq!(result, expected);
+
+        // The first occurrence of a repeated character is used, and extra
+        // characters of `to` are ignored
+        let result = translate(&array, "lol", "LOXYZ");
+        let expected = StringArray::from(vec![
+            Some("heLLO wOrLd"),
+            None,
+            Some(""),
+            Some("straße"),
+            Some("🦀 crab 🦀"),
+        ]);
+        assert_eq!(result, expected);
+
+        // Empty `from` returns the input
+        assert_eq!(translate(&array, "", "abc"), array);
+
+        // Sliced input
+        let sliced = array.slice(3, 2);
+        let result = translate(&sliced, "a", "A");
+        assert_eq!(result, StringArray::from(vec!["strAße", "🦀 crAb 🦀"]));
+    }
+}
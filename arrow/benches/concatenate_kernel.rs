@@ -53,6 +53,12 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| bench_concat_arrays(&arrays))
     });
 
+    let small_array = create_primitive_array::<Int32Type>(64, 0.5);
+    let arrays: Vec<_> = (0..1024).map(|_| &small_array as &dyn Array).collect();
+    c.bench_function("concat 1024 arrays i32 nulls 64", |b| {
+        b.iter(|| bench_concat_arrays(&arrays))
+    });
+
     {
         let input = (0..100)
             .map(|_| create_primitive_array::<Int32Type>(8192, 0.0))
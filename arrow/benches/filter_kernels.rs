@@ -23,7 +23,7 @@ use arrow::util::bench_util::*;
 
 use arrow::array::*;
 use arrow::compute::filter;
-use arrow::datatypes::{Field, Float32Type, Int32Type, Int64Type, Schema, UInt8Type};
+use arrow::datatypes::{DataType, Field, Float32Type, Int32Type, Int64Type, Schema, UInt8Type};
 
 use arrow_array::types::Decimal128Type;
 use criterion::{criterion_group, criterion_main, Criterion};
@@ -296,5 +296,47 @@ fn add_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, add_benchmark);
+fn add_struct_benchmark(c: &mut Criterion) {
+    let size = 65536;
+    let filter_array = create_boolean_array(size, 0.0, 0.5);
+    let sparse_filter_array = create_boolean_array(size, 0.0, 1.0 / 1024.0);
+
+    let inner = StructArray::from(vec![
+        (
+            Arc::new(Field::new("c", DataType::Utf8, true)),
+            Arc::new(create_string_array::<i32>(size, 0.2)) as ArrayRef,
+        ),
+        (
+            Arc::new(Field::new("d", DataType::Float32, true)),
+            Arc::new(create_primitive_array::<Float32Type>(size, 0.2)) as ArrayRef,
+        ),
+    ]);
+    let inner_nulls = create_boolean_array(size, 0.0, 0.9).values().clone();
+    let (fields, columns, _) = inner.into_parts();
+    let inner = StructArray::new(fields, columns, Some(inner_nulls.into()));
+    let data_array = StructArray::from(vec![
+        (
+            Arc::new(Field::new("a", DataType::Int32, true)),
+            Arc::new(create_primitive_array::<Int32Type>(size, 0.1)) as ArrayRef,
+        ),
+        (
+            Arc::new(Field::new("b", inner.data_type().clone(), true)),
+            Arc::new(inner) as ArrayRef,
+        ),
+    ]);
+
+    c.bench_function("filter nested struct (kept 1/2)", |b| {
+        b.iter(|| bench_filter(&data_array, &filter_array))
+    });
+    c.bench_function("filter nested struct low selectivity (kept 1/1024)", |b| {
+        b.iter(|| bench_filter(&data_array, &sparse_filter_array))
+    });
+
+    let filter = FilterBuilder::new(&filter_array).build();
+    c.bench_function("filter built nested struct unoptimized (kept 1/2)", |b| {
+        b.iter(|| bench_built_filter(&filter, &data_array))
+    });
+}
+
+criterion_group!(benches, add_benchmark, add_struct_benchmark);
 criterion_main!(benches);
@@ -0,0 +1,96 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Compares [`FilterPredicate::filter`] with [`FilterContext::filter_reuse`] in a loop,
+//! reporting the number of allocations of each in addition to the time taken
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+use arrow::array::*;
+use arrow::compute::{FilterBuilder, FilterContext, FilterPredicate};
+use arrow::datatypes::Int32Type;
+use arrow::util::bench_util::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const ITERATIONS: usize = 10_000;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+#[global_allocator]
+static GLOBAL_ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// SAFETY: only counts allocations, deferring to `System` for the actual work
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Returns the number of allocations made by `f`
+fn count_allocations(mut f: impl FnMut()) -> usize {
+    let start = ALLOCATIONS.load(Relaxed);
+    f();
+    ALLOCATIONS.load(Relaxed) - start
+}
+
+fn filter_loop(filter: &FilterPredicate, array: &dyn Array) {
+    for _ in 0..ITERATIONS {
+        criterion::black_box(filter.filter(array).unwrap());
+    }
+}
+
+fn filter_reuse_loop(ctx: &mut FilterContext, filter: &FilterPredicate, array: &dyn Array) {
+    for _ in 0..ITERATIONS {
+        criterion::black_box(ctx.filter_reuse(array, filter).unwrap());
+    }
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    let size = 1024;
+    let filter_array = create_boolean_array(size, 0.0, 0.5);
+    let filter = FilterBuilder::new(&filter_array).optimize().build();
+    let data_array = create_primitive_array::<Int32Type>(size, 0.0);
+
+    let mut ctx = FilterContext::new();
+    let filter_allocations = count_allocations(|| filter_loop(&filter, &data_array));
+    let reuse_allocations = count_allocations(|| filter_reuse_loop(&mut ctx, &filter, &data_array));
+    println!("filter i32 {ITERATIONS} iterations: {filter_allocations} allocations");
+    println!("filter_reuse i32 {ITERATIONS} iterations: {reuse_allocations} allocations");
+
+    c.bench_function("filter i32 10000 iterations (kept 1/2)", |b| {
+        b.iter(|| filter_loop(&filter, &data_array))
+    });
+    c.bench_function("filter_reuse i32 10000 iterations (kept 1/2)", |b| {
+        b.iter(|| filter_reuse_loop(&mut ctx, &filter, &data_array))
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -23,7 +23,7 @@ use rand::Rng;
 
 extern crate arrow;
 
-use arrow::compute::{take, TakeOptions};
+use arrow::compute::{take, take_run_decoded, TakeOptions};
 use arrow::datatypes::*;
 use arrow::util::test_util::seedable_rng;
 use arrow::{array::*, util::bench_util::*};
@@ -115,6 +115,18 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| bench_take(&values, &indices))
     });
 
+    let values = create_boolean_array(1 << 20, 0.0, 0.5);
+    let indices = create_random_index(1 << 20, 0.0);
+    c.bench_function("take bool 1048576", |b| {
+        b.iter(|| bench_take(&values, &indices))
+    });
+
+    let values = create_boolean_array(1 << 20, 0.5, 0.5);
+    let indices = create_random_index(1 << 20, 0.5);
+    c.bench_function("take bool null values null indices 1048576", |b| {
+        b.iter(|| bench_take(&values, &indices))
+    });
+
     let values = create_string_array::<i32>(512, 0.0);
     let indices = create_random_index(512, 0.0);
     c.bench_function("take str 512", |b| b.iter(|| bench_take(&values, &indices)));
@@ -191,6 +203,26 @@ fn add_benchmark(c: &mut Criterion) {
         "take primitive run logical len: 1024, physical len: 512, indices: 1024",
         |b| b.iter(|| bench_take(&values, &indices)),
     );
+
+    let values = create_primitive_run_array::<Int32Type, Int32Type>(65536, 1024);
+    let indices = create_random_index(4096, 0.1);
+    c.bench_function(
+        "take_run_decoded primitive run logical len: 65536, physical len: 1024, indices: 4096",
+        |b| b.iter(|| criterion::black_box(take_run_decoded(&values, &indices).unwrap())),
+    );
+    c.bench_function(
+        "decode then take primitive run logical len: 65536, physical len: 1024, indices: 4096",
+        |b| {
+            b.iter(|| {
+                let decoded: Int32Array = values
+                    .downcast::<Int32Array>()
+                    .unwrap()
+                    .into_iter()
+                    .collect();
+                bench_take(&decoded, &indices)
+            })
+        },
+    );
 }
 
 criterion_group!(benches, add_benchmark);
@@ -0,0 +1,86 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#[macro_use]
+extern crate criterion;
+use criterion::Criterion;
+
+extern crate arrow;
+
+use arrow::array::*;
+use arrow::compute::kernels::zip::zip;
+use arrow::datatypes::{Int32Type, Int64Type};
+use arrow::util::bench_util::*;
+
+fn bench_zip(mask: &BooleanArray, truthy: &dyn Datum, falsy: &dyn Datum) {
+    criterion::black_box(zip(mask, truthy, falsy).unwrap());
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    let size = 65536;
+    let mask = create_boolean_array(size, 0.0, 0.5);
+    let array = create_string_array::<i64>(size, 0.0);
+    let scalar = LargeStringArray::new_scalar("a".repeat(32));
+
+    c.bench_function("zip large_utf8 scalar falsy", |b| {
+        b.iter(|| bench_zip(&mask, &array, &scalar))
+    });
+
+    c.bench_function("zip large_utf8 scalar both", |b| {
+        b.iter(|| bench_zip(&mask, &scalar, &LargeStringArray::new_scalar("b")))
+    });
+
+    // Long runs of the scalar
+    let mask = BooleanArray::from_iter((0..size).map(|i| Some(i % 4096 < 16)));
+    c.bench_function("zip large_utf8 scalar long fill", |b| {
+        b.iter(|| bench_zip(&mask, &array, &scalar))
+    });
+
+    // Mostly null primitive branches
+    let mask = create_boolean_array(size, 0.0, 0.5);
+    let truthy = create_primitive_array::<Int64Type>(size, 0.95);
+    let falsy = create_primitive_array::<Int64Type>(size, 0.95);
+    c.bench_function("zip int64 95% nulls", |b| {
+        b.iter(|| bench_zip(&mask, &truthy, &falsy))
+    });
+
+    let truthy = create_primitive_array::<Int64Type>(size, 0.0);
+    let falsy = create_primitive_array::<Int64Type>(size, 0.0);
+    c.bench_function("zip int64 no nulls", |b| {
+        b.iter(|| bench_zip(&mask, &truthy, &falsy))
+    });
+
+    // Primitive branches are blended 64 values at a time
+    let truthy = create_primitive_array::<Int32Type>(size, 0.0);
+    let falsy = create_primitive_array::<Int32Type>(size, 0.1);
+    c.bench_function("zip int32 blend", |b| {
+        b.iter(|| bench_zip(&mask, &truthy, &falsy))
+    });
+
+    let mask = BooleanArray::from_iter((0..size).map(|i| Some(i % 4096 < 16)));
+    c.bench_function("zip int32 blend long runs", |b| {
+        b.iter(|| bench_zip(&mask, &truthy, &falsy))
+    });
+
+    let null = Scalar::new(LargeStringArray::new_null(1));
+    c.bench_function("zip large_utf8 null scalar long fill", |b| {
+        b.iter(|| bench_zip(&mask, &array, &null))
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -20,9 +20,11 @@
 pub use arrow_arith::{aggregate, arithmetic, arity, bitwise, boolean, numeric, temporal};
 pub use arrow_cast::cast;
 pub use arrow_cast::parse as cast_utils;
-pub use arrow_ord::{cmp, partition, rank, sort};
-pub use arrow_select::{concat, filter, interleave, nullif, take, union_extract, window, zip};
-pub use arrow_string::{concat_elements, length, regexp, substring};
+pub use arrow_ord::{approx, cmp, partition, rank, sort};
+pub use arrow_select::{
+    concat, filter, interleave, map, minmax, nullif, sample, take, union_extract, window, zip,
+};
+pub use arrow_string::{concat_elements, length, regexp, substring, translate};
 
 /// Comparison kernels for `Array`s.
 pub mod comparison {
@@ -17,9 +17,9 @@
 
 use arrow::array::{
     Array, ArrayRef, BooleanArray, Decimal128Array, DictionaryArray, FixedSizeBinaryArray,
-    FixedSizeListBuilder, Int16Array, Int32Array, Int64Array, Int64Builder, ListArray, ListBuilder,
-    MapBuilder, NullArray, StringArray, StringBuilder, StringDictionaryBuilder, StructArray,
-    UInt16Array, UInt16Builder, UInt8Array, UnionArray,
+    FixedSizeListBuilder, Int16Array, Int32Array, Int64Array, Int64Builder, LargeStringArray,
+    ListArray, ListBuilder, MapBuilder, NullArray, StringArray, StringBuilder,
+    StringDictionaryBuilder, StructArray, UInt16Array, UInt16Builder, UInt8Array, UnionArray,
 };
 use arrow::datatypes::Int16Type;
 use arrow_array::StringViewArray;
@@ -1021,11 +1021,108 @@ fn test_extend_nulls() {
 }
 
 #[test]
-#[should_panic(expected = "MutableArrayData not nullable")]
-fn test_extend_nulls_panic() {
+fn test_extend_constant() {
+    let int = Int32Array::from(vec![Some(1), None, Some(3)]).into_data();
+    let mut mutable = MutableArrayData::new(vec![&int], false, 6);
+    mutable.extend_constant(0, 2, 3);
+    mutable.extend_constant(0, 1, 2);
+    mutable.extend_constant(0, 0, 0);
+    mutable.extend(0, 0, 1);
+
+    let data = mutable.freeze();
+    data.validate_full().unwrap();
+    let out = Int32Array::from(data);
+    let expected = Int32Array::from(vec![Some(3), Some(3), Some(3), None, None, Some(1)]);
+    assert_eq!(out, expected);
+
+    let strings = LargeStringArray::from(vec!["hello", "", "world"]).into_data();
+    let sliced = strings.slice(1, 2);
+    let mut mutable = MutableArrayData::new(vec![&strings, &sliced], false, 6);
+    mutable.extend_constant(0, 0, 2);
+    mutable.extend_constant(1, 0, 2);
+    mutable.extend_constant(1, 1, 1);
+    mutable.extend(0, 2, 3);
+
+    let data = mutable.freeze();
+    data.validate_full().unwrap();
+    let out = LargeStringArray::from(data);
+    let expected = LargeStringArray::from(vec!["hello", "hello", "", "", "world", "world"]);
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_reserve_capacity() {
+    let int = Int32Array::from(vec![Some(1), None, Some(3)]).into_data();
+    let mut mutable = MutableArrayData::new(vec![&int], false, 0);
+    mutable.reserve(100);
+    assert!(mutable.capacity() >= 100);
+    let capacity = mutable.capacity();
+    for _ in 0..33 {
+        mutable.extend(0, 0, 3);
+    }
+    assert_eq!(mutable.capacity(), capacity);
+    assert_eq!(mutable.len(), 99);
+
+    mutable.reserve(1000);
+    assert!(mutable.capacity() >= 1099);
+    let data = mutable.freeze();
+    data.validate_full().unwrap();
+    assert_eq!(data.len(), 99);
+
+    // Offsets hold one more element than the array
+    let strings = StringArray::from(vec!["a", "bc"]).into_data();
+    let mut mutable = MutableArrayData::new(vec![&strings], false, 0);
+    mutable.extend(0, 0, 2);
+    mutable.reserve(10);
+    assert!(mutable.capacity() >= 12);
+
+    let bools = BooleanArray::from(vec![true, false]).into_data();
+    let mut mutable = MutableArrayData::new(vec![&bools], true, 0);
+    mutable.reserve(100);
+    assert!(mutable.capacity() >= 100);
+
+    // Struct children are reserved too
+    let struct_array = StructArray::from(vec![(
+        Arc::new(Field::new("a", DataType::Int64, false)),
+        Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef,
+    )])
+    .into_data();
+    let mut mutable = MutableArrayData::new(vec![&struct_array], false, 0);
+    mutable.reserve(50);
+    assert!(mutable.capacity() >= 50);
+
+    let null = NullArray::new(3).into_data();
+    let mutable = MutableArrayData::new(vec![&null], false, 0);
+    assert_eq!(mutable.capacity(), usize::MAX);
+}
+
+#[test]
+fn test_extend_nulls_without_use_nulls() {
     let int = Int32Array::from(vec![1, 2, 3, 4]).into_data();
     let mut mutable = MutableArrayData::new(vec![&int], false, 4);
+    mutable.extend(0, 0, 2);
     mutable.extend_nulls(2);
+    mutable.extend(0, 3, 4);
+
+    let data = mutable.freeze();
+    data.validate_full().unwrap();
+    let out = Int32Array::from(data);
+    let expected = Int32Array::from(vec![Some(1), Some(2), None, None, Some(4)]);
+    assert_eq!(out, expected);
+
+    let strings = StringArray::from(vec!["hello", "world"]).into_data();
+    let mut mutable = MutableArrayData::new(vec![&strings], false, 4);
+    mutable.extend_nulls(1);
+    mutable.extend(0, 1, 2);
+    mutable.extend_nulls(2);
+    mutable.extend_constant(0, 0, 1);
+
+    let data = mutable.freeze();
+    data.validate_full().unwrap();
+    let out = StringArray::from(data);
+    let expected = StringArray::from(vec![None, Some("world"), None, None, Some("hello")]);
+    assert_eq!(out, expected);
+    assert_eq!(out.value_offsets(), &[0, 0, 5, 5, 5, 10]);
 }
 
 #[test]
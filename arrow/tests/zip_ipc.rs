@@ -0,0 +1,57 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{Array, ArrayRef, BooleanArray, Int32Array, RecordBatch, Scalar, StringArray};
+use arrow::compute::kernels::zip::zip;
+use arrow::ipc::reader::StreamReader;
+use arrow::ipc::writer::StreamWriter;
+use arrow_array::cast::AsArray;
+
+/// Test that the output of `zip` of sliced inputs can be written to an IPC stream
+/// without being re-encoded, and reads back equal
+#[test]
+fn test_zip_write_ipc_stream() {
+    let mask = BooleanArray::from_iter((0..20).map(|i| (i % 4 != 3).then_some(i % 3 == 0)));
+
+    let ints = Int32Array::from_iter((0..30).map(|i| (i % 5 != 0).then_some(i)));
+    let strings = StringArray::from_iter_values((0..30).map(|i| "ab".repeat(i % 4)));
+    let scalar = Scalar::new(StringArray::from(vec!["falsy"]));
+
+    let zipped_ints = zip(&mask, &ints.slice(5, 20), &ints.slice(10, 20)).unwrap();
+    let zipped_strings = zip(&mask, &strings.slice(7, 20), &scalar).unwrap();
+
+    for zipped in [&zipped_ints, &zipped_strings] {
+        assert_eq!(zipped.offset(), 0);
+    }
+    let offsets = zipped_strings.as_string::<i32>().offsets();
+    assert_eq!(offsets[0], 0);
+
+    let batch = RecordBatch::try_from_iter([
+        ("ints", zipped_ints as ArrayRef),
+        ("strings", zipped_strings as ArrayRef),
+    ])
+    .unwrap();
+
+    let mut writer = StreamWriter::try_new(vec![], batch.schema_ref()).unwrap();
+    writer.write(&batch).unwrap();
+    let buffer = writer.into_inner().unwrap();
+
+    let mut reader = StreamReader::try_new(buffer.as_slice(), None).unwrap();
+    let read = reader.next().unwrap().unwrap();
+    assert_eq!(read, batch);
+    assert!(reader.next().is_none());
+}